@@ -39,6 +39,9 @@ impl<'a> Context<'a> {
     pub fn build_from_api(api: &'a JsonExtensionApi) -> Self {
         let mut ctx = Self::default();
 
+        // Must happen before any exclusion checks.
+        special_cases::init_class_selection(api);
+
         for class in api.singletons.iter() {
             ctx.singletons.insert(class.name.as_str());
         }
@@ -71,7 +71,10 @@ fn collect_base_traits(all_base_classes: &[TyName], ctx: &Context) -> Vec<(Strin
 
 fn make_special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
     quote! {
-        #[doc(hidden)]
+        /// Registers additional methods, properties, signals and constants for this class at runtime.
+        ///
+        /// Called once during class registration, after the symbols from `#[godot_api]` and `#[var]`/`#[export]`.
+        /// See [`ClassBuilder`][crate::builder::ClassBuilder] for details.
         fn register_class(builder: &mut crate::builder::ClassBuilder<Self>) {
             unimplemented!()
         }
@@ -5,13 +5,174 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-//! Codegen-dependent exclusions. Can be removed if feature `codegen-full` is removed.
+//! Codegen-dependent exclusions: minimal config (without `codegen-full`) and user-defined class subsets.
 
 // TODO make this file private and only accessed by special_cases.rs.
 
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{
+    JsonBuiltinMethod, JsonClass, JsonClassMethod, JsonExtensionApi, JsonUtilityFunction,
+};
 use crate::special_cases;
+use crate::util::option_as_slice;
+
+/// Environment variable holding the path to a file that lists engine classes to generate.
+pub(crate) const CLASS_SUBSET_ENV: &str = "GODOT4_CODEGEN_CLASSES";
+
+/// Engine classes that are generated, or `None` if all of them are.
+static SELECTED_CLASS_SET: OnceLock<Option<HashSet<String>>> = OnceLock::new();
+
+/// Determines which engine classes are generated. Must be called before any exclusion checks.
+///
+/// Without `codegen-full`, this is the minimal set in [`ALWAYS_SELECTED_CLASSES`]. In both modes, users can list additional classes in a file
+/// referenced by [`CLASS_SUBSET_ENV`]; with `codegen-full`, this restricts codegen to those classes instead of the entire API.
+pub(crate) fn init_class_selection(api: &JsonExtensionApi) {
+    SELECTED_CLASS_SET.get_or_init(|| {
+        println!("cargo:rerun-if-env-changed={CLASS_SUBSET_ENV}");
+
+        let requested = read_requested_classes()?;
+        Some(select_classes(&requested, &api.classes))
+    });
+}
+
+/// Reads the class list file, if the environment variable is set.
+///
+/// Without `codegen-full`, an absent file means the minimal set, represented as an empty request.
+fn read_requested_classes() -> Option<Vec<String>> {
+    let Ok(path) = std::env::var(CLASS_SUBSET_ENV) else {
+        return if cfg!(feature = "codegen-full") {
+            None
+        } else {
+            Some(Vec::new())
+        };
+    };
+
+    println!("cargo:rerun-if-changed={path}");
+    let content = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!("{CLASS_SUBSET_ENV}: failed to read class list from '{path}': {e}")
+    });
+
+    Some(parse_class_list(&content))
+}
+
+/// Parses a class list: class names separated by whitespace or commas. `#` starts a comment until the end of the line.
+pub(crate) fn parse_class_list(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Computes the classes to generate: the always-needed ones, plus the requested classes with their dependencies.
+///
+/// The selection always contains the base classes of each selected class. Dependencies of a requested class are all classes appearing in
+/// its method and signal signatures (including typed arrays/dictionaries, and classes declaring enums). Dependencies are not expanded
+/// further; their methods are generated only if the signature types are part of the selection. Neither are the classes needed by
+/// godot-rust itself, to keep the minimal config minimal.
+pub(crate) fn select_classes(requested: &[String], classes: &[JsonClass]) -> HashSet<String> {
+    let mut selected = HashSet::new();
+
+    let full_classes: &[&str] = if cfg!(feature = "codegen-full") {
+        CODEGEN_FULL_CLASSES
+    } else {
+        &[]
+    };
+
+    for &name in ALWAYS_SELECTED_CLASSES.iter().chain(full_classes) {
+        insert_with_bases(&mut selected, classes, name);
+
+        // Keep classes that don't exist in this API version; they are simply not generated.
+        selected.insert(name.to_string());
+    }
+
+    for name in requested {
+        let Some(class) = find_class(classes, name) else {
+            panic!("{CLASS_SUBSET_ENV}: class `{name}` does not exist in the Godot API");
+        };
+
+        insert_with_bases(&mut selected, classes, &class.name);
+
+        let method_types = option_as_slice(&class.methods).iter().flat_map(|method| {
+            let args = option_as_slice(&method.arguments)
+                .iter()
+                .map(|arg| &arg.type_);
+            args.chain(method.return_value.as_ref().map(|ret| &ret.type_))
+        });
+        let signal_types = option_as_slice(&class.signals).iter().flat_map(|signal| {
+            option_as_slice(&signal.arguments)
+                .iter()
+                .map(|arg| &arg.type_)
+        });
+
+        for ty in method_types.chain(signal_types) {
+            for dependency in referenced_class_names(ty) {
+                insert_with_bases(&mut selected, classes, dependency);
+            }
+        }
+    }
+
+    selected
+}
+
+fn find_class<'c>(classes: &'c [JsonClass], name: &str) -> Option<&'c JsonClass> {
+    classes.iter().find(|class| class.name == name)
+}
+
+/// Adds a class and all its base classes to the selection. Stops at the first base that is already selected, whose bases are then selected
+/// as well.
+fn insert_with_bases(selected: &mut HashSet<String>, classes: &[JsonClass], name: &str) {
+    let mut current = find_class(classes, name);
+
+    while let Some(class) = current {
+        if !selected.insert(class.name.clone()) {
+            break;
+        }
+
+        current = class
+            .inherits
+            .as_ref()
+            .and_then(|base| find_class(classes, base));
+    }
+}
+
+/// Class names that a JSON type string can refer to, e.g. `Node` in `enum::Node.ProcessMode` or `typedarray::Node`.
+///
+/// May return names of builtins or native structures; those are not found among classes and thus ignored by the caller.
+fn referenced_class_names(ty: &str) -> Vec<&str> {
+    if let Some(enum_) = ty
+        .strip_prefix("enum::")
+        .or_else(|| ty.strip_prefix("bitfield::"))
+    {
+        // Global enums have no surrounding class.
+        return enum_
+            .split_once('.')
+            .map(|(class, _)| class)
+            .into_iter()
+            .collect();
+    }
+
+    if let Some(element) = ty.strip_prefix("typedarray::") {
+        // Element can be in hint format, e.g. `24/17:Node`.
+        return vec![element.rsplit(':').next().unwrap_or(element)];
+    }
+
+    if let Some(entries) = ty.strip_prefix("typeddictionary::") {
+        return entries.split(';').collect();
+    }
+
+    vec![ty]
+}
+
+/// Whether only a subset of engine classes is generated.
+fn is_subset() -> bool {
+    SELECTED_CLASS_SET.get().is_some_and(Option::is_some)
+}
 
 pub(crate) fn is_builtin_method_excluded(_method: &JsonBuiltinMethod) -> bool {
     // Prior to Godot 4.2, builtin varargs (method.is_vararg) weren't supported, but that's now our minimum supported version.
@@ -19,27 +180,22 @@ pub(crate) fn is_builtin_method_excluded(_method: &JsonBuiltinMethod) -> bool {
     false
 }
 
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_class_excluded(godot_class_name: &str) -> bool {
-    !SELECTED_CLASSES.contains(&godot_class_name)
-}
+    match SELECTED_CLASS_SET.get() {
+        Some(Some(selected)) => !selected.contains(godot_class_name),
+        Some(None) => false,
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_class_excluded(_godot_class_name: &str) -> bool {
-    false
+        // Selection not yet initialized: fall back to Cargo features.
+        None => {
+            !cfg!(feature = "codegen-full") && !ALWAYS_SELECTED_CLASSES.contains(&godot_class_name)
+        }
+    }
 }
 
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_native_struct_excluded(native_struct: &str) -> bool {
-    native_struct == "CaretInfo"
-}
-
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_native_struct_excluded(_native_struct: &str) -> bool {
-    false
+    is_subset() && native_struct == "CaretInfo"
 }
 
-#[cfg(not(feature = "codegen-full"))]
 fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
     use crate::conv;
     use crate::models::domain::RustTy;
@@ -75,15 +231,14 @@ fn is_type_excluded(ty: &str, ctx: &mut Context) -> bool {
         }
     }
 
+    if !is_subset() {
+        return false;
+    }
+
     // Both meta + flow direction are irrelevant here.
     is_rust_type_excluded(&conv::to_rust_temporary_type(ty, ctx))
 }
 
-#[cfg(feature = "codegen-full")]
-fn is_type_excluded(_ty: &str, _ctx: &mut Context) -> bool {
-    false
-}
-
 pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Context) -> bool {
     let is_arg_or_return_excluded = |ty: &str, _ctx: &mut Context| {
         // First check if the type is explicitly deleted. In Godot, type names are unique without further categorization,
@@ -114,15 +269,6 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
     false
 }
 
-#[cfg(feature = "codegen-full")]
-pub(crate) fn is_utility_function_excluded(
-    _function: &JsonUtilityFunction,
-    _ctx: &mut Context,
-) -> bool {
-    false
-}
-
-#[cfg(not(feature = "codegen-full"))]
 pub(crate) fn is_utility_function_excluded(
     function: &JsonUtilityFunction,
     ctx: &mut Context,
@@ -140,9 +286,8 @@ pub(crate) fn is_utility_function_excluded(
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
-// Classes for minimal config
-#[cfg(not(feature = "codegen-full"))]
-const SELECTED_CLASSES: &[&str] = &[
+/// Classes needed by godot-rust itself; generated even in minimal config or for a user-defined class subset, together with their base classes.
+const ALWAYS_SELECTED_CLASSES: &[&str] = &[
     // Core class hierarchy
     "Object",
     "Node",
@@ -165,6 +310,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "FileAccess",
+    "DirAccess",
     //
     // Scene (node_test, rpc_test)
     "MainLoop", // base of SceneTree
@@ -201,3 +347,13 @@ const SELECTED_CLASSES: &[&str] = &[
     "HTTPRequest",
     "ResourceFormatLoader", // TODO: replace?
 ];
+
+/// Classes additionally needed by godot-rust APIs behind the `codegen-full` feature (RPC configuration, property editors).
+///
+/// Like [`ALWAYS_SELECTED_CLASSES`], these are selected together with their base classes, but signature types are not expanded.
+const CODEGEN_FULL_CLASSES: &[&str] = &[
+    "MultiplayerAPI",
+    "MultiplayerPeer",
+    "EditorProperty",
+    "EditorInspectorPlugin",
+];
@@ -12,3 +12,6 @@ mod special_cases;
 
 // Content not in mod.rs to find the file quicker.
 pub use special_cases::*;
+
+#[cfg(test)]
+pub(crate) use codegen_special_cases::{parse_class_list, select_classes};
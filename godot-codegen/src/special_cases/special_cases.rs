@@ -36,7 +36,9 @@ use crate::conv::to_enum_type_uncached;
 use crate::models::domain::{
     ClassCodegenLevel, Enum, EnumReplacements, RustTy, TyName, VirtualMethodPresence,
 };
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
+use crate::models::json::{
+    JsonBuiltinMethod, JsonClassMethod, JsonExtensionApi, JsonSignal, JsonUtilityFunction,
+};
 use crate::special_cases::codegen_special_cases;
 use crate::util::option_as_slice;
 
@@ -105,6 +107,11 @@ pub fn get_class_method_deprecation(class_name: &TyName, method: &JsonClassMetho
     Some(deprecation_msg)
 }
 
+/// Determines which engine classes are generated, based on Cargo features and the user's class list. See [`is_class_deleted`].
+pub fn init_class_selection(api: &JsonExtensionApi) {
+    codegen_special_cases::init_class_selection(api)
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
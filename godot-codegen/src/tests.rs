@@ -170,3 +170,104 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_class_subset_selection() {
+    use nanoserde::DeJson;
+
+    use crate::models::json::JsonClass;
+    use crate::special_cases::{parse_class_list, select_classes};
+
+    let classes: Vec<JsonClass> = DeJson::deserialize_json(
+        r#"[
+        {"name": "Node2D", "is_refcounted": false, "is_instantiable": true, "inherits": "CanvasItem", "api_type": "core",
+         "methods": [{"name": "add_tween", "is_const": false, "is_vararg": false, "is_static": false, "is_virtual": false,
+                      "arguments": [{"name": "t", "type": "Tween"}]}]},
+        {"name": "Sprite2D", "is_refcounted": false, "is_instantiable": true, "inherits": "Node2D", "api_type": "core",
+         "methods": [{"name": "f", "is_const": false, "is_vararg": false, "is_static": false, "is_virtual": false,
+                      "return_value": {"type": "typeddictionary::String;Material"},
+                      "arguments": [{"name": "a", "type": "Texture2D"}, {"name": "b", "type": "typedarray::24/17:Image"},
+                                    {"name": "c", "type": "enum::BaseMaterial3D.Flags"}, {"name": "d", "type": "enum::Error"}]}],
+         "signals": [{"name": "s", "arguments": [{"name": "a", "type": "Shader"}]}]},
+        {"name": "Texture2D", "is_refcounted": true, "is_instantiable": false, "inherits": "Texture", "api_type": "core"},
+        {"name": "Texture", "is_refcounted": true, "is_instantiable": false, "inherits": "Resource", "api_type": "core"},
+        {"name": "Image", "is_refcounted": true, "is_instantiable": true, "inherits": "Resource", "api_type": "core"},
+        {"name": "BaseMaterial3D", "is_refcounted": true, "is_instantiable": false, "inherits": "Material", "api_type": "core"},
+        {"name": "Material", "is_refcounted": true, "is_instantiable": false, "inherits": "Resource", "api_type": "core"},
+        {"name": "Shader", "is_refcounted": true, "is_instantiable": true, "inherits": "Resource", "api_type": "core"},
+        {"name": "Tween", "is_refcounted": true, "is_instantiable": false, "inherits": "RefCounted", "api_type": "core"}
+    ]"#,
+    )
+    .unwrap();
+
+    let requested =
+        parse_class_list("# Classes used by the game.\nSprite2D, # trailing comment\n\n");
+    assert_eq!(requested, ["Sprite2D"]);
+
+    let selected = select_classes(&requested, &classes);
+
+    // Requested class, its bases and signature types (including typed collections, enums and signals).
+    for class in [
+        "Sprite2D",
+        "Node2D",
+        "Texture2D",
+        "Texture",
+        "Image",
+        "Material",
+        "Shader",
+        "BaseMaterial3D",
+    ] {
+        assert!(selected.contains(class), "{class} should be selected");
+    }
+
+    // Dependencies of dependencies are not expanded.
+    assert!(!selected.contains("Tween"));
+}
+
+#[test]
+fn test_class_selection_includes_bases() {
+    use nanoserde::DeJson;
+
+    use crate::models::json::JsonClass;
+    use crate::special_cases::select_classes;
+
+    let classes: Vec<JsonClass> = DeJson::deserialize_json(
+        r#"[
+        {"name": "Object", "is_refcounted": false, "is_instantiable": true, "api_type": "core"},
+        {"name": "RefCounted", "is_refcounted": false, "is_instantiable": true, "inherits": "Object", "api_type": "core"},
+        {"name": "Resource", "is_refcounted": false, "is_instantiable": true, "inherits": "RefCounted", "api_type": "core"},
+        {"name": "Node", "is_refcounted": false, "is_instantiable": true, "inherits": "Object", "api_type": "core"},
+        {"name": "CanvasItem", "is_refcounted": false, "is_instantiable": true, "inherits": "Node", "api_type": "core"},
+        {"name": "Node2D", "is_refcounted": false, "is_instantiable": true, "inherits": "CanvasItem", "api_type": "core"},
+        {"name": "Control", "is_refcounted": false, "is_instantiable": true, "inherits": "CanvasItem", "api_type": "core"},
+        {"name": "Container", "is_refcounted": false, "is_instantiable": true, "inherits": "Control", "api_type": "core"},
+        {"name": "EditorProperty", "is_refcounted": false, "is_instantiable": true, "inherits": "Container", "api_type": "core"},
+        {"name": "EditorInspectorPlugin", "is_refcounted": false, "is_instantiable": true, "inherits": "RefCounted", "api_type": "core"},
+        {"name": "PacketPeer", "is_refcounted": false, "is_instantiable": true, "inherits": "RefCounted", "api_type": "core"},
+        {"name": "MultiplayerPeer", "is_refcounted": false, "is_instantiable": true, "inherits": "PacketPeer", "api_type": "core"},
+        {"name": "MultiplayerAPI", "is_refcounted": false, "is_instantiable": true, "inherits": "RefCounted", "api_type": "core"},
+        {"name": "Sprite2D", "is_refcounted": false, "is_instantiable": true, "inherits": "Node2D", "api_type": "core"}
+    ]"#,
+    )
+    .unwrap();
+
+    for requested in [vec![], vec!["Sprite2D".to_string()]] {
+        let selected = select_classes(&requested, &classes);
+
+        // Selection must be closed under inheritance.
+        for class in &classes {
+            if let (true, Some(base)) = (selected.contains(&class.name), &class.inherits) {
+                assert!(
+                    selected.contains(base),
+                    "{} is selected, but not its base {base}",
+                    class.name
+                );
+            }
+        }
+
+        if cfg!(feature = "codegen-full") {
+            assert!(selected.contains("PacketPeer"));
+            assert!(selected.contains("Container"));
+        }
+    }
+}
@@ -5,136 +5,431 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-// use crate::GodotClass;
-// use godot_core::Variant;
-// use godot_ffi as sys;
-// use std::borrow::Cow;
-// use std::ffi::CStr;
-
-// pub trait Method<C> {
-//     type ReturnType;
-//     type ParamTypes;
-//
-//     fn method_name(&self) -> Cow<CStr>;
-//     fn ptrcall(&mut self, instance: &mut C, args: Self::ParamTypes) -> Self::ReturnType;
-// }
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
 
-// ----------------------------------------------------------------------------------------------------------------------------------------------
-/*
+use godot_ffi as sys;
+use sys::{Global, GlobalGuard};
+
+use crate::builder::ClassBuilder;
+use crate::builtin::{StringName, Variant};
+use crate::global::MethodFlags;
+use crate::meta::{CallContext, ClassId, InParamTuple, Signature, ToGodot};
+use crate::obj::GodotClass;
+use crate::private::{handle_fallible_ptrcall, handle_fallible_varcall};
+use crate::registry::method::ClassMethodInfo;
+use crate::storage::{Storage, as_storage};
 
-/// Method known at compile time (statically), either a Rust `fn` or closure.
-pub trait CodeMethod<C, R, Ps>
+/// Builder for a method registered through [`ClassBuilder`].
+///
+/// The method is only registered once [`done()`][Self::done] is called.
+#[must_use]
+pub struct MethodBuilder<'cb, C: GodotClass, F, Params, Ret> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    name: String,
+    method: F,
+    flags: MethodFlags,
+    param_names: Option<Vec<String>>,
+    default_args: Vec<Variant>,
+    call_func: sys::GDExtensionClassMethodCall,
+    ptrcall_func: sys::GDExtensionClassMethodPtrCall,
+    _signature: PhantomData<fn(Params) -> Ret>,
+}
+
+impl<'cb, C, F, Params, Ret> MethodBuilder<'cb, C, F, Params, Ret>
 where
     C: GodotClass,
+    F: 'static,
+    Params: InParamTuple + 'static,
+    Ret: ToGodot<Via: Clone> + 'static,
 {
-    const PARAM_COUNT: usize;
-    const NAME: &'static str;
-
-    unsafe fn varcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-        err: *mut sys::GDExtensionCallError,
-    );
-
-    unsafe fn ptrcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-    );
+    pub(super) fn new_instance(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        method: F,
+    ) -> Self
+    where
+        F: Fn(&mut C, Params) -> Ret,
+    {
+        Self::new(
+            class_builder,
+            name,
+            method,
+            MethodFlags::NORMAL,
+            Some(varcall_instance::<C, F, Params, Ret>),
+            Some(ptrcall_instance::<C, F, Params, Ret>),
+        )
+    }
+
+    pub(super) fn new_static(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        function: F,
+    ) -> Self
+    where
+        F: Fn(Params) -> Ret,
+    {
+        Self::new(
+            class_builder,
+            name,
+            function,
+            MethodFlags::NORMAL | MethodFlags::STATIC,
+            Some(varcall_static::<F, Params, Ret>),
+            Some(ptrcall_static::<F, Params, Ret>),
+        )
+    }
+
+    fn new(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        method: F,
+        flags: MethodFlags,
+        call_func: sys::GDExtensionClassMethodCall,
+        ptrcall_func: sys::GDExtensionClassMethodPtrCall,
+    ) -> Self {
+        Self {
+            class_builder,
+            name: name.to_string(),
+            method,
+            flags,
+            param_names: None,
+            default_args: vec![],
+            call_func,
+            ptrcall_func,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Names of the parameters, as shown in the editor docs and autocompletion.
+    ///
+    /// If not specified, parameters are named `arg0`, `arg1`, ...
+    ///
+    /// # Panics
+    /// If the number of names does not match the number of parameters (checked in [`done()`][Self::done]).
+    pub fn param_names(mut self, names: &[&str]) -> Self {
+        self.param_names = Some(names.iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Default values for the last `defaults.len()` parameters, used when GDScript omits those arguments.
+    ///
+    /// Equivalent to `#[opt(default = ...)]` on `#[func]` parameters.
+    pub fn default_args(mut self, defaults: Vec<Variant>) -> Self {
+        self.default_args = defaults;
+        self
+    }
+
+    /// Registers the method as `const`, i.e. not modifying the object. This is purely informational for the editor.
+    pub fn is_const(mut self) -> Self {
+        self.flags = self.flags | MethodFlags::CONST;
+        self
+    }
+
+    /// Finishes the method definition and adds it to the class.
+    pub fn done(self) {
+        let Self {
+            class_builder,
+            name,
+            method,
+            flags,
+            param_names,
+            default_args,
+            call_func,
+            ptrcall_func,
+            _signature,
+        } = self;
+
+        let param_names =
+            param_names.unwrap_or_else(|| (0..Params::LEN).map(|i| format!("arg{i}")).collect());
+        let param_names: Vec<&str> = param_names.iter().map(String::as_str).collect();
+
+        let is_varargs = flags.is_set(MethodFlags::VARARG);
+        let class_name = C::class_id().to_string();
+
+        // SAFETY: call_func + ptrcall_func are instantiated with the same Params/Ret/F as here, and interpret in/out parameters accordingly.
+        let method_info = unsafe {
+            ClassMethodInfo::from_signature::<C, Params, Ret>(
+                StringName::from(name.as_str()),
+                call_func,
+                ptrcall_func,
+                flags,
+                if is_varargs { &[] } else { &param_names },
+                default_args.clone(),
+            )
+        };
+
+        // Godot doesn't provide a callback for freeing method userdata; it is freed when the class is unregistered.
+        let method_data = Box::new(MethodData {
+            class_name,
+            method_name: name,
+            func: method,
+            default_args,
+        });
+        let method_data = Box::into_raw(method_data).cast::<c_void>();
+        global_method_userdata()
+            .entry(C::class_id())
+            .or_default()
+            .push(MethodUserdata {
+                ptr: method_data,
+                free_fn: free_method_data::<F>,
+            });
+
+        // SAFETY: the trampolines interpret `method_userdata` as `MethodData<F>`; the data stays valid until the class is unregistered.
+        let method_info = unsafe { method_info.with_method_userdata(method_data) };
+
+        class_builder.state.methods.push(method_info);
+    }
 }
 
+impl<'cb, C, F> MethodBuilder<'cb, C, F, (), Variant>
+where
+    C: GodotClass,
+    F: Fn(&mut C, &[&Variant]) -> Variant + 'static,
+{
+    pub(super) fn new_varargs(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &str,
+        method: F,
+    ) -> Self {
+        Self::new(
+            class_builder,
+            name,
+            method,
+            MethodFlags::NORMAL | MethodFlags::VARARG,
+            Some(varcall_varargs::<C, F>),
+            None,
+        )
+    }
+}
 
-// TODO code duplication ((2))
-macro_rules! count_idents {
-    () => {
-        0
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Userdata lifetime
+
+/// Owned `MethodData<F>` of a registered method, type-erased together with its destructor.
+struct MethodUserdata {
+    ptr: *mut c_void,
+    free_fn: unsafe fn(*mut c_void),
+}
+
+// SAFETY: the pointer is only dereferenced on the main thread, during calls and class (de)registration.
+unsafe impl Send for MethodUserdata {}
+
+/// Returns a lock to the userdata of all methods registered through `ClassBuilder`, by class.
+///
+/// Like the loaded-classes maps in `registry::class`, this is only accessed on the main thread during (de)registration.
+fn global_method_userdata() -> GlobalGuard<'static, HashMap<ClassId, Vec<MethodUserdata>>> {
+    static METHOD_USERDATA: Global<HashMap<ClassId, Vec<MethodUserdata>>> = Global::default();
+
+    METHOD_USERDATA.lock()
+}
+
+/// Frees the userdata of methods registered through `ClassBuilder` for `class_id`.
+///
+/// Must be called after the class has been unregistered from Godot, so that none of its methods can be called anymore.
+pub(crate) fn free_method_userdata(class_id: ClassId) {
+    let Some(userdata) = global_method_userdata().remove(&class_id) else {
+        return;
     };
-    ($name:ident, $($other:ident,)*) => {
-        1 + $crate::gdext_count_idents!($($other,)*)
+
+    for MethodUserdata { ptr, free_fn } in userdata {
+        // SAFETY: `ptr` was created together with `free_fn` in `MethodBuilder::done()` and is freed exactly once, as it's removed above.
+        unsafe { free_fn(ptr) };
     }
 }
 
-macro_rules! impl_code_method {
-// 	( $( $Param:ident ),* ) => {
-    ( $( $Param:ident $arg:ident ),* ) => {
-        impl<C, F, R, $( $Param ),*> CodeMethod<C, R, ( $( $Param, )* )> for F
-        where
-            C: $crate::GodotClass + $crate::GodotDefault, // TODO only GodotClass
-            F: Fn(&C, $( $Param ),* ) -> R,
-            $(
-                $Param: sys::GodotFfi,
-            )*
-            R: sys::GodotFfi + 'static,
-        {
-            const PARAM_COUNT: usize = count_idents!($( $Param, )*);
-
-            // Varcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn varcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-                err: *mut sys::GDExtensionCallError,
-            ) {
-                let storage = ::godot_core::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as FromVariant::from_variant(&*(*args.offset(idx) as *mut Variant));
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                *(ret as *mut Variant) = Variant::from(ret_val);
-                (*err).error = sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK;
-            }
-
-
-            // Ptrcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn ptrcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-            ) {
-                let storage = $crate::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                // TODO reuse code, see ((1))
-                let mut idx = 0;
-
-                $(
-                    let $arg = <$Param as sys::GodotFfi>::from_sys(*args.offset(idx));
-                    // FIXME update refcount, e.g. Gd::ready() or T::DynMemory::maybe_inc_ref(&result);
-                    // possibly in from_sys() directly; what about from_sys_init() and from_{obj|str}_sys()?
-                    idx += 1;
-                )*
-
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                <R as sys::GodotFfi>::write_sys(&ret_val, ret);
-            }
-        }
+/// # Safety
+/// `method_data` must come from `Box::into_raw()` of a `MethodData<F>`, and must not be used afterwards.
+unsafe fn free_method_data<F>(method_data: *mut c_void) {
+    // SAFETY: see function contract.
+    let _ = unsafe { Box::from_raw(method_data.cast::<MethodData<F>>()) };
+}
 
-    };
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// FFI trampolines
+
+/// Passed as `method_userdata` to the trampolines below.
+struct MethodData<F> {
+    class_name: String,
+    method_name: String,
+    func: F,
+    default_args: Vec<Variant>,
+}
+
+impl<F> MethodData<F> {
+    /// # Safety
+    /// `method_data` must point to a `MethodData<F>` created in [`MethodBuilder::done()`].
+    unsafe fn from_userdata<'a>(method_data: *mut c_void) -> &'a Self {
+        unsafe { &*method_data.cast::<Self>() }
+    }
+
+    fn call_ctx(&self) -> CallContext<'_> {
+        CallContext::func(&self.class_name, &self.method_name)
+    }
+}
+
+unsafe extern "C" fn varcall_instance<C, F, Params, Ret>(
+    method_data: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    C: GodotClass,
+    F: Fn(&mut C, Params) -> Ret + 'static,
+    Params: InParamTuple,
+    Ret: ToGodot<Via: Clone>,
+{
+    let data = unsafe { MethodData::<F>::from_userdata(method_data) };
+    let call_ctx = data.call_ctx();
+
+    let invocation = AssertUnwindSafe(|| unsafe {
+        Signature::<Params, Ret>::in_varcall(
+            instance_ptr,
+            &call_ctx,
+            args_ptr,
+            arg_count,
+            &data.default_args,
+            ret,
+            err,
+            |instance_ptr, params| call_instance(instance_ptr, &data.func, params),
+        )
+    });
+
+    handle_fallible_varcall(&call_ctx, unsafe { &mut *err }, invocation);
+}
+
+unsafe extern "C" fn ptrcall_instance<C, F, Params, Ret>(
+    method_data: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) where
+    C: GodotClass,
+    F: Fn(&mut C, Params) -> Ret + 'static,
+    Params: InParamTuple,
+    Ret: ToGodot<Via: Clone>,
+{
+    let data = unsafe { MethodData::<F>::from_userdata(method_data) };
+    let call_ctx = data.call_ctx();
+
+    let invocation = AssertUnwindSafe(|| unsafe {
+        Signature::<Params, Ret>::in_ptrcall(
+            instance_ptr,
+            &call_ctx,
+            args_ptr,
+            ret,
+            |instance_ptr, params| call_instance(instance_ptr, &data.func, params),
+            sys::PtrcallType::Standard,
+        )
+    });
+
+    handle_fallible_ptrcall(&call_ctx, invocation);
+}
+
+unsafe extern "C" fn varcall_static<F, Params, Ret>(
+    method_data: *mut c_void,
+    _instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    F: Fn(Params) -> Ret + 'static,
+    Params: InParamTuple,
+    Ret: ToGodot<Via: Clone>,
+{
+    let data = unsafe { MethodData::<F>::from_userdata(method_data) };
+    let call_ctx = data.call_ctx();
+
+    let invocation = AssertUnwindSafe(|| unsafe {
+        Signature::<Params, Ret>::in_varcall(
+            std::ptr::null_mut(),
+            &call_ctx,
+            args_ptr,
+            arg_count,
+            &data.default_args,
+            ret,
+            err,
+            |_, params| (data.func)(params),
+        )
+    });
+
+    handle_fallible_varcall(&call_ctx, unsafe { &mut *err }, invocation);
 }
 
-impl_code_method!();
-impl_code_method!(P0 arg0);
-impl_code_method!(P0 arg0, P1 arg1);
-impl_code_method!(P0 arg0, P1 arg1, P2 arg2);
-*/
+unsafe extern "C" fn ptrcall_static<F, Params, Ret>(
+    method_data: *mut c_void,
+    _instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) where
+    F: Fn(Params) -> Ret + 'static,
+    Params: InParamTuple,
+    Ret: ToGodot<Via: Clone>,
+{
+    let data = unsafe { MethodData::<F>::from_userdata(method_data) };
+    let call_ctx = data.call_ctx();
+
+    let invocation = AssertUnwindSafe(|| unsafe {
+        Signature::<Params, Ret>::in_ptrcall(
+            std::ptr::null_mut(),
+            &call_ctx,
+            args_ptr,
+            ret,
+            |_, params| (data.func)(params),
+            sys::PtrcallType::Standard,
+        )
+    });
+
+    handle_fallible_ptrcall(&call_ctx, invocation);
+}
+
+unsafe extern "C" fn varcall_varargs<C, F>(
+    method_data: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    C: GodotClass,
+    F: Fn(&mut C, &[&Variant]) -> Variant + 'static,
+{
+    let data = unsafe { MethodData::<F>::from_userdata(method_data) };
+    let call_ctx = data.call_ctx();
+
+    let invocation = AssertUnwindSafe(|| unsafe {
+        let args = Variant::borrow_ref_slice(args_ptr, arg_count as usize);
+
+        let storage = as_storage::<C>(instance_ptr);
+        let mut instance = Storage::get_mut(storage);
+        let result = (data.func)(&mut *instance, args);
+
+        *(ret as *mut Variant) = result;
+        (*err).error = sys::GDEXTENSION_CALL_OK;
+
+        crate::meta::error::CallResult::Ok(())
+    });
+
+    handle_fallible_varcall(&call_ctx, unsafe { &mut *err }, invocation);
+}
+
+/// # Safety
+/// `instance_ptr` must point to a valid `InstanceStorage<C>`.
+unsafe fn call_instance<C, F, Params, Ret>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    func: &F,
+    params: Params,
+) -> Ret
+where
+    C: GodotClass,
+    F: Fn(&mut C, Params) -> Ret,
+{
+    let storage = unsafe { as_storage::<C>(instance_ptr) };
+    let mut instance = Storage::get_mut(storage);
+
+    func(&mut *instance, params)
+}
@@ -5,16 +5,70 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+//! Runtime registration of class symbols, without proc-macros.
+
 use std::marker::PhantomData;
 
+use crate::builtin::{StringName, Variant};
+use crate::meta::{ClassId, InParamTuple, ToGodot};
 use crate::obj::GodotClass;
+use crate::registry::constant::{ConstantKind, ExportConstant, IntegerConstant};
+use crate::registry::method::ClassMethodInfo;
 
 mod method;
+mod property;
+mod signal;
+
+pub use method::MethodBuilder;
+pub(crate) use method::free_method_userdata;
+pub use property::PropertyBuilder;
+pub use signal::SignalBuilder;
 
-/// Class builder to store state for registering a class with Godot.
+/// Registers methods, properties, signals and constants of a class from regular Rust code.
+///
+/// This is the runtime counterpart to `#[godot_api]` and `#[var]`/`#[export]`. It is useful when the surface of a class is only known at
+/// startup, for example because it's read from a data file. A `ClassBuilder` is handed out by the `register_class()` method of the class'
+/// `I*` interface trait:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::ClassBuilder;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// struct Stats {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl IRefCounted for Stats {
+///     fn register_class(builder: &mut ClassBuilder<Self>) {
+///         builder
+///             .method("get_health", |this: &mut Self, (): ()| this.health)
+///             .done();
+///         builder
+///             .method("set_health", |this: &mut Self, (health,): (i64,)| this.health = health)
+///             .param_names(&["health"])
+///             .done();
 ///
-/// In the future this will be used, but for now it's a dummy struct.
+///         builder
+///             .property::<i64>("health")
+///             .getter("get_health")
+///             .setter("set_health")
+///             .done();
+///
+///         builder.signal("died").done();
+///         builder.constant("MAX_HEALTH", 100);
+///     }
+/// }
+/// ```
+///
+/// # Registration order
+/// Symbols are not registered immediately, but collected and handed to Godot after all other registration hooks of the class have run
+/// (including those generated by `#[godot_api]`). Methods are registered first, followed by properties, signals and constants. This
+/// means properties can refer to getters and setters declared either through the builder or through `#[func]`.
 pub struct ClassBuilder<C> {
+    state: ClassBuilderState,
     _c: PhantomData<C>,
 }
 
@@ -23,36 +77,183 @@ where
     C: GodotClass,
 {
     pub(crate) fn new() -> Self {
-        Self { _c: PhantomData }
+        Self {
+            state: ClassBuilderState::new(C::class_id()),
+            _c: PhantomData,
+        }
+    }
+
+    /// Registers an instance method, callable from GDScript as `name`.
+    ///
+    /// Parameters are received as a tuple, e.g. `(i64, GString)`. Use `()` for methods without parameters. The instance is accessed
+    /// mutably, so re-entrant calls from Godot into the same object (during the execution of `method`) will panic.
+    pub fn method<F, Params, Ret>(
+        &mut self,
+        name: &str,
+        method: F,
+    ) -> MethodBuilder<'_, C, F, Params, Ret>
+    where
+        F: Fn(&mut C, Params) -> Ret + 'static,
+        Params: InParamTuple + 'static,
+        Ret: ToGodot<Via: Clone> + 'static,
+    {
+        MethodBuilder::new_instance(self, name, method)
+    }
+
+    /// Registers a static function, callable from GDScript as `ClassName.name()`.
+    pub fn static_method<F, Params, Ret>(
+        &mut self,
+        name: &str,
+        function: F,
+    ) -> MethodBuilder<'_, C, F, Params, Ret>
+    where
+        F: Fn(Params) -> Ret + 'static,
+        Params: InParamTuple + 'static,
+        Ret: ToGodot<Via: Clone> + 'static,
+    {
+        MethodBuilder::new_static(self, name, function)
     }
 
-    pub fn virtual_method<'cb, F>(
-        &'cb mut self,
-        name: &'cb str,
+    /// Registers an instance method accepting any number of arguments of any type.
+    ///
+    /// Useful if the signature is only known at runtime. Arguments are not validated; `method` is responsible for checking count and types.
+    pub fn varargs_method<F>(
+        &mut self,
+        name: &str,
         method: F,
-    ) -> MethodBuilder<'cb, C, F> {
-        MethodBuilder::new(self, name, method)
+    ) -> MethodBuilder<'_, C, F, (), Variant>
+    where
+        F: Fn(&mut C, &[&Variant]) -> Variant + 'static,
+    {
+        MethodBuilder::new_varargs(self, name, method)
+    }
+
+    /// Registers a property of type `T`, backed by getter and setter methods.
+    pub fn property<T>(&mut self, name: &str) -> PropertyBuilder<'_, C>
+    where
+        T: crate::registry::property::Var,
+    {
+        PropertyBuilder::new::<T>(self, name)
+    }
+
+    /// Starts a new group in the editor inspector. All properties registered afterwards, whose names start with `prefix`, belong to it.
+    pub fn group(&mut self, name: &str, prefix: &str) {
+        self.state.properties.push(property::PropertyItem::Group {
+            name: name.to_string(),
+            prefix: prefix.to_string(),
+        });
+    }
+
+    /// Starts a new subgroup within the current group. See [`group()`][Self::group].
+    pub fn subgroup(&mut self, name: &str, prefix: &str) {
+        self.state
+            .properties
+            .push(property::PropertyItem::Subgroup {
+                name: name.to_string(),
+                prefix: prefix.to_string(),
+            });
+    }
+
+    /// Registers a signal. Parameters can be added through the returned builder.
+    pub fn signal(&mut self, name: &str) -> SignalBuilder<'_, C> {
+        SignalBuilder::new(self, name)
+    }
+
+    /// Registers an integer constant, accessible from GDScript as `ClassName.name`.
+    pub fn constant(&mut self, name: &str, value: i64) {
+        let kind = ConstantKind::Integer(IntegerConstant::new(name, value));
+        self.push_constant(kind);
+    }
+
+    /// Registers an enum with the given `(name, value)` enumerators.
+    pub fn enum_constants(&mut self, enum_name: &str, enumerators: &[(&str, i64)]) {
+        let kind = ConstantKind::Enum {
+            name: StringName::from(enum_name),
+            enumerators: make_integer_constants(enumerators),
+        };
+        self.push_constant(kind);
+    }
+
+    /// Registers a bitfield with the given `(name, value)` flags.
+    pub fn bitfield_constants(&mut self, bitfield_name: &str, flags: &[(&str, i64)]) {
+        let kind = ConstantKind::Bitfield {
+            name: StringName::from(bitfield_name),
+            flags: make_integer_constants(flags),
+        };
+        self.push_constant(kind);
+    }
+
+    pub(crate) fn into_state(self) -> ClassBuilderState {
+        self.state
+    }
+
+    fn push_constant(&mut self, kind: ConstantKind) {
+        let constant = ExportConstant::new(self.state.class_id, kind);
+        self.state.constants.push(constant);
     }
 }
 
+fn make_integer_constants(values: &[(&str, i64)]) -> Vec<IntegerConstant> {
+    values
+        .iter()
+        .map(|(name, value)| IntegerConstant::new(name, *value))
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)] // TODO rm
-#[must_use]
-pub struct MethodBuilder<'cb, C, F> {
-    class_builder: &'cb mut ClassBuilder<C>,
-    name: &'cb str,
-    method: F,
+/// Type-erased state of a [`ClassBuilder`], collecting all symbols until they are registered with Godot.
+///
+/// Passed as `&mut dyn Any` to the registration functions of a class (see `ErasedRegisterFn`).
+pub(crate) struct ClassBuilderState {
+    class_id: ClassId,
+    methods: Vec<ClassMethodInfo>,
+    properties: Vec<property::PropertyItem>,
+    signals: Vec<signal::SignalRegistration>,
+    constants: Vec<ExportConstant>,
 }
 
-impl<'cb, C, F> MethodBuilder<'cb, C, F> {
-    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str, method: F) -> Self {
+impl ClassBuilderState {
+    pub(crate) fn new(class_id: ClassId) -> Self {
         Self {
-            class_builder,
-            name,
-            method,
+            class_id,
+            methods: vec![],
+            properties: vec![],
+            signals: vec![],
+            constants: vec![],
         }
     }
 
-    pub fn done(self) {}
+    /// Takes over all symbols collected by `other`.
+    pub(crate) fn append(&mut self, mut other: ClassBuilderState) {
+        debug_assert_eq!(self.class_id, other.class_id, "class mismatch");
+
+        self.methods.append(&mut other.methods);
+        self.properties.append(&mut other.properties);
+        self.signals.append(&mut other.signals);
+        self.constants.append(&mut other.constants);
+    }
+
+    /// Registers all collected symbols with Godot.
+    ///
+    /// Methods come first, since properties may refer to them as getters/setters.
+    pub(crate) fn register_all(self) {
+        let class_id = self.class_id;
+
+        for method in self.methods {
+            method.register_extension_class_method();
+        }
+
+        for item in self.properties {
+            item.register(class_id);
+        }
+
+        for signal in self.signals {
+            signal.register(class_id);
+        }
+
+        for constant in self.constants {
+            constant.register();
+        }
+    }
 }
@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builder::ClassBuilder;
+use crate::builtin::GString;
+use crate::global::PropertyUsageFlags;
+use crate::meta::{ClassId, PropertyHintInfo, PropertyInfo};
+use crate::obj::GodotClass;
+use crate::registry::godot_register_wrappers::register_var_or_export_inner;
+use crate::registry::property::{Export, Var};
+use crate::sys;
+
+/// Builder for a property registered through [`ClassBuilder`].
+///
+/// At least one of [`getter()`][Self::getter] and [`setter()`][Self::setter] should be provided; they refer to methods by their Godot
+/// name, registered either through `#[func]` or [`ClassBuilder::method()`].
+#[must_use]
+pub struct PropertyBuilder<'cb, C> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    info: PropertyInfo,
+    getter: String,
+    setter: String,
+}
+
+impl<'cb, C> PropertyBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    pub(super) fn new<T: Var>(class_builder: &'cb mut ClassBuilder<C>, name: &str) -> Self {
+        Self {
+            class_builder,
+            // Like #[var]: not visible in the editor, unless exported.
+            info: PropertyInfo {
+                usage: PropertyUsageFlags::NONE,
+                ..PropertyInfo::new_var::<T>(name)
+            },
+            getter: String::new(),
+            setter: String::new(),
+        }
+    }
+
+    /// Name of the method returning the property's value.
+    pub fn getter(mut self, method_name: &str) -> Self {
+        self.getter = method_name.to_string();
+        self
+    }
+
+    /// Name of the method accepting the property's new value.
+    pub fn setter(mut self, method_name: &str) -> Self {
+        self.setter = method_name.to_string();
+        self
+    }
+
+    /// Shows the property in the editor inspector, with the same hints as `#[export]` on a field of type `T`.
+    pub fn export<T: Export>(mut self) -> Self {
+        self.info.hint_info = T::export_hint();
+        self.info.usage = PropertyUsageFlags::DEFAULT;
+        self
+    }
+
+    /// Overrides hint and hint string, e.g. with a function from [`export_info_functions`][crate::registry::property::export_info_functions].
+    pub fn hint_info(mut self, hint_info: PropertyHintInfo) -> Self {
+        self.info.hint_info = hint_info;
+        self
+    }
+
+    /// Overrides the usage flags. The default is [`PropertyUsageFlags::NONE`] for plain properties (like `#[var]`).
+    pub fn usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.info.usage = usage;
+        self
+    }
+
+    /// Finishes the property definition and adds it to the class.
+    pub fn done(self) {
+        let Self {
+            class_builder,
+            info,
+            getter,
+            setter,
+        } = self;
+
+        class_builder.state.properties.push(PropertyItem::Property {
+            info,
+            getter,
+            setter,
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Entries in the property list; order is significant for groups.
+pub(super) enum PropertyItem {
+    Property {
+        info: PropertyInfo,
+        getter: String,
+        setter: String,
+    },
+    Group {
+        name: String,
+        prefix: String,
+    },
+    Subgroup {
+        name: String,
+        prefix: String,
+    },
+}
+
+impl PropertyItem {
+    pub(super) fn register(self, class_id: ClassId) {
+        match self {
+            PropertyItem::Property {
+                info,
+                getter,
+                setter,
+            } => register_var_or_export_inner(info, class_id, &getter, &setter),
+
+            PropertyItem::Group { name, prefix } => {
+                let name = GString::from(name.as_str());
+                let prefix = GString::from(prefix.as_str());
+
+                unsafe {
+                    sys::interface_fn!(classdb_register_extension_class_property_group)(
+                        sys::get_library(),
+                        class_id.string_sys(),
+                        name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
+
+            PropertyItem::Subgroup { name, prefix } => {
+                let name = GString::from(name.as_str());
+                let prefix = GString::from(prefix.as_str());
+
+                unsafe {
+                    sys::interface_fn!(classdb_register_extension_class_property_subgroup)(
+                        sys::get_library(),
+                        class_id.string_sys(),
+                        name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
+        }
+    }
+}
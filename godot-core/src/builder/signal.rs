@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builder::ClassBuilder;
+use crate::builtin::StringName;
+use crate::meta::{ClassId, GodotConvert, GodotType, PropertyInfo};
+use crate::obj::GodotClass;
+use crate::sys;
+
+/// Builder for a signal registered through [`ClassBuilder`].
+///
+/// The signal can be emitted from Rust through the untyped [`Object::emit_signal()`][crate::classes::Object::emit_signal] API.
+#[must_use]
+pub struct SignalBuilder<'cb, C> {
+    class_builder: &'cb mut ClassBuilder<C>,
+    name: StringName,
+    params: Vec<PropertyInfo>,
+}
+
+impl<'cb, C> SignalBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &str) -> Self {
+        Self {
+            class_builder,
+            name: StringName::from(name),
+            params: vec![],
+        }
+    }
+
+    /// Adds a parameter of type `T` and name `name`.
+    pub fn param<T: GodotConvert>(self, name: &str) -> Self {
+        let info = T::Via::property_info(name);
+        self.param_info(info)
+    }
+
+    /// Adds a parameter with custom type information, e.g. for types only known at runtime.
+    pub fn param_info(mut self, info: PropertyInfo) -> Self {
+        self.params.push(info);
+        self
+    }
+
+    /// Finishes the signal definition and adds it to the class.
+    pub fn done(self) {
+        let Self {
+            class_builder,
+            name,
+            params,
+        } = self;
+
+        class_builder
+            .state
+            .signals
+            .push(SignalRegistration { name, params });
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct SignalRegistration {
+    name: StringName,
+    params: Vec<PropertyInfo>,
+}
+
+impl SignalRegistration {
+    pub(super) fn register(self, class_id: ClassId) {
+        // Don't use raw sys pointers directly; `params` must outlive the FFI call.
+        let params_sys: Vec<sys::GDExtensionPropertyInfo> =
+            self.params.iter().map(|info| info.property_sys()).collect();
+
+        unsafe {
+            sys::interface_fn!(classdb_register_extension_class_signal)(
+                sys::get_library(),
+                class_id.string_sys(),
+                self.name.string_sys(),
+                params_sys.as_ptr(),
+                params_sys.len() as sys::GDExtensionInt,
+            );
+        }
+    }
+}
@@ -34,6 +34,8 @@ type CallableCustomInfo = sys::GDExtensionCallableCustomInfo2;
 /// # Godot docs
 ///
 /// [`Callable` (stable)](https://docs.godotengine.org/en/stable/classes/class_callable.html)
+// Layout must match Godot's Callable, for borrowing from a Variant in `Variant::view()`.
+#[repr(transparent)]
 pub struct Callable {
     opaque: sys::types::OpaqueCallable,
 }
@@ -590,6 +590,48 @@ impl GodotFfiVariant for AnyArray {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// Serialized like [`VarArray`], regardless of the runtime element type.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for AnyArray {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let mut sequence = serializer.serialize_seq(Some(self.len()))?;
+            for e in self.iter_shared() {
+                sequence.serialize_element(&e)?
+            }
+            sequence.end()
+        }
+    }
+
+    /// Always deserializes to an untyped array.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for AnyArray {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            VarArray::deserialize(deserializer).map(VarArray::upcast_any_array)
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// An iterator over elements of an [`AnyArray`].
@@ -447,3 +447,41 @@ impl GodotFfiVariant for AnyDictionary {
         result.map(|inner| Self { dict: inner })
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::super::dictionary::serialize::serialize_pairs;
+    use super::*;
+
+    /// Serialized like [`VarDictionary`], as a sequence of `(key, value)` pairs, regardless of the runtime element types.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for AnyDictionary {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            serialize_pairs(serializer, self.len(), self.iter_shared(), false)
+        }
+    }
+
+    /// Always deserializes to an untyped dictionary.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for AnyDictionary {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            VarDictionary::deserialize(deserializer).map(VarDictionary::upcast_any_dictionary)
+        }
+    }
+}
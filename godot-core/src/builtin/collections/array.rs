@@ -22,7 +22,7 @@ use crate::meta::{
     GodotType, PropertyHintInfo, RefArg, ToGodot, element_godot_type_name, element_variant_type,
 };
 use crate::obj::{Bounds, DynGd, Gd, GodotClass, bounds};
-use crate::registry::property::{BuiltinExport, Export, Var};
+use crate::registry::property::{BuiltinExport, ElementsExport, Export, Var};
 
 /// Godot's `Array` type.
 ///
@@ -1256,6 +1256,8 @@ where
 
 impl<T: Element> BuiltinExport for Array<T> {}
 
+impl<T: Element> ElementsExport for Array<T> where Self: Export {}
+
 impl<T> Export for Array<Gd<T>>
 where
     T: GodotClass + Bounds<Exportable = bounds::Yes>,
@@ -1422,20 +1424,72 @@ impl<T: Element + ToGodot> FromIterator<T> for Array<T> {
 }
 
 /// Extends a `Array` with the contents of an iterator.
+///
+/// # Performance note
+/// This uses the lower bound from `Iterator::size_hint()` to resize the array once up front, and writes elements directly into the new
+/// slots. Elements beyond that bound are appended one by one.
+///
+/// If the iterator panics, or returns fewer elements than its lower bound, the array is truncated to the elements actually written.
 impl<T: Element> Extend<T> for Array<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-        // Otherwise, we could use it to pre-allocate based on `iter.size_hint()`.
-        //
-        // A faster implementation using `resize()` and direct pointer writes might still be possible.
-        // Note that this could technically also use iter(), since no moves need to happen (however Extend requires IntoIterator).
-        for item in iter.into_iter() {
-            // self.push(AsArg::into_arg(&item));
+        // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`. Resizing once and writing the values in place
+        // avoids Godot's per-element growth and type checks in `push_back`.
+        let mut iter = iter.into_iter();
+
+        let (size_hint_min, _size_hint_max) = iter.size_hint();
+        if size_hint_min > 0 {
+            self.balanced_ensure_mutable();
+
+            let start = self.len();
+            let end = start + size_hint_min;
+
+            // SAFETY: The new elements are either overwritten with values of type `T` below, or removed again by the guard.
+            unsafe { self.as_inner_mut() }.resize(to_i64(end));
+
+            let mut guard = ExtendGuard {
+                array: self,
+                written_end: start,
+                end,
+            };
+
+            while guard.written_end < end {
+                let Some(item) = iter.next() else {
+                    // Iterator broke its `size_hint()` contract; the guard removes the unwritten slots.
+                    break;
+                };
+
+                // Pointer is re-fetched for every element, since the iterator may access the array through another reference.
+                let ptr_mut = guard.array.ptr_mut(guard.written_end);
+
+                // SAFETY: `ptr_mut` just checked that the index is not out of bounds.
+                unsafe { item.to_variant().move_into_var_ptr(ptr_mut) };
+                guard.written_end += 1;
+            }
+        }
+
+        for item in iter {
             self.push(meta::owned_into_arg(item));
         }
     }
 }
 
+/// Removes the not yet written slots of [`Array::extend()`] on early exit or unwinding, so that no placeholder `Nil` values remain in a
+/// typed array.
+struct ExtendGuard<'a, T: Element> {
+    array: &'a mut Array<T>,
+    written_end: usize,
+    end: usize,
+}
+
+impl<T: Element> Drop for ExtendGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.written_end < self.end {
+            // SAFETY: Shrinking only removes elements; all remaining ones hold values of type `T`.
+            unsafe { self.array.as_inner_mut() }.resize(to_i64(self.written_end));
+        }
+    }
+}
+
 /// Converts this array to a strongly typed Rust vector.
 impl<T: Element + FromGodot> From<&Array<T>> for Vec<T> {
     fn from(array: &Array<T>) -> Vec<T> {
@@ -24,6 +24,14 @@ use crate::{meta, sys};
 ///
 /// In many cases, it is thus better to use [`Array::iter_shared()`] combined with iterator adapters. Check the individual method docs of
 /// this struct for concrete alternatives.
+///
+/// # Typed closures
+/// Each callable-based method has a counterpart with a `_by` suffix, which accepts a typed Rust closure instead of a `Callable`, e.g.
+/// [`filter_by()`][Self::filter_by] or [`map_by()`][Self::map_by]. These run entirely in Rust: elements are converted once to `T`, and
+/// no callable dispatch or intermediate variants are involved. Results are returned as typed arrays.
+///
+/// Predicates (`filter_by`, `any_by`, `all_by`, `find_by`, `rfind_by`) receive each element as `&T`. Only the transforming methods
+/// [`map_by()`][Self::map_by] and [`fold_by()`][Self::fold_by] take elements by value, since they usually consume them.
 pub struct ArrayFunctionalOps<'a, T: Element> {
     array: &'a Array<T>,
 }
@@ -190,6 +198,129 @@ impl<'a, T: Element> ArrayFunctionalOps<'a, T> {
         sys::found_to_option(found_index)
     }
 
+    /// Returns a new array containing only the elements for which `predicate` returns `true`.
+    ///
+    /// Typed counterpart of [`filter()`][Self::filter], without callable dispatch.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 2, 3, 4, 5];
+    /// let even = array.functional_ops().filter_by(|i| i % 2 == 0);
+    /// assert_eq!(even, array![2, 4]);
+    /// ```
+    #[must_use]
+    pub fn filter_by<F>(&self, mut predicate: F) -> Array<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.array.iter_shared().filter(|e| predicate(e)).collect()
+    }
+
+    /// Returns a new typed array with each element transformed by `f`.
+    ///
+    /// Typed counterpart of [`map()`][Self::map], without callable dispatch. Unlike `map()`, the result is an `Array<U>`. Elements are
+    /// passed to `f` by value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1.1, 1.5, 1.9];
+    /// let rounded: Array<i64> = array.functional_ops().map_by(|f| f.round() as i64);
+    /// assert_eq!(rounded, array![1, 2, 2]);
+    /// ```
+    #[must_use]
+    pub fn map_by<U, F>(&self, f: F) -> Array<U>
+    where
+        U: Element,
+        F: FnMut(T) -> U,
+    {
+        self.array.iter_shared().map(f).collect()
+    }
+
+    /// Reduces the array to a single value by iteratively applying `f`, starting with `initial`.
+    ///
+    /// Typed counterpart of [`reduce()`][Self::reduce], without callable dispatch. The accumulator can be any Rust type, and elements are
+    /// passed to `f` by value.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 2, 3, 4];
+    /// let sum = array.functional_ops().fold_by(0, |acc, i| acc + i);
+    /// assert_eq!(sum, 10);
+    /// ```
+    pub fn fold_by<B, F>(&self, initial: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.array.iter_shared().fold(initial, f)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for at least one element.
+    ///
+    /// Typed counterpart of [`any()`][Self::any], without callable dispatch. Stops at the first match.
+    pub fn any_by<F>(&self, mut predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.array.iter_shared().any(|e| predicate(&e))
+    }
+
+    /// Returns `true` if `predicate` returns `true` for all elements.
+    ///
+    /// Typed counterpart of [`all()`][Self::all], without callable dispatch. Stops at the first mismatch.
+    pub fn all_by<F>(&self, mut predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.array.iter_shared().all(|e| predicate(&e))
+    }
+
+    /// Finds the index of the first element matching `predicate`, starting at index `from`.
+    ///
+    /// Typed counterpart of [`find_custom()`][Self::find_custom], without callable dispatch. Available in all Godot versions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 2, 3, 4, 5];
+    /// assert_eq!(array.functional_ops().find_by(|i| i % 2 == 0, None), Some(1)); // value 2
+    /// assert_eq!(array.functional_ops().find_by(|i| i % 2 == 0, Some(2)), Some(3)); // value 4
+    /// ```
+    pub fn find_by<F>(&self, mut predicate: F, from: Option<usize>) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let from = from.unwrap_or(0);
+
+        self.array
+            .iter_shared()
+            .skip(from)
+            .position(|e| predicate(&e))
+            .map(|i| i + from)
+    }
+
+    /// Finds the index of the last element matching `predicate`, searching backwards from index `from`.
+    ///
+    /// Typed counterpart of [`rfind_custom()`][Self::rfind_custom], without callable dispatch. Available in all Godot versions.
+    /// If `from` is `None` or out of bounds, the search starts at the last element.
+    pub fn rfind_by<F>(&self, mut predicate: F, from: Option<usize>) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.array.len();
+        let end = match from {
+            Some(from) if from < len => from + 1,
+            _ => len,
+        };
+
+        // Elements are fetched by index, since the predicate may modify the array through another reference.
+        (0..end)
+            .rev()
+            .find(|&i| self.array.get(i).is_some_and(|e| predicate(&e)))
+    }
+
     /// Finds the index of a value in a sorted array using binary search, with `Callable` custom predicate.
     ///
     /// The callable `pred` takes two elements `(a, b)` and should return if `a < b` (strictly less).
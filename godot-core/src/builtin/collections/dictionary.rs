@@ -1264,6 +1264,145 @@ impl<K: FromGodot> Iterator for TypedKeys<'_, K> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+pub(super) mod serialize {
+    use std::marker::PhantomData;
+
+    use serde::de::{MapAccess, SeqAccess, Visitor};
+    use serde::ser::{SerializeMap, SerializeSeq};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// Serializes key-value pairs either as a map, or as a sequence of `(key, value)` tuples.
+    ///
+    /// Only string keys are serialized as maps, since formats like JSON only support string map keys. Other keys (including `Variant`, which
+    /// is serialized as an enum) would either be rejected or silently converted to strings.
+    pub(in crate::builtin::collections) fn serialize_pairs<S, K, V>(
+        serializer: S,
+        len: usize,
+        pairs: impl Iterator<Item = (K, V)>,
+        as_map: bool,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+        V: Serialize,
+    {
+        if as_map {
+            let mut map = serializer.serialize_map(Some(len))?;
+            for (key, value) in pairs {
+                map.serialize_entry(&key, &value)?;
+            }
+            map.end()
+        } else {
+            let mut sequence = serializer.serialize_seq(Some(len))?;
+            for pair in pairs {
+                sequence.serialize_element(&pair)?;
+            }
+            sequence.end()
+        }
+    }
+
+    /// Whether dictionaries with key type `K` are serialized as maps, rather than sequences of `(key, value)` pairs.
+    fn is_serialized_as_map<K: Element>() -> bool {
+        matches!(
+            meta::element_variant_type::<K>(),
+            VariantType::STRING | VariantType::STRING_NAME
+        )
+    }
+
+    // For "Available on crate feature `serde`" in docs. Cannot be inherited from module.
+    /// Dictionaries with string keys ([`GString`][crate::builtin::GString] or [`StringName`][crate::builtin::StringName]) are serialized as
+    /// maps. All other dictionaries, including those with `Variant` keys (such as [`VarDictionary`]), are serialized as sequences of
+    /// `(key, value)` pairs, so that they remain representable in formats with string-only map keys.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<K, V> Serialize for Dictionary<K, V>
+    where
+        K: Element + Serialize,
+        V: Element + Serialize,
+    {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let as_map = is_serialized_as_map::<K>();
+            let pairs = self.iter_shared().typed::<K, V>();
+
+            serialize_pairs(serializer, self.len(), pairs, as_map)
+        }
+    }
+
+    /// Expects the layout produced by serialization: a map for string keys, otherwise a sequence of `(key, value)` pairs.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, K, V> Deserialize<'de> for Dictionary<K, V>
+    where
+        K: Element + Deserialize<'de>,
+        V: Element + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct DictionaryVisitor<K, V>(PhantomData<(K, V)>);
+
+            impl<'de, K, V> Visitor<'de> for DictionaryVisitor<K, V>
+            where
+                K: Element + Deserialize<'de>,
+                V: Element + Deserialize<'de>,
+            {
+                type Value = Dictionary<K, V>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(std::any::type_name::<Self::Value>())
+                }
+
+                fn visit_map<A>(
+                    self,
+                    mut map: A,
+                ) -> Result<Self::Value, <A as MapAccess<'de>>::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut dict = Dictionary::new();
+                    while let Some(pair) = map.next_entry::<K, V>()? {
+                        dict.extend(Some(pair));
+                    }
+                    Ok(dict)
+                }
+
+                fn visit_seq<A>(
+                    self,
+                    mut seq: A,
+                ) -> Result<Self::Value, <A as SeqAccess<'de>>::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut dict = Dictionary::new();
+                    while let Some(pair) = seq.next_element::<(K, V)>()? {
+                        dict.extend(Some(pair));
+                    }
+                    Ok(dict)
+                }
+            }
+
+            if is_serialized_as_map::<K>() {
+                deserializer.deserialize_map(DictionaryVisitor::<K, V>(PhantomData))
+            } else {
+                deserializer.deserialize_seq(DictionaryVisitor::<K, V>(PhantomData))
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
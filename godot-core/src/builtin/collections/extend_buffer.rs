@@ -49,6 +49,16 @@ impl<T, const N: usize> ExtendBufferTrait<T> for ExtendBuffer<T, N> {
         self.len == N
     }
 
+    /// Returns the number of elements currently in the buffer.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` iff the buffer holds no elements.
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Returns a slice of all initialized elements in the buffer, and sets the length of the buffer back to 0.
     ///
     /// It is the caller's responsibility to ensure that all elements in the returned slice get dropped!
@@ -135,5 +145,7 @@ fn test_extend_buffer_drop() {
 pub trait ExtendBufferTrait<T> {
     fn push(&mut self, value: T);
     fn is_full(&self) -> bool;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool;
     fn drain_as_mut_slice(&mut self) -> &mut [T];
 }
@@ -12,7 +12,9 @@ mod array_functional_ops;
 mod dictionary;
 mod extend_buffer;
 mod packed_array;
+mod packed_array_editor;
 mod packed_array_element;
+mod path_query;
 
 // Re-export in godot::builtin.
 pub(crate) mod containers {
@@ -23,6 +25,7 @@ pub(crate) mod containers {
     pub use super::dictionary::Dictionary;
     pub use super::dictionary::VarDictionary;
     pub use super::packed_array::*;
+    pub use super::packed_array_editor::PackedArrayEditor;
 }
 
 // Re-export in godot::builtin::iter.
@@ -90,6 +90,8 @@ pub type PackedColorArray = PackedArray<Color>;
 ///
 /// # Element type and conversions
 /// See the [corresponding section in `Array`](struct.Array.html#conversions-between-arrays).
+// Layout must match Godot's Vector<T>, for borrowing from a Variant in `Variant::view()`.
+#[repr(transparent)]
 pub struct PackedArray<T: PackedElement> {
     // All packed arrays have same memory layout.
     opaque: sys::types::OpaquePackedByteArray,
@@ -291,6 +293,30 @@ impl<T: PackedElement> PackedArray<T> {
         }
     }
 
+    /// Edits the array through a [`PackedArrayEditor`], which appends elements in batches.
+    ///
+    /// Calling [`push()`][Self::push] in a loop resizes the array on every call. The editor instead collects elements in a small buffer and
+    /// writes them to Godot's storage in bulk, and offers slice-based methods that resize only once. Pending elements are written when the
+    /// closure returns.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut indices = PackedInt32Array::new();
+    /// indices.edit(|editor| {
+    ///     for quad in 0..100 {
+    ///         let base = quad * 4;
+    ///         editor.extend_from_slice(&[base, base + 1, base + 2]);
+    ///         editor.extend_from_slice(&[base, base + 2, base + 3]);
+    ///     }
+    ///     editor.push(0);
+    /// });
+    /// assert_eq!(indices.len(), 601);
+    /// ```
+    pub fn edit<R>(&mut self, f: impl FnOnce(&mut PackedArrayEditor<'_, T>) -> R) -> R {
+        let mut editor = PackedArrayEditor::new(self);
+        f(&mut editor)
+    }
+
     /// Searches the array for the first occurrence of a value and returns its index, or `None` if not found.
     ///
     /// Starts searching at index `from`; pass `None` to search the entire array.
@@ -449,7 +475,7 @@ impl<T: PackedElement> PackedArray<T> {
     /// * `src` must not point to `self` data.
     /// * `len` must be equal to `self.len() - dst`.
     /// * Source data must not be dropped later.
-    unsafe fn move_from_slice(&mut self, src: *const T, dst: usize, len: usize) {
+    pub(super) unsafe fn move_from_slice(&mut self, src: *const T, dst: usize, len: usize) {
         let ptr = self.ptr_mut(dst);
         sys::strict_assert_eq!(len, self.len() - dst, "length precondition violated");
 
@@ -618,6 +644,9 @@ impl<T: PackedElement> Export for PackedArray<T> {
     }
 }
 
+#[cfg(since_api = "4.3")]
+impl<T: PackedElement> crate::registry::property::ElementsExport for PackedArray<T> {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion trait impls
 
@@ -663,6 +692,9 @@ impl<T: PackedElement, const N: usize> From<[T; N]> for PackedArray<T> {
 }
 
 /// Creates a `PackedArray<T>` from the given Rust vec.
+///
+/// The elements are moved into Godot's storage with a single allocation and a bitwise copy. Taking over the vector's allocation is not
+/// possible, because Godot stores a header in front of the elements and frees the memory with its own allocator.
 impl<T: PackedElement> From<Vec<T>> for PackedArray<T> {
     fn from(mut vec: Vec<T>) -> Self {
         if vec.is_empty() {
@@ -681,6 +713,15 @@ impl<T: PackedElement> From<Vec<T>> for PackedArray<T> {
     }
 }
 
+/// Creates a `PackedArray<T>` from the given boxed slice.
+///
+/// Like the `Vec<T>` conversion, this moves the elements with a single bitwise copy.
+impl<T: PackedElement> From<Box<[T]>> for PackedArray<T> {
+    fn from(boxed: Box<[T]>) -> Self {
+        Self::from(Vec::from(boxed))
+    }
+}
+
 /// Creates a `PackedArray<T>` from an iterator.
 ///
 /// # Performance note
@@ -1103,3 +1144,83 @@ impl PackedByteArray {
 fn populated_or_err(array: PackedByteArray) -> Result<PackedByteArray, ()> {
     if array.is_empty() { Err(()) } else { Ok(array) }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use std::marker::PhantomData;
+
+    use serde::de::{SeqAccess, Visitor};
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<T> Serialize for PackedArray<T>
+    where
+        T: PackedElement + Serialize,
+    {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let mut sequence = serializer.serialize_seq(Some(self.len()))?;
+            for e in self.as_slice() {
+                sequence.serialize_element(e)?
+            }
+            sequence.end()
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, T> Deserialize<'de> for PackedArray<T>
+    where
+        T: PackedElement + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct PackedArrayVisitor<T>(PhantomData<T>);
+
+            impl<'de, T> Visitor<'de> for PackedArrayVisitor<T>
+            where
+                T: PackedElement + Deserialize<'de>,
+            {
+                type Value = PackedArray<T>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(std::any::type_name::<Self::Value>())
+                }
+
+                fn visit_seq<A>(
+                    self,
+                    mut seq: A,
+                ) -> Result<Self::Value, <A as SeqAccess<'de>>::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    // The length comes from untrusted input; cap the preallocation like serde's own `size_hint::cautious()`.
+                    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+                    let max_len = MAX_PREALLOC_BYTES / std::mem::size_of::<T>().max(1);
+
+                    let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(max_len));
+                    while let Some(val) = seq.next_element::<T>()? {
+                        vec.push(val);
+                    }
+                    Ok(Self::Value::from(vec))
+                }
+            }
+
+            deserializer.deserialize_seq(PackedArrayVisitor::<T>(PhantomData))
+        }
+    }
+}
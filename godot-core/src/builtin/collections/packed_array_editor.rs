@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::PackedArray;
+use crate::builtin::collections::extend_buffer::ExtendBufferTrait;
+use crate::meta::PackedElement;
+
+/// Scoped, `Vec`-like access to a [`PackedArray`], for appending many elements efficiently.
+///
+/// Obtained through [`PackedArray::edit()`]. Elements added with [`push()`][Self::push] are collected in a fixed-size buffer on the
+/// stack, and written to Godot's storage in batches, so that the array is resized only once per batch instead of once per element.
+/// Bulk methods like [`extend_from_slice()`][Self::extend_from_slice] and [`extend_with()`][Self::extend_with] resize once and write
+/// directly into Godot's storage, without intermediate copies.
+///
+/// All pending elements are written to the array when the editor goes out of scope.
+pub struct PackedArrayEditor<'a, T: PackedElement> {
+    array: &'a mut PackedArray<T>,
+
+    /// Number of elements stored in `array`. Cached to avoid repeated Godot API calls.
+    written_len: usize,
+
+    /// Elements that have been pushed, but not yet written to `array`.
+    pending: T::ExtendBuffer,
+}
+
+impl<'a, T: PackedElement> PackedArrayEditor<'a, T> {
+    pub(super) fn new(array: &'a mut PackedArray<T>) -> Self {
+        let written_len = array.len();
+
+        Self {
+            array,
+            written_len,
+            pending: T::ExtendBuffer::default(),
+        }
+    }
+
+    /// Number of elements in the array, including those not yet written.
+    pub fn len(&self) -> usize {
+        self.written_len + self.pending.len()
+    }
+
+    /// Returns `true` if the array has no elements, including those not yet written.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an element to the end of the array.
+    ///
+    /// Unlike [`PackedArray::push()`], this does not call into Godot for every element.
+    pub fn push(&mut self, value: T) {
+        self.pending.push(value);
+        if self.pending.is_full() {
+            self.flush();
+        }
+    }
+
+    /// Appends clones of all elements in `slice`, resizing the array once.
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        self.extend_with(slice.len(), |dst| dst.clone_from_slice(slice));
+    }
+
+    /// Appends `count` elements, which are initialized by `init`.
+    ///
+    /// The array is resized once, and `init` receives the new elements as a slice into Godot's storage, with default values.
+    /// This is the most direct way to fill large buffers, e.g. vertex data for procedural meshes:
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut vertices = PackedVector3Array::new();
+    /// vertices.edit(|editor| {
+    ///     editor.extend_with(64, |new| {
+    ///         for (i, v) in new.iter_mut().enumerate() {
+    ///             *v = Vector3::new(i as f32, 0.0, 0.0);
+    ///         }
+    ///     });
+    /// });
+    /// assert_eq!(vertices.len(), 64);
+    /// ```
+    pub fn extend_with(&mut self, count: usize, init: impl FnOnce(&mut [T])) {
+        self.flush();
+        if count == 0 {
+            return;
+        }
+
+        let start = self.written_len;
+        self.written_len += count;
+        self.array.resize(self.written_len);
+
+        init(&mut self.array.as_mut_slice()[start..]);
+    }
+
+    /// Shortens the array to `len` elements. Has no effect if the array is already shorter.
+    pub fn truncate(&mut self, len: usize) {
+        self.flush();
+        if len < self.written_len {
+            self.written_len = len;
+            self.array.resize(len);
+        }
+    }
+
+    /// Returns an exclusive slice of all elements, writing pending ones to the array first.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.flush();
+        self.array.as_mut_slice()
+    }
+
+    /// Writes all pending elements to the array, resizing it once.
+    fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let pending = self.pending.drain_as_mut_slice();
+        let start = self.written_len;
+        let count = pending.len();
+
+        // Assumption: resize does not panic. Otherwise we would leak memory here.
+        self.written_len += count;
+        self.array.resize(self.written_len);
+
+        // SAFETY: We just allocated `count` new elements after index `start`. The drained elements are moved into the array and not dropped
+        // by the buffer.
+        unsafe {
+            self.array.move_from_slice(pending.as_ptr(), start, count);
+        }
+    }
+}
+
+impl<T: PackedElement> Extend<T> for PackedArrayEditor<'_, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.flush();
+
+        // PackedArray::extend() already batches writes, based on the iterator's size hint.
+        self.array.extend(iter);
+        self.written_len = self.array.len();
+    }
+}
+
+impl<T: PackedElement> Drop for PackedArrayEditor<'_, T> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl<T: PackedElement> fmt::Debug for PackedArrayEditor<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedArrayEditor")
+            .field("len", &self.len())
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
@@ -0,0 +1,307 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Path-based access into nested `Dictionary`/`Array` trees, e.g. `config.get_path::<i64>("graphics/shadows/2/size")`.
+
+use crate::builtin::*;
+use crate::meta::error::ConvertError;
+use crate::meta::{AsArg, FromGodot, ToGodot, arg_into_ref};
+
+impl Variant {
+    /// Looks up a value nested inside dictionaries and arrays, and converts it to `T`.
+    ///
+    /// The `path` is parsed as a [`NodePath`]: segments are separated by `/`, and subnames (`:name`) are treated as further segments. Each
+    /// segment is a key if the current value is a dictionary, or an index if it is an array. Dictionary keys are matched as strings first,
+    /// then as integers if the segment is numeric. An empty path refers to the variant itself.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let config = vdict! {
+    ///     "graphics": vdict! {
+    ///         "shadows": varray![vdict! { "size": 1024 }, vdict! { "size": 2048 }],
+    ///     },
+    /// };
+    ///
+    /// let size: i64 = config.to_variant().get_path("graphics/shadows/1/size").unwrap();
+    /// assert_eq!(size, 2048);
+    /// ```
+    ///
+    /// # Errors
+    /// If a segment cannot be resolved (missing key, index out of bounds, value that is not a container), or the final value cannot be
+    /// converted to `T`. The error's [`field_path()`][ConvertError::field_path] contains the segments up to the failing one.
+    pub fn get_path<T: FromGodot>(&self, path: impl AsArg<NodePath>) -> Result<T, ConvertError> {
+        arg_into_ref!(path);
+
+        let segments = path_segments(path);
+        let value = resolve_path(self.clone(), &segments)?;
+
+        value
+            .try_to::<T>()
+            .map_err(|err| prepend_segments(err, &segments))
+    }
+
+    /// Sets a value nested inside dictionaries and arrays, creating intermediate dictionaries where needed.
+    ///
+    /// See [`get_path()`][Self::get_path] for the path syntax. Missing keys and `null` values along the path are replaced with new, empty
+    /// dictionaries. The last segment of an array path may be equal to the array's length, in which case the value is appended.
+    ///
+    /// If this variant is `null`, it is replaced with a new dictionary first. An empty path replaces the variant itself.
+    ///
+    /// Containers are modified in place, so the change is visible in all variants and collections sharing them.
+    ///
+    /// # Errors
+    /// If a segment points into a value that is not a container, an array index is out of bounds, or a container along the path is typed or
+    /// read-only. The error's [`field_path()`][ConvertError::field_path] contains the segments up to the failing one.
+    pub fn set_path(
+        &mut self,
+        path: impl AsArg<NodePath>,
+        value: impl ToGodot,
+    ) -> Result<(), ConvertError> {
+        arg_into_ref!(path);
+
+        let segments = path_segments(path);
+        if segments.is_empty() {
+            *self = value.to_variant();
+            return Ok(());
+        }
+
+        if self.is_nil() {
+            *self = VarDictionary::new().to_variant();
+        }
+
+        assign_path(self, &segments, value.to_variant())
+    }
+}
+
+impl AnyDictionary {
+    /// Looks up a value nested inside this dictionary, and converts it to `T`.
+    ///
+    /// Equivalent to [`Variant::get_path()`], see there for details.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let config = vdict! { "audio": vdict! { "volume": 0.8 } };
+    /// let volume: f64 = config.get_path("audio/volume").unwrap();
+    /// ```
+    pub fn get_path<T: FromGodot>(&self, path: impl AsArg<NodePath>) -> Result<T, ConvertError> {
+        self.to_variant().get_path(path)
+    }
+
+    /// Sets a value nested inside this dictionary, creating intermediate dictionaries where needed.
+    ///
+    /// Equivalent to [`Variant::set_path()`], see there for details. The path must not be empty.
+    pub fn set_path(
+        &mut self,
+        path: impl AsArg<NodePath>,
+        value: impl ToGodot,
+    ) -> Result<(), ConvertError> {
+        arg_into_ref!(path);
+
+        let segments = non_empty_segments(path)?;
+        assign_path(&self.to_variant(), &segments, value.to_variant())
+    }
+}
+
+impl AnyArray {
+    /// Looks up a value nested inside this array, and converts it to `T`.
+    ///
+    /// Equivalent to [`Variant::get_path()`], see there for details. The first segment is an index into this array.
+    pub fn get_path<T: FromGodot>(&self, path: impl AsArg<NodePath>) -> Result<T, ConvertError> {
+        self.to_variant().get_path(path)
+    }
+
+    /// Sets a value nested inside this array, creating intermediate dictionaries where needed.
+    ///
+    /// Equivalent to [`Variant::set_path()`], see there for details. The path must not be empty.
+    pub fn set_path(
+        &mut self,
+        path: impl AsArg<NodePath>,
+        value: impl ToGodot,
+    ) -> Result<(), ConvertError> {
+        arg_into_ref!(path);
+
+        let segments = non_empty_segments(path)?;
+        assign_path(&self.to_variant(), &segments, value.to_variant())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Splits a path into its names, followed by its subnames.
+fn path_segments(path: &NodePath) -> Vec<GString> {
+    let names = (0..path.get_name_count()).map(|i| path.get_name(i));
+    let subnames = (0..path.get_subname_count()).map(|i| path.get_subname(i));
+
+    names
+        .chain(subnames)
+        .map(|name| GString::from(&name))
+        .collect()
+}
+
+fn non_empty_segments(path: &NodePath) -> Result<Vec<GString>, ConvertError> {
+    let segments = path_segments(path);
+    if segments.is_empty() {
+        return Err(ConvertError::new("path must not be empty"));
+    }
+
+    Ok(segments)
+}
+
+/// Follows all segments, starting at `root`.
+fn resolve_path(root: Variant, segments: &[GString]) -> Result<Variant, ConvertError> {
+    let mut current = root;
+    for (depth, segment) in segments.iter().enumerate() {
+        current = child(&current, segment, false)
+            .map_err(|err| prepend_segments(err, &segments[..=depth]))?;
+    }
+
+    Ok(current)
+}
+
+/// Follows all but the last segment, creating containers if necessary, then stores `value` under the last one.
+fn assign_path(root: &Variant, segments: &[GString], value: Variant) -> Result<(), ConvertError> {
+    let (last, parents) = segments.split_last().expect("path is not empty");
+
+    let mut current = root.clone();
+    for (depth, segment) in parents.iter().enumerate() {
+        current = child(&current, segment, true)
+            .map_err(|err| prepend_segments(err, &segments[..=depth]))?;
+    }
+
+    set_child(&current, last, value).map_err(|err| prepend_segments(err, segments))
+}
+
+/// Returns the element of `container` addressed by `segment`.
+///
+/// If `create` is true, missing or `null` elements are replaced with a new dictionary, which is returned.
+fn child(container: &Variant, segment: &GString, create: bool) -> Result<Variant, ConvertError> {
+    match container.get_type() {
+        VariantType::DICTIONARY => {
+            let dict = container.to::<AnyDictionary>();
+            let existing = dict_key(&dict, segment).and_then(|key| dict.get(&key));
+
+            match existing {
+                Some(value) if !(create && value.is_nil()) => Ok(value),
+                _ if create => {
+                    let new_dict = VarDictionary::new().to_variant();
+                    set_child(container, segment, new_dict.clone())?;
+                    Ok(new_dict)
+                }
+                _ => Err(ConvertError::with_error_value("missing key", dict)),
+            }
+        }
+        VariantType::ARRAY => {
+            let array = container.to::<AnyArray>();
+            let index = array_index(segment)?;
+
+            match array.get(index) {
+                Some(value) if !(create && value.is_nil()) => Ok(value),
+                _ if create && index <= array.len() => {
+                    let new_dict = VarDictionary::new().to_variant();
+                    set_child(container, segment, new_dict.clone())?;
+                    Ok(new_dict)
+                }
+                _ => Err(index_out_of_bounds(index, array)),
+            }
+        }
+        other => Err(ConvertError::with_error_value(
+            format!("cannot look up `{segment}` in value of type {other:?}"),
+            container.clone(),
+        )),
+    }
+}
+
+/// Stores `value` in `container` under `segment`. Arrays are extended by one if the index equals their length.
+fn set_child(container: &Variant, segment: &GString, value: Variant) -> Result<(), ConvertError> {
+    match container.get_type() {
+        VariantType::DICTIONARY => {
+            let dict = container.to::<AnyDictionary>();
+            if dict.is_read_only() {
+                return Err(ConvertError::with_error_value(
+                    "cannot modify read-only dictionary",
+                    dict,
+                ));
+            }
+
+            let key = dict_key(&dict, segment).unwrap_or_else(|| segment.to_variant());
+            let mut dict = dict.try_cast_var_dictionary().map_err(|dict| {
+                ConvertError::with_error_value(
+                    "cannot modify typed dictionary through a path",
+                    dict,
+                )
+            })?;
+
+            dict.set(&key, &value);
+            Ok(())
+        }
+        VariantType::ARRAY => {
+            let array = container.to::<AnyArray>();
+            let index = array_index(segment)?;
+
+            if array.is_read_only() {
+                return Err(ConvertError::with_error_value(
+                    "cannot modify read-only array",
+                    array,
+                ));
+            }
+
+            let mut array = array.try_cast_var_array().map_err(|array| {
+                ConvertError::with_error_value("cannot modify typed array through a path", array)
+            })?;
+
+            if index < array.len() {
+                array.set(index, &value);
+            } else if index == array.len() {
+                array.push(&value);
+            } else {
+                return Err(index_out_of_bounds(index, array.upcast_any_array()));
+            }
+
+            Ok(())
+        }
+        other => Err(ConvertError::with_error_value(
+            format!("cannot set `{segment}` in value of type {other:?}"),
+            container.clone(),
+        )),
+    }
+}
+
+/// Finds the key matching `segment`: the string itself, or the integer it represents.
+fn dict_key(dict: &AnyDictionary, segment: &GString) -> Option<Variant> {
+    let string_key = segment.to_variant();
+    if dict.contains_key(&string_key) {
+        return Some(string_key);
+    }
+
+    let int_key = segment.to_string().parse::<i64>().ok()?.to_variant();
+    dict.contains_key(&int_key).then_some(int_key)
+}
+
+fn array_index(segment: &GString) -> Result<usize, ConvertError> {
+    segment.to_string().parse::<usize>().map_err(|_| {
+        ConvertError::new(format!(
+            "array index must be a non-negative integer, got `{segment}`"
+        ))
+    })
+}
+
+fn index_out_of_bounds(index: usize, array: AnyArray) -> ConvertError {
+    let len = array.len();
+    ConvertError::with_error_value(
+        format!("index {index} out of bounds for array of length {len}"),
+        array,
+    )
+}
+
+/// Annotates an error with the path segments leading to it.
+fn prepend_segments(err: ConvertError, segments: &[GString]) -> ConvertError {
+    segments
+        .iter()
+        .rev()
+        .fold(err, |err, segment| err.with_field(segment.to_string()))
+}
@@ -46,13 +46,13 @@ pub mod __prelude_reexport {
     pub use rect2i::*;
     pub use rid::*;
     pub use signal::*;
-    pub use strings::{Encoding, GString, NodePath, StringName};
+    pub use strings::{Encoding, GString, GStringBuilder, GStringUtf8, NodePath, StringName};
     pub use variant::*;
     pub use vectors::*;
 
     pub use super::math::XformInv;
     pub use super::{EulerOrder, Side, VariantOperator, VariantType};
-    pub use crate::{array, real, reals, varray, vdict, vslice};
+    pub use crate::{array, node_path, real, reals, sname, varray, vdict, vslice};
 
     #[allow(deprecated)]
     #[rustfmt::skip] // Do not reorder.
@@ -27,6 +27,8 @@ use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 ///
 /// # Godot docs
 /// [`Signal` (stable)](https://docs.godotengine.org/en/stable/classes/class_signal.html)
+// Layout must match Godot's Signal, for borrowing from a Variant in `Variant::view()`.
+#[repr(transparent)]
 pub struct Signal {
     opaque: sys::types::OpaqueSignal,
 }
@@ -0,0 +1,236 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::GString;
+
+/// Incrementally builds a [`GString`], writing directly into Godot's UTF-32 buffer.
+///
+/// Appending to a `GString` through Rust usually requires a round-trip via `String`, transcoding the whole text and allocating twice.
+/// `GStringBuilder` instead transcodes each appended piece in place, growing the buffer geometrically like `String` does. It implements
+/// [`fmt::Write`], so it works with `write!` and `writeln!`.
+///
+/// Call [`finish()`][Self::finish] to obtain the resulting `GString`.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use std::fmt::Write;
+/// use godot::builtin::GStringBuilder;
+///
+/// let mut builder = GStringBuilder::new();
+/// for (name, score) in [("Alice", 120), ("Bob", 95)] {
+///     writeln!(builder, "{name}: {score}").unwrap();
+/// }
+/// builder.push('…');
+///
+/// let text: GString = builder.finish();
+/// assert_eq!(text, "Alice: 120\nBob: 95\n…");
+/// ```
+pub struct GStringBuilder {
+    string: GString,
+
+    /// Number of characters written so far. The buffer may be larger, see `capacity`.
+    len: usize,
+
+    /// Number of characters that fit into the buffer, excluding the NUL terminator.
+    capacity: usize,
+}
+
+impl GStringBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self {
+            string: GString::new(),
+            len: 0,
+            capacity: 0,
+        }
+    }
+
+    /// Creates an empty builder, with room for at least `capacity` characters (Unicode code points, not bytes).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut builder = Self::new();
+        builder.reserve(capacity);
+        builder
+    }
+
+    /// Continues building from an existing string, appending to its end.
+    pub fn from_gstring(string: GString) -> Self {
+        let len = string.len();
+
+        Self {
+            string,
+            len,
+            capacity: len,
+        }
+    }
+
+    /// Number of characters written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Ensures that at least `additional` more characters can be written without reallocating.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len + additional;
+        if required <= self.capacity {
+            return;
+        }
+
+        let new_capacity = required.max(self.capacity * 2);
+        self.resize_buffer(new_capacity);
+        self.capacity = new_capacity;
+    }
+
+    /// Appends a Rust string slice.
+    pub fn push_str(&mut self, s: &str) {
+        // Counting first is cheaper than transcoding twice or writing into a temporary buffer.
+        let char_count = s.chars().count();
+        if char_count == 0 {
+            return;
+        }
+
+        self.reserve(char_count);
+
+        let len = self.len;
+        let dest = self.buffer_mut();
+        for (slot, ch) in dest[len..].iter_mut().zip(s.chars()) {
+            *slot = ch as u32;
+        }
+        dest[len + char_count] = 0;
+
+        self.len += char_count;
+    }
+
+    /// Appends a single character.
+    pub fn push(&mut self, ch: char) {
+        self.reserve(1);
+
+        let len = self.len;
+        let dest = self.buffer_mut();
+        dest[len] = ch as u32;
+        dest[len + 1] = 0;
+
+        self.len += 1;
+    }
+
+    /// Appends the contents of another `GString`, without transcoding.
+    pub fn push_gstring(&mut self, string: &GString) {
+        let chars = string.chars();
+        if chars.is_empty() {
+            return;
+        }
+
+        self.reserve(chars.len());
+
+        let len = self.len;
+        let dest = self.buffer_mut();
+        for (slot, ch) in dest[len..].iter_mut().zip(chars) {
+            *slot = *ch as u32;
+        }
+        dest[len + chars.len()] = 0;
+
+        self.len += chars.len();
+    }
+
+    /// Clears the contents, keeping the allocated buffer.
+    pub fn clear(&mut self) {
+        if self.capacity > 0 {
+            self.buffer_mut()[0] = 0;
+        }
+
+        self.len = 0;
+    }
+
+    /// Returns the built string.
+    ///
+    /// Excess capacity is released, so the resulting `GString` has exactly the written length.
+    pub fn finish(mut self) -> GString {
+        if self.capacity != self.len {
+            self.resize_buffer(self.len);
+        }
+
+        self.string
+    }
+
+    /// Resizes the Godot buffer to hold `char_count` characters plus the NUL terminator, and terminates it at the current length.
+    fn resize_buffer(&mut self, char_count: usize) {
+        let size = i64::try_from(char_count + 1).expect("string length exceeds i64");
+
+        // SAFETY: `string_resize` reallocates the buffer and keeps its contents; the string is exclusively owned by the builder.
+        let error = unsafe { interface_fn!(string_resize)(self.string.string_sys_mut(), size) };
+        assert_eq!(error, 0, "failed to resize GString to {char_count} chars");
+
+        // Godot leaves new memory uninitialized and does not write the terminator. Zero everything after the written text, which also
+        // terminates the string.
+        let len = self.len.min(char_count);
+
+        // SAFETY: the buffer holds `char_count + 1` elements, `len` is within bounds.
+        unsafe {
+            let ptr = self.buffer_ptr();
+            ptr.add(len).write_bytes(0, char_count + 1 - len);
+        }
+    }
+
+    /// Mutable view of the whole buffer, i.e. `capacity` characters plus the NUL terminator.
+    fn buffer_mut(&mut self) -> &mut [u32] {
+        let size = self.capacity + 1;
+
+        // SAFETY: the buffer has been resized to `capacity + 1` elements, all of which are initialized (see `resize_buffer`).
+        unsafe { std::slice::from_raw_parts_mut(self.buffer_ptr(), size) }
+    }
+
+    fn buffer_ptr(&mut self) -> *mut u32 {
+        // SAFETY: `string_operator_index` triggers copy-on-write, so the memory is not shared with other strings. char32_t and u32 have
+        // the same layout.
+        unsafe {
+            interface_fn!(string_operator_index)(self.string.string_sys_mut(), 0).cast::<u32>()
+        }
+    }
+}
+
+impl Default for GStringBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Write for GStringBuilder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for GStringBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GStringBuilder")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl From<GStringBuilder> for GString {
+    fn from(builder: GStringBuilder) -> Self {
+        builder.finish()
+    }
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::borrow::Borrow;
+use std::cell::OnceCell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::builtin::GString;
+
+/// [`GString`] with a lazily computed UTF-8 copy, for read-heavy code.
+///
+/// `GString` stores UTF-32, so every access as `&str` requires transcoding and allocating. `GStringUtf8` performs this conversion at most
+/// once, on the first call to [`as_str()`][Self::as_str], and keeps the result next to the original string. This is useful for strings
+/// that are read many times from Rust, e.g. for logging, UI text or keys in Rust maps.
+///
+/// `GStringUtf8` implements `Borrow<str>`, so it can be used as a key in `HashMap` and looked up by `&str`. Equality, ordering and hashing
+/// are based on the text content.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use std::collections::HashMap;
+/// use godot::builtin::GStringUtf8;
+///
+/// let mut scores: HashMap<GStringUtf8, i32> = HashMap::new();
+/// scores.insert(GString::from("Alice").into(), 120);
+///
+/// assert_eq!(scores.get("Alice"), Some(&120));
+/// ```
+#[derive(Clone)]
+pub struct GStringUtf8 {
+    string: GString,
+    utf8: OnceCell<Box<str>>,
+}
+
+impl GStringUtf8 {
+    /// Wraps a `GString`. The UTF-8 copy is computed on first access.
+    pub fn new(string: GString) -> Self {
+        Self {
+            string,
+            utf8: OnceCell::new(),
+        }
+    }
+
+    /// Returns the string as UTF-8, converting it on first access.
+    pub fn as_str(&self) -> &str {
+        self.utf8
+            .get_or_init(|| String::from(&self.string).into_boxed_str())
+    }
+
+    /// Returns the original Godot string.
+    pub fn as_gstring(&self) -> &GString {
+        &self.string
+    }
+
+    /// Returns the original Godot string, discarding the UTF-8 copy.
+    pub fn into_gstring(self) -> GString {
+        self.string
+    }
+}
+
+impl From<GString> for GStringUtf8 {
+    fn from(string: GString) -> Self {
+        Self::new(string)
+    }
+}
+
+impl From<&str> for GStringUtf8 {
+    /// Creates the `GString`, and keeps a copy of `s` as the UTF-8 representation.
+    fn from(s: &str) -> Self {
+        Self {
+            string: GString::from(s),
+            utf8: OnceCell::from(Box::from(s)),
+        }
+    }
+}
+
+impl From<GStringUtf8> for GString {
+    fn from(string: GStringUtf8) -> Self {
+        string.into_gstring()
+    }
+}
+
+impl AsRef<str> for GStringUtf8 {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for GStringUtf8 {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for GStringUtf8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
+}
+
+impl Eq for GStringUtf8 {}
+
+impl PartialOrd for GStringUtf8 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GStringUtf8 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Must be consistent with `Borrow<str>`, so compare as str rather than GString.
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for GStringUtf8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Must be consistent with `Borrow<str>`.
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Display for GStringUtf8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for GStringUtf8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.string, f)
+    }
+}
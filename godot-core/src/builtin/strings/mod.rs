@@ -8,12 +8,16 @@
 //! Godot-types that are Strings.
 
 mod gstring;
+mod gstring_builder;
+mod gstring_utf8;
 mod macros;
 mod node_path;
 mod string_macros;
 mod string_name;
 
 pub use gstring::{ExFind as GStringExFind, ExSplit as GStringExSplit, *};
+pub use gstring_builder::GStringBuilder;
+pub use gstring_utf8::GStringUtf8;
 pub use node_path::NodePath;
 pub use string_name::{ExFind as StringNameExFind, ExSplit as StringNameExSplit, *};
 
@@ -32,6 +32,8 @@ use crate::meta::signed_range::SignedRange;
 /// # Godot docs
 ///
 /// [`NodePath` (stable)](https://docs.godotengine.org/en/stable/classes/class_nodepath.html)
+// Layout must match Godot's NodePath, for borrowing from a Variant in `Variant::view()`.
+#[repr(transparent)]
 pub struct NodePath {
     opaque: sys::types::OpaqueNodePath,
 }
@@ -342,3 +344,40 @@ mod serialize {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Macro
+
+/// Creates a [`NodePath`] from a string literal, validated at compile time and constructed only once.
+///
+/// The path is checked for syntax errors during compilation: empty names or subnames (e.g. `"Player//Sprite"` or a trailing `/`), and
+/// characters that Godot does not allow in node names. On first use, the `NodePath` is parsed by Godot and cached in a `static`; every
+/// later evaluation returns a `&'static NodePath` without allocating. This makes it suitable for hot paths such as `get_node` in `process()`.
+///
+/// The result can be passed to any `impl AsArg<NodePath>` parameter.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn example(node: Gd<Node>) {
+/// let sprite = node.get_node_as::<Sprite2D>(node_path!("Player/Sprite"));
+/// let color = node.get_node_and_resource(node_path!("Player/Sprite:modulate"));
+///
+/// let unique = node.get_node_or_null(node_path!("%HealthBar"));
+/// # }
+/// ```
+///
+/// Invalid paths fail to compile:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// let path = node_path!("Player//Sprite");
+/// ```
+#[macro_export]
+macro_rules! node_path {
+    ($path:literal) => {{
+        const COUNTS: (usize, usize) = $crate::private::validate_node_path($path);
+        static PATH: $crate::private::StaticNodePath = $crate::private::StaticNodePath::new();
+
+        PATH.get_or_init($path, COUNTS)
+    }};
+}
@@ -549,3 +549,41 @@ macro_rules! static_sname {
         SNAME.get_or_init(|| StringName::__cstr_with_static(c_str, true))
     }};
 }
+
+/// Creates a [`StringName`] from an ASCII string literal, constructed only once.
+///
+/// The `StringName` is created on first use through [`StringName::__cstr_with_static()`], which lets Godot reference the literal directly
+/// instead of copying it, and is then cached in a `static`. Every later evaluation returns a `&'static StringName` without allocating or
+/// looking up Godot's string table. This makes it suitable for method, signal or property names that are used repeatedly.
+///
+/// The literal must be ASCII and must not contain NUL bytes; this is checked at compile time. For other strings, use `StringName::from()`.
+///
+/// The result can be passed to any `impl AsArg<StringName>` parameter.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn example(mut node: Gd<Node>) {
+/// node.set(sname!("visible"), &false.to_variant());
+/// let is_connected = node.has_signal(sname!("tree_entered"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sname {
+    ($name:literal) => {{
+        const C_STR: &::std::ffi::CStr =
+            match ::std::ffi::CStr::from_bytes_with_nul(concat!($name, "\0").as_bytes()) {
+                Ok(c_str) => c_str,
+                Err(_) => panic!("sname!: string must not contain NUL bytes"),
+            };
+        const _: () = assert!(
+            C_STR.to_bytes().is_ascii(),
+            "sname!: string must be ASCII; use StringName::from() for other strings"
+        );
+
+        static SNAME: ::std::sync::OnceLock<$crate::builtin::StringName> =
+            ::std::sync::OnceLock::new();
+
+        SNAME.get_or_init(|| $crate::builtin::StringName::__cstr_with_static(C_STR, true))
+    }};
+}
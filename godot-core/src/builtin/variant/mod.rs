@@ -21,6 +21,13 @@ use crate::meta::{
 };
 
 mod impls;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(since_api = "4.4")]
+mod variant_view;
+
+#[cfg(since_api = "4.4")]
+pub use variant_view::{VariantEnum, VariantView};
 
 /// Godot variant type, able to store a variety of different types.
 ///
@@ -0,0 +1,274 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serde support for `Variant`.
+//!
+//! A variant is mapped to an externally tagged enum named `Variant`, with one enumerator per [`VariantType`]. The enumerator name is the
+//! Godot type name (e.g. `Vector2` or `PackedInt32Array`), and its index is the ordinal of the `VariantType`. `Nil` is a unit enumerator, all
+//! others are newtype enumerators wrapping the serialized value.
+//!
+//! In JSON, this looks like `{"Vector2": {"x": 1.0, "y": 2.0}}` or `"Nil"`.
+//!
+//! Types without a meaningful serialized representation (`RID`, `Object`, `Callable`, `Signal`) cause a serialization error. Typed arrays and
+//! dictionaries nested in a variant are serialized with their elements, but deserialized as untyped `VarArray` and `VarDictionary`.
+
+use std::fmt;
+
+use serde::de::{EnumAccess, Error as _, VariantAccess, Visitor};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::*;
+use crate::meta::ToGodot;
+use crate::obj::EngineEnum;
+
+const ENUM_NAME: &str = "Variant";
+
+/// Enumerator names, indexed by `VariantType` ordinal.
+const ENUMERATOR_NAMES: &[&str] = &[
+    "Nil",
+    "Bool",
+    "Int",
+    "Float",
+    "String",
+    "Vector2",
+    "Vector2i",
+    "Rect2",
+    "Rect2i",
+    "Vector3",
+    "Vector3i",
+    "Transform2D",
+    "Vector4",
+    "Vector4i",
+    "Plane",
+    "Quaternion",
+    "AABB",
+    "Basis",
+    "Transform3D",
+    "Projection",
+    "Color",
+    "StringName",
+    "NodePath",
+    "RID",
+    "Object",
+    "Callable",
+    "Signal",
+    "Dictionary",
+    "Array",
+    "PackedByteArray",
+    "PackedInt32Array",
+    "PackedInt64Array",
+    "PackedFloat32Array",
+    "PackedFloat64Array",
+    "PackedStringArray",
+    "PackedVector2Array",
+    "PackedVector3Array",
+    "PackedColorArray",
+    "PackedVector4Array",
+];
+
+// For "Available on crate feature `serde`" in docs. Cannot be inherited from module.
+#[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let variant_type = self.get_type();
+        let index = variant_type.ord() as u32;
+        let name = ENUMERATOR_NAMES
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| S::Error::custom(format!("unknown variant type {variant_type:?}")))?;
+
+        macro_rules! newtype {
+            ($T:ty) => {
+                serializer.serialize_newtype_variant(ENUM_NAME, index, name, &self.to::<$T>())
+            };
+        }
+
+        match variant_type {
+            VariantType::NIL => serializer.serialize_unit_variant(ENUM_NAME, index, name),
+            VariantType::BOOL => newtype!(bool),
+            VariantType::INT => newtype!(i64),
+            VariantType::FLOAT => newtype!(f64),
+            VariantType::STRING => newtype!(GString),
+            VariantType::VECTOR2 => newtype!(Vector2),
+            VariantType::VECTOR2I => newtype!(Vector2i),
+            VariantType::RECT2 => newtype!(Rect2),
+            VariantType::RECT2I => newtype!(Rect2i),
+            VariantType::VECTOR3 => newtype!(Vector3),
+            VariantType::VECTOR3I => newtype!(Vector3i),
+            VariantType::TRANSFORM2D => newtype!(Transform2D),
+            VariantType::VECTOR4 => newtype!(Vector4),
+            VariantType::VECTOR4I => newtype!(Vector4i),
+            VariantType::PLANE => newtype!(Plane),
+            VariantType::QUATERNION => newtype!(Quaternion),
+            VariantType::AABB => newtype!(Aabb),
+            VariantType::BASIS => newtype!(Basis),
+            VariantType::TRANSFORM3D => newtype!(Transform3D),
+            VariantType::PROJECTION => newtype!(Projection),
+            VariantType::COLOR => newtype!(Color),
+            VariantType::STRING_NAME => newtype!(StringName),
+            VariantType::NODE_PATH => newtype!(NodePath),
+            VariantType::DICTIONARY => newtype!(AnyDictionary),
+            VariantType::ARRAY => newtype!(AnyArray),
+            VariantType::PACKED_BYTE_ARRAY => newtype!(PackedByteArray),
+            VariantType::PACKED_INT32_ARRAY => newtype!(PackedInt32Array),
+            VariantType::PACKED_INT64_ARRAY => newtype!(PackedInt64Array),
+            VariantType::PACKED_FLOAT32_ARRAY => newtype!(PackedFloat32Array),
+            VariantType::PACKED_FLOAT64_ARRAY => newtype!(PackedFloat64Array),
+            VariantType::PACKED_STRING_ARRAY => newtype!(PackedStringArray),
+            VariantType::PACKED_VECTOR2_ARRAY => newtype!(PackedVector2Array),
+            VariantType::PACKED_VECTOR3_ARRAY => newtype!(PackedVector3Array),
+            VariantType::PACKED_COLOR_ARRAY => newtype!(PackedColorArray),
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => newtype!(PackedVector4Array),
+
+            // RID, OBJECT, CALLABLE, SIGNAL: identity or code, not data.
+            _ => Err(S::Error::custom(format!(
+                "Variant of type {variant_type:?} cannot be serialized"
+            ))),
+        }
+    }
+}
+
+#[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(ENUM_NAME, ENUMERATOR_NAMES, VariantVisitor)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Deserialization helpers
+
+struct VariantVisitor;
+
+impl<'de> Visitor<'de> for VariantVisitor {
+    type Value = Variant;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (VariantTag(variant_type), access) = data.variant::<VariantTag>()?;
+
+        macro_rules! newtype {
+            ($T:ty) => {
+                access
+                    .newtype_variant::<$T>()
+                    .map(|value| value.to_variant())
+            };
+        }
+
+        match variant_type {
+            VariantType::NIL => access.unit_variant().map(|()| Variant::nil()),
+            VariantType::BOOL => newtype!(bool),
+            VariantType::INT => newtype!(i64),
+            VariantType::FLOAT => newtype!(f64),
+            VariantType::STRING => newtype!(GString),
+            VariantType::VECTOR2 => newtype!(Vector2),
+            VariantType::VECTOR2I => newtype!(Vector2i),
+            VariantType::RECT2 => newtype!(Rect2),
+            VariantType::RECT2I => newtype!(Rect2i),
+            VariantType::VECTOR3 => newtype!(Vector3),
+            VariantType::VECTOR3I => newtype!(Vector3i),
+            VariantType::TRANSFORM2D => newtype!(Transform2D),
+            VariantType::VECTOR4 => newtype!(Vector4),
+            VariantType::VECTOR4I => newtype!(Vector4i),
+            VariantType::PLANE => newtype!(Plane),
+            VariantType::QUATERNION => newtype!(Quaternion),
+            VariantType::AABB => newtype!(Aabb),
+            VariantType::BASIS => newtype!(Basis),
+            VariantType::TRANSFORM3D => newtype!(Transform3D),
+            VariantType::PROJECTION => newtype!(Projection),
+            VariantType::COLOR => newtype!(Color),
+            VariantType::STRING_NAME => newtype!(StringName),
+            VariantType::NODE_PATH => newtype!(NodePath),
+            VariantType::DICTIONARY => newtype!(VarDictionary),
+            VariantType::ARRAY => newtype!(VarArray),
+            VariantType::PACKED_BYTE_ARRAY => newtype!(PackedByteArray),
+            VariantType::PACKED_INT32_ARRAY => newtype!(PackedInt32Array),
+            VariantType::PACKED_INT64_ARRAY => newtype!(PackedInt64Array),
+            VariantType::PACKED_FLOAT32_ARRAY => newtype!(PackedFloat32Array),
+            VariantType::PACKED_FLOAT64_ARRAY => newtype!(PackedFloat64Array),
+            VariantType::PACKED_STRING_ARRAY => newtype!(PackedStringArray),
+            VariantType::PACKED_VECTOR2_ARRAY => newtype!(PackedVector2Array),
+            VariantType::PACKED_VECTOR3_ARRAY => newtype!(PackedVector3Array),
+            VariantType::PACKED_COLOR_ARRAY => newtype!(PackedColorArray),
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => newtype!(PackedVector4Array),
+
+            _ => Err(A::Error::custom(format!(
+                "Variant of type {variant_type:?} cannot be deserialized"
+            ))),
+        }
+    }
+}
+
+/// Enumerator identifier, either by name or by index.
+struct VariantTag(VariantType);
+
+impl<'de> Deserialize<'de> for VariantTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(VariantTagVisitor)
+    }
+}
+
+struct VariantTagVisitor;
+
+impl Visitor<'_> for VariantTagVisitor {
+    type Value = VariantTag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant type name or index")
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        i32::try_from(index)
+            .ok()
+            .and_then(VariantType::try_from_ord)
+            .map(VariantTag)
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        ENUMERATOR_NAMES
+            .iter()
+            .position(|&n| n == name)
+            .and_then(|index| VariantType::try_from_ord(index as i32))
+            .map(VariantTag)
+            .ok_or_else(|| E::unknown_variant(name, ENUMERATOR_NAMES))
+    }
+
+    fn visit_bytes<E>(self, name: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match std::str::from_utf8(name) {
+            Ok(name) => self.visit_str(name),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(name), &self)),
+        }
+    }
+}
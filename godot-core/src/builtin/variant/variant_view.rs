@@ -0,0 +1,266 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+use crate::builtin::*;
+use crate::classes::Object;
+use crate::meta::ToGodot;
+use crate::obj::Gd;
+
+/// Declares `VariantView` and `VariantEnum` with one enumerator per variant type, plus conversions between them.
+///
+/// Each entry has an access kind, which determines how the value is obtained from the variant:
+/// - `copy`: small value types, read by value.
+/// - `borrow`: types whose Rust layout matches Godot's, borrowed directly from the variant's storage.
+/// - `shared`: reference-counted types that are cloned (refcount increment), because their Rust representation carries extra state.
+macro_rules! impl_variant_enums {
+    (
+        $(
+            $( #[$attr:meta] )*
+            $Enumerator:ident ( $Type:ty ) = $VARIANT_TYPE:ident, $kind:ident;
+        )*
+    ) => {
+        /// Borrowed view of a [`Variant`]'s value, for pattern matching without conversions.
+        ///
+        /// Obtained through [`Variant::view()`]. There is one enumerator per [`VariantType`], holding the contained value:
+        /// - Value types such as `bool`, `i64` or `Vector3` are copied.
+        /// - Strings, `Callable`, `Signal` and packed arrays are borrowed from the variant, without copying or incrementing a refcount.
+        /// - [`AnyArray`] and [`AnyDictionary`] are returned by value, which increments their refcount but does not copy elements. Their Rust
+        ///   representation caches the element type and thus cannot be borrowed from Godot memory.
+        /// - Objects are returned as `Option<Gd<Object>>`, which is `None` if the object has been freed.
+        ///
+        /// To obtain an owned version that outlives the variant, use [`into_owned()`][Self::into_owned].
+        ///
+        /// Requires Godot 4.4 or later, which provides access to a variant's internal storage.
+        ///
+        /// # Example
+        /// ```no_run
+        /// # use godot::prelude::*;
+        /// use godot::builtin::VariantView;
+        ///
+        /// fn describe(value: &Variant) -> String {
+        ///     match value.view() {
+        ///         VariantView::Nil => "nothing".to_string(),
+        ///         VariantView::Int(i) => format!("number {i}"),
+        ///         VariantView::Str(s) if s.is_empty() => "empty string".to_string(),
+        ///         VariantView::Str(s) => format!("string {s}"),
+        ///         VariantView::Array(array) => format!("array of length {}", array.len()),
+        ///         VariantView::Object(Some(obj)) => format!("object {}", obj.get_class()),
+        ///         VariantView::Object(None) => "freed object".to_string(),
+        ///         other => format!("something else: {other:?}"),
+        ///     }
+        /// }
+        /// ```
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum VariantView<'a> {
+            /// Variant is `null`.
+            Nil,
+            $(
+                $( #[$attr] )*
+                $Enumerator(impl_variant_enums!(@view_type 'a, $kind, $Type)),
+            )*
+            /// Object, or `None` if the object has been freed.
+            Object(Option<Gd<Object>>),
+        }
+
+        /// Owned counterpart of [`VariantView`], holding a [`Variant`]'s value.
+        ///
+        /// Unlike `VariantView`, this does not borrow from the variant. Convert from and to `Variant` with `From`/`Into`, or from a view with
+        /// [`VariantView::into_owned()`].
+        ///
+        /// Requires Godot 4.4 or later, like `VariantView`.
+        ///
+        /// ```no_run
+        /// # use godot::prelude::*;
+        /// use godot::builtin::VariantEnum;
+        ///
+        /// let value = VariantEnum::from(&Vector2::new(1.0, 2.0).to_variant());
+        /// assert_eq!(value, VariantEnum::Vector2(Vector2::new(1.0, 2.0)));
+        ///
+        /// let variant = Variant::from(VariantEnum::Str("hello".into()));
+        /// assert_eq!(variant, "hello".to_variant());
+        /// ```
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum VariantEnum {
+            /// Variant is `null`.
+            Nil,
+            $(
+                $( #[$attr] )*
+                $Enumerator($Type),
+            )*
+            /// Object, or `None` if the object has been freed.
+            Object(Option<Gd<Object>>),
+        }
+
+        impl Variant {
+            /// Returns a [`VariantView`] of this variant's value, for pattern matching.
+            ///
+            /// Strings, callables, signals and packed arrays are borrowed rather than copied. See [`VariantView`] for details.
+            ///
+            /// Requires Godot 4.4 or later.
+            pub fn view(&self) -> VariantView<'_> {
+                match self.get_type() {
+                    VariantType::NIL => VariantView::Nil,
+                    VariantType::OBJECT => VariantView::Object(self.try_to::<Gd<Object>>().ok()),
+                    $(
+                        $( #[$attr] )*
+                        VariantType::$VARIANT_TYPE => VariantView::$Enumerator(
+                            impl_variant_enums!(@view_value self, $kind, $Type, $VARIANT_TYPE)
+                        ),
+                    )*
+
+                    // Panic can be removed as soon as VariantType is a proper, non-exhaustive enum.
+                    other => panic!("Variant type not supported: {other:?}"),
+                }
+            }
+        }
+
+        impl VariantView<'_> {
+            /// Converts to the owned [`VariantEnum`], cloning borrowed values.
+            pub fn into_owned(self) -> VariantEnum {
+                match self {
+                    Self::Nil => VariantEnum::Nil,
+                    Self::Object(obj) => VariantEnum::Object(obj),
+                    $(
+                        $( #[$attr] )*
+                        Self::$Enumerator(value) => VariantEnum::$Enumerator(
+                            impl_variant_enums!(@owned_value value, $kind)
+                        ),
+                    )*
+                }
+            }
+
+            /// Creates a new `Variant` holding the viewed value.
+            pub fn to_variant(&self) -> Variant {
+                match self {
+                    Self::Nil => Variant::nil(),
+                    Self::Object(obj) => obj.to_variant(),
+                    $(
+                        $( #[$attr] )*
+                        Self::$Enumerator(value) => value.to_variant(),
+                    )*
+                }
+            }
+        }
+
+        impl VariantEnum {
+            /// Creates a new `Variant` holding this value.
+            pub fn to_variant(&self) -> Variant {
+                match self {
+                    Self::Nil => Variant::nil(),
+                    Self::Object(obj) => obj.to_variant(),
+                    $(
+                        $( #[$attr] )*
+                        Self::$Enumerator(value) => value.to_variant(),
+                    )*
+                }
+            }
+        }
+    };
+
+    (@view_type $lt:lifetime, copy, $Type:ty) => { $Type };
+    (@view_type $lt:lifetime, borrow, $Type:ty) => { &$lt $Type };
+    (@view_type $lt:lifetime, shared, $Type:ty) => { $Type };
+
+    (@view_value $variant:ident, copy, $Type:ty, $VARIANT_TYPE:ident) => {
+        $variant.to::<$Type>()
+    };
+    (@view_value $variant:ident, borrow, $Type:ty, $VARIANT_TYPE:ident) => {
+        // SAFETY: the variant holds a value of this type; the type is #[repr(transparent)] over Godot's representation.
+        unsafe { borrow_internal::<$Type>($variant, VariantType::$VARIANT_TYPE) }
+    };
+    (@view_value $variant:ident, shared, $Type:ty, $VARIANT_TYPE:ident) => {
+        $variant.to::<$Type>()
+    };
+
+    (@owned_value $value:ident, copy) => { $value };
+    (@owned_value $value:ident, borrow) => { $value.clone() };
+    (@owned_value $value:ident, shared) => { $value };
+}
+
+impl_variant_enums! {
+    Bool(bool) = BOOL, copy;
+    Int(i64) = INT, copy;
+    Float(f64) = FLOAT, copy;
+    Str(GString) = STRING, borrow;
+    Vector2(Vector2) = VECTOR2, copy;
+    Vector2i(Vector2i) = VECTOR2I, copy;
+    Rect2(Rect2) = RECT2, copy;
+    Rect2i(Rect2i) = RECT2I, copy;
+    Vector3(Vector3) = VECTOR3, copy;
+    Vector3i(Vector3i) = VECTOR3I, copy;
+    Transform2D(Transform2D) = TRANSFORM2D, copy;
+    Vector4(Vector4) = VECTOR4, copy;
+    Vector4i(Vector4i) = VECTOR4I, copy;
+    Plane(Plane) = PLANE, copy;
+    Quaternion(Quaternion) = QUATERNION, copy;
+    Aabb(Aabb) = AABB, copy;
+    Basis(Basis) = BASIS, copy;
+    Transform3D(Transform3D) = TRANSFORM3D, copy;
+    Projection(Projection) = PROJECTION, copy;
+    Color(Color) = COLOR, copy;
+    StringName(StringName) = STRING_NAME, borrow;
+    NodePath(NodePath) = NODE_PATH, borrow;
+    Rid(Rid) = RID, copy;
+    Callable(Callable) = CALLABLE, borrow;
+    Signal(Signal) = SIGNAL, borrow;
+    Dictionary(AnyDictionary) = DICTIONARY, shared;
+    Array(AnyArray) = ARRAY, shared;
+    PackedByteArray(PackedByteArray) = PACKED_BYTE_ARRAY, borrow;
+    PackedInt32Array(PackedInt32Array) = PACKED_INT32_ARRAY, borrow;
+    PackedInt64Array(PackedInt64Array) = PACKED_INT64_ARRAY, borrow;
+    PackedFloat32Array(PackedFloat32Array) = PACKED_FLOAT32_ARRAY, borrow;
+    PackedFloat64Array(PackedFloat64Array) = PACKED_FLOAT64_ARRAY, borrow;
+    PackedStringArray(PackedStringArray) = PACKED_STRING_ARRAY, borrow;
+    PackedVector2Array(PackedVector2Array) = PACKED_VECTOR2_ARRAY, borrow;
+    PackedVector3Array(PackedVector3Array) = PACKED_VECTOR3_ARRAY, borrow;
+    PackedColorArray(PackedColorArray) = PACKED_COLOR_ARRAY, borrow;
+    #[cfg(since_api = "4.3")]
+    PackedVector4Array(PackedVector4Array) = PACKED_VECTOR4_ARRAY, borrow;
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions
+
+impl<'a> From<VariantView<'a>> for VariantEnum {
+    fn from(view: VariantView<'a>) -> Self {
+        view.into_owned()
+    }
+}
+
+impl From<&Variant> for VariantEnum {
+    fn from(variant: &Variant) -> Self {
+        variant.view().into_owned()
+    }
+}
+
+impl From<VariantEnum> for Variant {
+    fn from(value: VariantEnum) -> Self {
+        value.to_variant()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Private helpers
+
+/// Borrows the value stored inside `variant`, without copying it.
+///
+/// # Safety
+/// `variant` must hold a value of `variant_type`, and `T` must have the same memory layout as Godot's representation of that type.
+unsafe fn borrow_internal<T>(variant: &Variant, variant_type: VariantType) -> &T {
+    let getter = unsafe { interface_fn!(get_variant_get_internal_ptr_func)(variant_type.sys()) };
+    let getter =
+        getter.unwrap_or_else(|| panic!("missing internal pointer getter for {variant_type:?}"));
+
+    // SAFETY: the getter returns a pointer into the variant's storage, which lives as long as `variant` is borrowed.
+    unsafe {
+        let ptr = getter(sys::SysPtr::force_mut(variant.var_sys()));
+        &*(ptr as *const T)
+    }
+}
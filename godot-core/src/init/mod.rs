@@ -44,6 +44,8 @@ unsafe extern "C" fn frame_func<E: ExtensionLibrary>() {
     let ctx = || "failed during MainLoop frame".to_string();
 
     swallow_panics(ctx, || {
+        // Drive timer and frame futures before user code, so that woken tasks are polled within the same frame.
+        crate::task::on_main_loop_frame();
         E::on_main_loop_frame();
     });
 }
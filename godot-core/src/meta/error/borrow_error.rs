@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+use std::thread::ThreadId;
+
+/// Kind of borrow of a user instance.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BorrowKind {
+    /// Shared borrow, through [`Gd::bind()`][crate::obj::Gd::bind] or a `#[func]` taking `&self`.
+    Shared,
+
+    /// Exclusive borrow, through [`Gd::bind_mut()`][crate::obj::Gd::bind_mut] or a `#[func]` taking `&mut self`.
+    Exclusive,
+
+    /// Exclusive borrow which is temporarily released through [`base_mut()`][crate::obj::WithBaseField::base_mut], so that re-entrant
+    /// calls can bind the instance again.
+    Inaccessible,
+}
+
+impl fmt::Display for BorrowKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Shared => "shared (bind)",
+            Self::Exclusive => "exclusive (bind_mut)",
+            Self::Inaccessible => "inaccessible (base_mut)",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// Live borrow of a user instance, as recorded by the borrow tracker.
+///
+/// Borrows are only tracked with the `safeguards_strict` level (default in Debug builds). Backtraces are only captured if the
+/// `RUST_BACKTRACE` environment variable is set, since capturing them unconditionally is slow.
+///
+/// See [`Gd::live_borrows()`][crate::obj::Gd::live_borrows].
+#[derive(Clone, Debug)]
+pub struct BorrowInfo {
+    kind: BorrowKind,
+    thread_id: ThreadId,
+    backtrace: Option<String>,
+}
+
+impl BorrowInfo {
+    #[cfg_attr(not(safeguards_strict), allow(dead_code))]
+    pub(crate) fn new(kind: BorrowKind, thread_id: ThreadId, backtrace: Option<String>) -> Self {
+        Self {
+            kind,
+            thread_id,
+            backtrace,
+        }
+    }
+
+    /// Whether the borrow is shared, exclusive or inaccessible.
+    pub fn kind(&self) -> BorrowKind {
+        self.kind
+    }
+
+    /// Thread on which the borrow was started.
+    pub fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    /// Backtrace at the point where the borrow was started, if captured.
+    pub fn backtrace(&self) -> Option<&str> {
+        self.backtrace.as_deref()
+    }
+}
+
+impl fmt::Display for BorrowInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} borrow on thread {:?}", self.kind, self.thread_id)?;
+
+        match &self.backtrace {
+            Some(backtrace) => write!(f, ", started at:\n{backtrace}"),
+            None => write!(
+                f,
+                " (backtrace not captured, run application with `RUST_BACKTRACE=1` environment variable)"
+            ),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when binding a [`Gd`][crate::obj::Gd] fails due to conflicting borrows.
+///
+/// Returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+#[derive(Clone, Debug)]
+pub struct BorrowError {
+    requested: BorrowKind,
+    class_name: &'static str,
+    reason: String,
+    live_borrows: Vec<BorrowInfo>,
+}
+
+impl BorrowError {
+    pub(crate) fn new(
+        requested: BorrowKind,
+        class_name: &'static str,
+        reason: String,
+        live_borrows: Vec<BorrowInfo>,
+    ) -> Self {
+        Self {
+            requested,
+            class_name,
+            reason,
+            live_borrows,
+        }
+    }
+
+    /// The kind of borrow that was attempted; [`Shared`][BorrowKind::Shared] or [`Exclusive`][BorrowKind::Exclusive].
+    pub fn requested_kind(&self) -> BorrowKind {
+        self.requested
+    }
+
+    /// Borrows that were live at the time of the failed attempt.
+    ///
+    /// Empty unless borrows are tracked, see [`BorrowInfo`].
+    pub fn live_borrows(&self) -> &[BorrowInfo] {
+        &self.live_borrows
+    }
+
+    /// Lists all live borrows, one paragraph each.
+    pub(crate) fn live_borrows_report(&self) -> String {
+        format_live_borrows(&self.live_borrows)
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ty = self.class_name;
+
+        match self.requested {
+            BorrowKind::Exclusive => write!(
+                f,
+                "Gd<T>::bind_mut() failed, already bound; T = {ty}.\n  \
+                Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
+                Details: {}.",
+                self.reason
+            ),
+            _ => write!(
+                f,
+                "Gd<T>::bind() failed, already bound; T = {ty}.\n  \
+                Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
+                Details: {}.",
+                self.reason
+            ),
+        }
+    }
+}
+
+impl Error for BorrowError {}
+
+/// Human-readable list of live borrows, used in panic messages and `Gd::live_borrows()` dumps.
+pub(crate) fn format_live_borrows(borrows: &[BorrowInfo]) -> String {
+    if borrows.is_empty() {
+        return "no live borrows tracked.\n".to_string();
+    }
+
+    let mut report = format!("{} live borrow(s):\n", borrows.len());
+    for (i, borrow) in borrows.iter().enumerate() {
+        report += &format!("[{i}] {borrow}\n");
+    }
+
+    report
+}
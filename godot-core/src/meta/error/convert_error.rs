@@ -22,6 +22,8 @@ type Cause = Box<dyn Error + Send + Sync>;
 pub struct ConvertError {
     kind: ErrorKind,
     value: Option<Variant>,
+    /// Dictionary keys and array indices leading to the value that failed to convert, outermost first.
+    field_path: Vec<String>,
 }
 
 impl ConvertError {
@@ -38,7 +40,10 @@ impl ConvertError {
     /// Create a new custom error for a conversion, without associated value.
     #[allow(dead_code)] // Needed a few times already, stays to prevent churn on refactorings.
     pub(crate) fn with_kind(kind: ErrorKind) -> Self {
-        Self { kind, value: None }
+        Self {
+            kind,
+            ..Default::default()
+        }
     }
 
     /// Create a new custom error for a conversion with the value that failed to convert.
@@ -49,6 +54,7 @@ impl ConvertError {
         Self {
             kind,
             value: Some(value.to_variant()),
+            field_path: Vec::new(),
         }
     }
 
@@ -72,6 +78,25 @@ impl ConvertError {
         Self {
             kind: ErrorKind::Custom(Some(error.into())),
             value: Some(value.to_variant()),
+            field_path: Vec::new(),
+        }
+    }
+
+    /// Records that the error occurred inside the field (or element) `field` of an enclosing value.
+    ///
+    /// Called from the outside in while an error propagates up, so that nested conversions produce a full path like `stats.health`.
+    /// Used by `#[derive(GodotConvert)]` for dictionary- and array-based conversions.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field_path.insert(0, field.into());
+        self
+    }
+
+    /// Returns the path to the field that failed to convert, e.g. `stats.health`, if the error occurred inside a nested value.
+    pub fn field_path(&self) -> Option<String> {
+        if self.field_path.is_empty() {
+            None
+        } else {
+            Some(self.field_path.join("."))
         }
     }
 
@@ -102,6 +127,10 @@ impl ConvertError {
 
 impl fmt::Display for ConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = self.field_path() {
+            write!(f, "at `{path}`: ")?;
+        }
+
         write!(f, "{}", self.kind)?;
 
         if let Some(value) = &self.value {
@@ -126,6 +155,7 @@ impl Default for ConvertError {
         Self {
             kind: ErrorKind::Custom(None),
             value: None,
+            field_path: Vec::new(),
         }
     }
 }
@@ -134,17 +164,24 @@ impl Default for ConvertError {
 #[derive(Debug)]
 pub(crate) struct ErasedConvertError {
     kind: ErrorKind,
+    field_path: Vec<String>,
 }
 
 impl From<ConvertError> for ErasedConvertError {
     fn from(v: ConvertError) -> Self {
-        let ConvertError { kind, .. } = v;
-        Self { kind }
+        let ConvertError {
+            kind, field_path, ..
+        } = v;
+        Self { kind, field_path }
     }
 }
 
 impl fmt::Display for ErasedConvertError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.field_path.is_empty() {
+            write!(f, "at `{}`: ", self.field_path.join("."))?;
+        }
+
         write!(f, "{}", self.kind)
     }
 }
@@ -7,12 +7,14 @@
 
 //! Errors in the gdext library.
 
+mod borrow_error;
 mod call_error;
 mod call_error_type;
 mod convert_error;
 mod io_error;
 mod string_error;
 
+pub use borrow_error::*;
 pub use call_error::*;
 pub use call_error_type::*;
 pub use convert_error::*;
@@ -187,6 +187,19 @@ impl PropertyInfo {
         }
     }
 
+    /// Create a new `PropertyInfo` representing a category in Godot.
+    ///
+    /// Categories are displayed as headers in the inspector, like the ones separating the properties of different classes.
+    pub fn new_category(category_name: &str) -> Self {
+        Self {
+            variant_type: VariantType::NIL,
+            class_id: ClassId::none(),
+            property_name: category_name.into(),
+            hint_info: PropertyHintInfo::none(),
+            usage: PropertyUsageFlags::CATEGORY,
+        }
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Introspection API -- could be made public in the future
 
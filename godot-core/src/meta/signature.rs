@@ -78,7 +78,7 @@ where
         default_values: &[Variant],
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
-        func: unsafe fn(sys::GDExtensionClassInstancePtr, Params) -> Ret,
+        func: impl FnOnce(sys::GDExtensionClassInstancePtr, Params) -> Ret,
     ) -> CallResult<()> {
         //$crate::out!("in_varcall: {call_ctx}");
         let arg_count = arg_count as usize;
@@ -91,7 +91,7 @@ where
         let args =
             unsafe { Params::from_varcall_args(args_ptr, arg_count, default_values, call_ctx)? };
 
-        let rust_result = unsafe { func(instance_ptr, args) };
+        let rust_result = func(instance_ptr, args);
         // SAFETY: TODO.
         unsafe { varcall_return::<Ret>(rust_result, ret, err) };
         Ok(())
@@ -108,7 +108,7 @@ where
         call_ctx: &CallContext,
         args_ptr: *const sys::GDExtensionConstTypePtr,
         ret: sys::GDExtensionTypePtr,
-        func: fn(sys::GDExtensionClassInstancePtr, Params) -> Ret,
+        func: impl FnOnce(sys::GDExtensionClassInstancePtr, Params) -> Ret,
         call_type: sys::PtrcallType,
     ) -> CallResult<()> {
         // $crate::out!("in_ptrcall: {call_ctx}");
@@ -13,7 +13,9 @@ use godot_ffi::is_main_thread;
 use sys::{SysPtr as _, static_assert_eq_size_align};
 
 use crate::builtin::{Callable, NodePath, StringName, Variant};
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{
+    BorrowError, BorrowInfo, ConvertError, FromFfiError, format_live_borrows,
+};
 use crate::meta::{
     AsArg, ClassId, Element, FromGodot, GodotConvert, GodotType, PropertyHintInfo, RefArg, ToGodot,
 };
@@ -203,6 +205,52 @@ where
     pub fn bind_mut(&mut self) -> GdMut<'_, T> {
         self.raw.bind_mut()
     }
+
+    /// Fallible version of [`bind()`][Self::bind], returning an error instead of panicking if the instance is exclusively bound.
+    ///
+    /// The [`BorrowError`] describes the conflict and, with strict safeguards, lists all borrows that were live at the time.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)] #[class(init)] struct MyClass {}
+    /// let obj = MyClass::new_gd();
+    /// let mut other = obj.clone();
+    /// let _guard = other.bind_mut();
+    ///
+    /// match obj.try_bind() {
+    ///     Ok(_guard) => unreachable!("bound exclusively through `other`"),
+    ///     Err(err) => godot_print!("{err}"),
+    /// }
+    /// ```
+    pub fn try_bind(&self) -> Result<GdRef<'_, T>, BorrowError> {
+        self.raw.try_bind()
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut], returning an error instead of panicking if the instance is already bound.
+    ///
+    /// See [`try_bind()`][Self::try_bind] for details on the error.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BorrowError> {
+        self.raw.try_bind_mut()
+    }
+
+    /// Returns all borrows of the user instance that are currently live.
+    ///
+    /// This includes guards from [`bind()`][Self::bind] and [`bind_mut()`][Self::bind_mut], receivers of ongoing `#[func]` calls, and
+    /// exclusive borrows temporarily released through [`base_mut()`][crate::obj::WithBaseField::base_mut]. Useful to diagnose
+    /// "already bound" errors, see also [`live_borrows_report()`][Self::live_borrows_report].
+    ///
+    /// Borrows are only tracked with strict safeguards (default in Debug builds); otherwise the list is always empty.
+    pub fn live_borrows(&self) -> Vec<BorrowInfo> {
+        self.raw.live_borrows()
+    }
+
+    /// Human-readable dump of [`live_borrows()`][Self::live_borrows], one entry per borrow.
+    ///
+    /// Backtraces are included if the application is run with the `RUST_BACKTRACE=1` environment variable.
+    pub fn live_borrows_report(&self) -> String {
+        format_live_borrows(&self.live_borrows())
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -9,13 +9,14 @@ use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
 #[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::blocking::InaccessibleGuard;
 #[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::panicking::InaccessibleGuard;
 use godot_ffi::out;
 
 use crate::obj::script::ScriptInstance;
 use crate::obj::{AsDyn, Gd, GodotClass, PassiveGd};
+use crate::storage::{StorageInaccessible, StorageMut, StorageRef};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
@@ -23,11 +24,11 @@ use crate::obj::{AsDyn, Gd, GodotClass, PassiveGd};
 // GdRef could technically implement Clone, but it wasn't needed so far.
 #[derive(Debug)]
 pub struct GdRef<'a, T: GodotClass> {
-    guard: RefGuard<'a, T>,
+    guard: StorageRef<'a, T>,
 }
 
 impl<'a, T: GodotClass> GdRef<'a, T> {
-    pub(crate) fn from_guard(guard: RefGuard<'a, T>) -> Self {
+    pub(crate) fn from_guard(guard: StorageRef<'a, T>) -> Self {
         Self { guard }
     }
 }
@@ -53,11 +54,11 @@ impl<T: GodotClass> Drop for GdRef<'_, T> {
 /// See [`Gd::bind_mut`][crate::obj::Gd::bind_mut] for usage.
 #[derive(Debug)]
 pub struct GdMut<'a, T: GodotClass> {
-    guard: MutGuard<'a, T>,
+    guard: StorageMut<'a, T>,
 }
 
 impl<'a, T: GodotClass> GdMut<'a, T> {
-    pub(crate) fn from_guard(guard: MutGuard<'a, T>) -> Self {
+    pub(crate) fn from_guard(guard: StorageMut<'a, T>) -> Self {
         Self { guard }
     }
 }
@@ -224,7 +225,7 @@ macro_rules! make_base_ref {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 macro_rules! make_base_mut {
-    ($ident:ident, $bound:ident, $doc_type:ident, $doc_path:path, $object_name:literal) => {
+    ($ident:ident, $bound:ident, $guard:ident, $doc_type:ident, $doc_path:path, $object_name:literal) => {
         /// Mutable/exclusive reference guard for a [`Base`](crate::obj::Base) pointer.
         ///
         /// This can be used to call methods on the base object of a Rust object, which takes `&self` or `&mut self` as the receiver.
@@ -232,13 +233,13 @@ macro_rules! make_base_mut {
         #[doc = concat!("See [`", stringify!($doc_type), "::base_mut()`](", stringify!($doc_path), "::base_mut()) for usage.\n")]
         pub struct $ident<'a, T: $bound> {
             passive_gd: PassiveGd<T::Base>,
-            _inaccessible_guard: InaccessibleGuard<'a, T>,
+            _inaccessible_guard: $guard<'a, T>,
         }
 
         impl<'a, T: $bound> $ident<'a, T> {
             pub(crate) fn new(
                 passive_gd: PassiveGd<T::Base>,
-                inaccessible_guard: InaccessibleGuard<'a, T>,
+                inaccessible_guard: $guard<'a, T>,
             ) -> Self {
                 Self {
                     passive_gd,
@@ -273,6 +274,7 @@ make_base_ref!(
 make_base_mut!(
     BaseMut,
     GodotClass,
+    StorageInaccessible,
     WithBaseField,
     super::WithBaseField,
     "rust object"
@@ -288,6 +290,7 @@ make_base_ref!(
 make_base_mut!(
     ScriptBaseMut,
     ScriptInstance,
+    InaccessibleGuard,
     SiMut,
     crate::obj::script::SiMut,
     "['ScriptInstance']"
@@ -13,7 +13,7 @@ use sys::{ExtVariantType, GodotFfi, GodotNullableFfi, PtrcallType, interface_fn}
 use crate::builtin::{Variant, VariantType};
 #[cfg(safeguards_balanced)]
 use crate::meta::CallContext;
-use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::error::{BorrowError, BorrowInfo, ConvertError, FromVariantError};
 use crate::meta::{ClassId, FromGodot, GodotConvert, GodotFfiVariant, GodotType, RefArg, ToGodot};
 use crate::obj::bounds::{Declarer, DynMemory as _};
 use crate::obj::casts::CastSuccess;
@@ -476,6 +476,24 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking on conflicting borrows.
+    pub(crate) fn try_bind(&self) -> Result<GdRef<'_, T>, BorrowError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking on conflicting borrows.
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BorrowError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
+    /// Borrows of the user instance which are currently live; empty unless tracked.
+    pub(crate) fn live_borrows(&self) -> Vec<BorrowInfo> {
+        self.check_rtti("live_borrows");
+        self.storage().unwrap().live_borrows()
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
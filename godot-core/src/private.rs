@@ -12,9 +12,10 @@ use std::sync::atomic;
 
 use sys::Global;
 
+use crate::builtin::{NodePath, VarArray, VarDictionary, Variant};
 use crate::global::godot_error;
-use crate::meta::CallContext;
-use crate::meta::error::{CallError, CallResult};
+use crate::meta::error::{CallError, CallResult, ConvertError};
+use crate::meta::{CallContext, FromGodot};
 use crate::obj::Gd;
 use crate::registry::property::Var;
 use crate::{classes, sys};
@@ -41,6 +42,9 @@ mod reexport_pub {
         as_storage,
     };
     pub use crate::sys::out;
+    pub use crate::task::async_func::spawn_async_func;
+    #[cfg(feature = "codegen-full")]
+    pub use crate::tools::property_editor::ErasedPropertyEditor;
 }
 pub use reexport_pub::*;
 
@@ -196,6 +200,187 @@ pub struct ClassConfig {
 pub fn typecheck_getter<C, T: Var>(_getter: impl Fn(&C) -> T::PubType) {}
 pub fn typecheck_setter<C, T: Var>(_setter: fn(&mut C, T::PubType)) {}
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Support for #[derive(GodotConvert)] with dictionary or array representation
+
+/// Converts the value stored under `key`, returning `Ok(None)` if the key is absent.
+///
+/// Conversion errors are annotated with `key`, so nested structs report the full path.
+pub fn convert_dict_field<T: FromGodot>(
+    dict: &VarDictionary,
+    key: &str,
+) -> Result<Option<T>, ConvertError> {
+    match dict.get(key) {
+        Some(value) => value.try_to::<T>().map(Some).map_err(|e| e.with_field(key)),
+        None => Ok(None),
+    }
+}
+
+/// Error for a required key that is absent in the dictionary.
+pub fn convert_missing_key(dict: &VarDictionary, key: &str) -> ConvertError {
+    ConvertError::with_error_value("missing key", dict.clone()).with_field(key)
+}
+
+/// Converts the element at `index`, returning `Ok(None)` if the array is too short.
+///
+/// Conversion errors are annotated with `index`.
+pub fn convert_array_element<T: FromGodot>(
+    array: &VarArray,
+    index: usize,
+) -> Result<Option<T>, ConvertError> {
+    match array.get(index) {
+        Some(value) => value
+            .try_to::<T>()
+            .map(Some)
+            .map_err(|e| e.with_field(index.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Error for a required element that is absent, because the array is too short.
+pub fn convert_missing_element(array: &VarArray, index: usize) -> ConvertError {
+    ConvertError::with_error_value("missing array element", array.clone())
+        .with_field(index.to_string())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Support for node_path! and sname! macros
+
+/// Checks the syntax of a `node_path!` literal at compile time, returning the number of names and subnames.
+///
+/// Panics (i.e. fails compilation when evaluated in a `const`) on empty names or subnames, and on invalid characters in node names.
+pub const fn validate_node_path(path: &str) -> (usize, usize) {
+    let bytes = path.as_bytes();
+    if bytes.is_empty() {
+        panic!("node_path!: path must not be empty; use NodePath::default() instead");
+    }
+
+    let is_absolute = bytes[0] == b'/';
+    let mut names = 0;
+    let mut subnames = 0;
+    let mut in_subnames = false;
+    let mut start = if is_absolute { 1 } else { 0 };
+    let mut i = start;
+
+    while i <= bytes.len() {
+        let at_end = i == bytes.len();
+        let ends_name = !in_subnames && !at_end && bytes[i] == b'/';
+        let ends_segment = at_end || ends_name || bytes[i] == b':';
+
+        if ends_segment {
+            if i == start {
+                // Allowed: "/" (root) and paths consisting only of subnames, such as ":position".
+                let is_root = at_end && is_absolute && i == 1;
+                let is_leading_colon = !at_end && i == 0 && bytes[i] == b':';
+
+                if !is_root && !is_leading_colon {
+                    panic!(
+                        "node_path!: empty name or subname; check for `//`, `::` or a trailing `/` or `:`"
+                    );
+                }
+            } else if in_subnames {
+                subnames += 1;
+            } else {
+                validate_node_name(bytes, start, i);
+                names += 1;
+            }
+
+            if !at_end && bytes[i] == b':' {
+                in_subnames = true;
+            }
+
+            start = i + 1;
+        }
+
+        i += 1;
+    }
+
+    (names, subnames)
+}
+
+/// Node names may be `.` or `..`, may start with `%` (scene-unique names), and must not contain `.`, `%` or `"` otherwise.
+const fn validate_node_name(bytes: &[u8], start: usize, end: usize) {
+    let len = end - start;
+    let is_dot = len == 1 && bytes[start] == b'.';
+    let is_dot_dot = len == 2 && bytes[start] == b'.' && bytes[start + 1] == b'.';
+    if is_dot || is_dot_dot {
+        return;
+    }
+
+    let mut i = start;
+    while i < end {
+        match bytes[i] {
+            b'.' => panic!("node_path!: node names must not contain `.`, except for `.` and `..`"),
+            b'"' => panic!("node_path!: node names must not contain `\"`"),
+            b'%' if i != start => {
+                panic!("node_path!: `%` is only allowed at the start of a node name")
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// Lazily constructed `NodePath`, stored in a `static` by `node_path!`.
+pub struct StaticNodePath {
+    path: std::sync::OnceLock<NodePath>,
+}
+
+impl StaticNodePath {
+    #[allow(clippy::new_without_default)] // Only used in statics.
+    pub const fn new() -> Self {
+        Self {
+            path: std::sync::OnceLock::new(),
+        }
+    }
+
+    #[cfg_attr(not(safeguards_strict), allow(unused_variables))] // expected_counts only checked in strict mode.
+    pub fn get_or_init(
+        &'static self,
+        path: &str,
+        expected_counts: (usize, usize),
+    ) -> &'static NodePath {
+        self.path.get_or_init(|| {
+            let node_path = NodePath::from(path);
+
+            sys::strict_assert_eq!(
+                (node_path.get_name_count(), node_path.get_subname_count()),
+                expected_counts,
+                "node_path!(\"{path}\"): Godot parsed names/subnames differently"
+            );
+
+            node_path
+        })
+    }
+}
+
+// SAFETY: The NodePath is never modified after construction, and Godot's NodePath uses an atomic reference count, so it can be read and
+// cloned from multiple threads. The static value is never dropped, so destruction after Godot shutdown is not an issue.
+unsafe impl Sync for StaticNodePath {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Support for gdscript_classes! macro
+
+/// Returns whether the script attached to `object`, or one of its base scripts, is located at `script_path`.
+pub fn object_has_script_path(object: &classes::Object, script_path: &str) -> bool {
+    let mut script = object.get_script();
+    while let Some(current) = script {
+        if current.get_path() == script_path {
+            return true;
+        }
+
+        script = current.get_base_script();
+    }
+
+    false
+}
+
+/// Loads the script at `script_path` and calls `method` on it; used for static functions and `new()`.
+pub fn call_script_method(script_path: &str, method: &str, args: &[Variant]) -> Variant {
+    let mut script = crate::tools::load::<classes::Script>(script_path);
+    script.call(method, args)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Capability queries and internal access
 
@@ -557,7 +742,7 @@ pub fn rebuild_gd(object_ref: &classes::Object) -> Gd<classes::Object> {
 
 #[cfg(test)]
 mod tests {
-    use super::{CallError, CallErrors, PanicPayload};
+    use super::{CallError, CallErrors, PanicPayload, validate_node_path};
     use crate::meta::CallContext;
 
     fn make(index: usize) -> CallError {
@@ -604,4 +789,23 @@ mod tests {
         let e = store.remove(id13).expect("generation not yet overwritten");
         assert_eq!(e.method_name(), "method_13");
     }
+
+    #[test]
+    fn test_validate_node_path() {
+        assert_eq!(validate_node_path("Player/Sprite:modulate"), (2, 1));
+        assert_eq!(validate_node_path("/root/Main"), (2, 0));
+        assert_eq!(validate_node_path("/"), (0, 0));
+        assert_eq!(validate_node_path(":position:x"), (0, 2));
+        assert_eq!(validate_node_path("../Sibling/."), (3, 0));
+        assert_eq!(validate_node_path("%HealthBar/Label"), (2, 0));
+        assert_eq!(
+            validate_node_path("Label:theme_override_colors/font_color"),
+            (1, 1)
+        );
+
+        for invalid in ["", "//", "a//b", "a/", "a:", "a::b", "a.b", "a%b", "a\"b"] {
+            let result = std::panic::catch_unwind(|| validate_node_path(invalid));
+            assert!(result.is_err(), "path {invalid:?} should be rejected");
+        }
+    }
 }
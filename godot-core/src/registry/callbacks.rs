@@ -16,7 +16,7 @@ use godot_ffi as sys;
 use sys::conv::u32_to_usize;
 use sys::interface_fn;
 
-use crate::builder::ClassBuilder;
+use crate::builder::{ClassBuilder, ClassBuilderState};
 use crate::builtin::{StringName, Variant};
 use crate::classes::Object;
 use crate::meta::PropertyInfo;
@@ -467,14 +467,15 @@ pub unsafe extern "C" fn validate_property<T: cap::GodotValidateProperty>(
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Safe, higher-level methods
 
-pub fn register_class_by_builder<T: cap::GodotRegisterClass>(_class_builder: &mut dyn Any) {
-    // TODO use actual argument, once class builder carries state
-    // let class_builder = class_builder
-    //     .downcast_mut::<ClassBuilder<T>>()
-    //     .expect("bad type erasure");
+pub fn register_class_by_builder<T: cap::GodotRegisterClass>(class_builder: &mut dyn Any) {
+    let state = class_builder
+        .downcast_mut::<ClassBuilderState>()
+        .expect("bad type erasure");
+
+    let mut typed_builder = ClassBuilder::<T>::new();
+    T::__godot_register_class(&mut typed_builder);
 
-    let mut class_builder = ClassBuilder::new();
-    T::__godot_register_class(&mut class_builder);
+    state.append(typed_builder.into_state());
 }
 
 pub fn register_user_properties<T: cap::ImplementsGodotExports>(_class_builder: &mut dyn Any) {
@@ -11,6 +11,7 @@ use std::{any, ptr};
 use godot_ffi::join_with;
 use sys::{Global, GlobalGuard, GlobalLockError, interface_fn, out};
 
+use crate::builder::ClassBuilderState;
 use crate::classes::ClassDb;
 use crate::init::InitLevel;
 use crate::meta::ClassId;
@@ -300,6 +301,11 @@ fn register_classes_and_dyn_traits(
     }
 }
 
+/// Returns the names of all classes registered by this extension, across all initialization levels.
+pub(crate) fn loaded_class_ids() -> Vec<ClassId> {
+    global_loaded_classes_by_name().keys().copied().collect()
+}
+
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
@@ -617,8 +623,8 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
 
     // ...then custom symbols
 
-    //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
-    let mut class_builder = 0; // TODO dummy argument; see callbacks
+    // Type-erased state of ClassBuilder<T>; symbols added through the builder are collected and registered at the end.
+    let mut class_builder = ClassBuilderState::new(class_name);
 
     // Order of the following registrations is crucial:
     // 1. Methods and constants.
@@ -635,6 +641,9 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     if let Some(register_fn) = info.user_register_fn {
         (register_fn.raw)(&mut class_builder);
     }
+
+    // 4. Symbols added through ClassBuilder. Registered last, so that builder properties can refer to #[func] getters/setters.
+    class_builder.register_all();
 }
 
 fn validate_class_constraints(_class: &ClassRegistrationInfo) {
@@ -668,6 +677,9 @@ fn unregister_class_raw(class: LoadedClass) {
         )
     };
 
+    // Methods can no longer be called, so the userdata of builder-registered methods can be released.
+    crate::builder::free_method_userdata(class_name);
+
     out!("Class {class_name} unloaded");
 }
 
@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Mapping of `Result` return types in `#[func]` methods to values that Godot understands.
+
+use std::fmt;
+
+use crate::builtin::{VarDictionary, Variant};
+use crate::global::Error;
+use crate::godot_error;
+use crate::meta::ToGodot;
+
+/// Strategy to convert a `Result<T, E>` returned by a `#[func]` into a value passed to Godot.
+///
+/// GDScript has no notion of `Result`, so `#[func]` methods returning one need to decide how errors are surfaced. The strategy is a
+/// type implementing this trait, selected per function with `#[func(on_error = Strategy)]`, or for all functions in an impl block with
+/// `#[godot_api(on_error = Strategy)]`. Without explicit selection, [`ErrorToPanic`] is used.
+///
+/// The registered method signature uses [`Output`][Self::Output] as its return type, so GDScript and the editor documentation see
+/// the actual value that is returned.
+///
+/// # Built-in strategies
+/// | Strategy              | Return type    | On `Ok(value)`             | On `Err(error)`                                |
+/// |-----------------------|----------------|----------------------------|------------------------------------------------|
+/// | [`ErrorToPanic`]      | `T`            | `value`                    | Failed call, as if the function had panicked.  |
+/// | [`ErrorToNull`]       | `Variant`      | `value` as variant         | `null`, after printing the error.              |
+/// | [`ErrorToCode`]       | [`Error`]      | `Error::OK`                | `error.into()`; requires `T = ()`.             |
+/// | [`ErrorToDictionary`] | `Dictionary`   | `{ok: value, err: null}`   | `{ok: null, err: "message"}`                   |
+///
+/// # Custom strategies
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::ErrorMapping;
+///
+/// /// Returns `-1` for failed lookups.
+/// struct ErrorToMinusOne;
+///
+/// impl<E> ErrorMapping<i64, E> for ErrorToMinusOne {
+///     type Output = i64;
+///
+///     fn map_result(result: Result<i64, E>) -> i64 {
+///         result.unwrap_or(-1)
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Inventory {}
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[func(on_error = ErrorToMinusOne)]
+///     fn slot_of(&self, item: GString) -> Result<i64, String> {
+///         Err(format!("no item {item}"))
+///     }
+/// }
+/// ```
+pub trait ErrorMapping<T, E> {
+    /// Type that is returned to Godot.
+    type Output: ToGodot;
+
+    /// Converts the result of the user function.
+    fn map_result(result: Result<T, E>) -> Self::Output;
+}
+
+/// Treats errors like panics: the call fails and the error is printed. This is the default strategy.
+///
+/// The return type seen by Godot is `T`. A varcall from GDScript reports the failure to the caller, like any other failed call.
+pub struct ErrorToPanic;
+
+impl<T: ToGodot, E: fmt::Display> ErrorMapping<T, E> for ErrorToPanic {
+    type Output = T;
+
+    fn map_result(result: Result<T, E>) -> T {
+        result.unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// Returns `null` on error and prints the error to Godot.
+///
+/// The return type seen by Godot is `Variant`.
+pub struct ErrorToNull;
+
+impl<T: ToGodot, E: fmt::Display> ErrorMapping<T, E> for ErrorToNull {
+    type Output = Variant;
+
+    fn map_result(result: Result<T, E>) -> Variant {
+        match result {
+            Ok(value) => value.to_variant(),
+            Err(err) => {
+                godot_error!("{err}");
+                Variant::nil()
+            }
+        }
+    }
+}
+
+/// Returns a [`global::Error`][Error] code; `Error::OK` on success.
+///
+/// Only applicable to functions returning `Result<(), E>`, where `E` converts into `Error`.
+pub struct ErrorToCode;
+
+impl<E: Into<Error>> ErrorMapping<(), E> for ErrorToCode {
+    type Output = Error;
+
+    fn map_result(result: Result<(), E>) -> Error {
+        match result {
+            Ok(()) => Error::OK,
+            Err(err) => err.into(),
+        }
+    }
+}
+
+/// Returns a dictionary `{ "ok": value, "err": message }`, where exactly one of the two entries is non-null.
+///
+/// The error message is the `Display` representation of `E`.
+pub struct ErrorToDictionary;
+
+impl<T: ToGodot, E: fmt::Display> ErrorMapping<T, E> for ErrorToDictionary {
+    type Output = VarDictionary;
+
+    fn map_result(result: Result<T, E>) -> VarDictionary {
+        let (ok, err) = match result {
+            Ok(value) => (value.to_variant(), Variant::nil()),
+            Err(err) => (Variant::nil(), err.to_string().to_variant()),
+        };
+
+        crate::vdict! { "ok": ok, "err": err }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Proc-macro support
+
+/// Splits a return type into its `Ok` and `Err` parts; also works through type aliases such as `io::Result<T>`.
+#[doc(hidden)]
+pub trait FuncResult {
+    type Ok;
+    type Err;
+
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> FuncResult for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+/// Type registered with Godot for a `#[func]` returning `R`, mapped with strategy `M`.
+#[doc(hidden)]
+pub type MappedReturn<M, R> =
+    <M as ErrorMapping<<R as FuncResult>::Ok, <R as FuncResult>::Err>>::Output;
+
+#[doc(hidden)]
+pub fn map_func_result<M, R>(result: R) -> MappedReturn<M, R>
+where
+    R: FuncResult,
+    M: ErrorMapping<R::Ok, R::Err>,
+{
+    M::map_result(result.into_result())
+}
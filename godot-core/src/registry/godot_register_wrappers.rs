@@ -59,7 +59,7 @@ pub fn register_var<C: GodotClass, T: Var>(
     register_var_or_export_inner(info, class_name, getter_name, setter_name);
 }
 
-fn register_var_or_export_inner(
+pub(crate) fn register_var_or_export_inner(
     info: PropertyInfo,
     class_name: ClassId,
     getter_name: &str,
@@ -81,6 +81,13 @@ fn register_var_or_export_inner(
     }
 }
 
+pub fn register_category<C: GodotClass>(category_name: &str) {
+    // There is no dedicated GDExtension API for categories; like in GDScript, they are properties with CATEGORY usage and no accessors.
+    let info = PropertyInfo::new_category(category_name);
+
+    register_var_or_export_inner(info, C::class_id(), "", "");
+}
+
 pub fn register_group<C: GodotClass>(group_name: &str, prefix: &str) {
     let group_name = GString::from(group_name);
     let prefix = GString::from(prefix);
@@ -37,6 +37,8 @@ pub struct ClassMethodInfo {
     /// Whether default arguments are real "arguments" is controversial. From the function PoV they are, but for the caller,
     /// they are just pre-set values to fill in for missing arguments.
     default_arguments: Vec<Variant>,
+    /// Opaque pointer handed back to `call_func` and `ptrcall_func` on each invocation.
+    method_userdata: *mut std::ffi::c_void,
 }
 
 impl ClassMethodInfo {
@@ -80,9 +82,23 @@ impl ClassMethodInfo {
             return_value,
             arguments,
             default_arguments,
+            method_userdata: std::ptr::null_mut(),
         }
     }
 
+    /// Sets the pointer that Godot passes as `method_userdata` to the call functions.
+    ///
+    /// # Safety
+    /// `call_func` and `ptrcall_func` must interpret `method_userdata` correctly, and the pointee must stay valid as long as the
+    /// method remains registered.
+    pub(crate) unsafe fn with_method_userdata(
+        mut self,
+        method_userdata: *mut std::ffi::c_void,
+    ) -> Self {
+        self.method_userdata = method_userdata;
+        self
+    }
+
     pub fn register_extension_class_method(&self) {
         use crate::obj::EngineBitfield as _;
 
@@ -113,7 +129,7 @@ impl ClassMethodInfo {
 
         let method_info_sys = sys::GDExtensionClassMethodInfo {
             name: sys::SysPtr::force_mut(self.method_name.string_sys()),
-            method_userdata: std::ptr::null_mut(),
+            method_userdata: self.method_userdata,
             call_func: self.call_func,
             ptrcall_func: self.ptrcall_func,
             method_flags: self.method_flags.ord() as u32,
@@ -11,6 +11,7 @@
 pub mod callbacks;
 pub mod class;
 pub mod constant;
+pub mod func_result;
 pub mod method;
 pub mod plugin;
 pub mod property;
@@ -20,7 +21,11 @@ pub mod signal;
 #[cfg(feature = "codegen-full")]
 mod rpc_config;
 #[cfg(feature = "codegen-full")]
+mod typed_rpc;
+#[cfg(feature = "codegen-full")]
 pub use rpc_config::RpcConfig;
+#[cfg(feature = "codegen-full")]
+pub use typed_rpc::{RpcError, TypedRpc};
 
 #[doc(hidden)]
 pub mod godot_register_wrappers;
@@ -165,6 +165,25 @@ pub trait Export: Var {
 // this `MarkerTrait` serves as the intended solution to recognize aforementioned types.
 pub trait BuiltinExport {}
 
+/// Marker trait for array types whose elements can be exported with a hint, using `#[export(elements, ...)]`.
+///
+/// Implemented for [`Array<T>`][crate::builtin::Array], and since Godot 4.3 for [packed arrays][crate::builtin::PackedArray]. Earlier
+/// versions don't support element hints on packed arrays.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(elements, ...)]` requires a typed `Array<T>` field, or a packed array field since Godot 4.3",
+    label = "type does not support element hints"
+)]
+pub trait ElementsExport: Export {}
+
+/// Marker trait for field types that can be restricted to certain node classes, using `#[export(node_path = (...))]`.
+///
+/// Only implemented for [`NodePath`][crate::builtin::NodePath]; Godot ignores the hint on other types.
+#[diagnostic::on_unimplemented(
+    message = "`#[export(node_path = ...)]` requires a `NodePath` field",
+    label = "type does not support node path hints"
+)]
+pub trait NodePathExport: Export {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Doctests to test compile errors
 
@@ -349,9 +368,9 @@ pub mod export_info_functions {
 
     use crate::builtin::GString;
     use crate::global::PropertyHint;
-    use crate::meta::{GodotType, PropertyHintInfo, PropertyInfo};
+    use crate::meta::{ClassId, GodotType, PropertyHintInfo, PropertyInfo};
     use crate::obj::EngineEnum;
-    use crate::registry::property::Export;
+    use crate::registry::property::{ElementsExport, Export, NodePathExport};
     use crate::sys;
 
     /// Turn a list of variables into a comma separated string containing only the identifiers corresponding
@@ -561,6 +580,22 @@ pub mod export_info_functions {
             (false, false) => PropertyHint::DIR,
         };
 
+        file_hint_for_field(field_ty, hint, filter)
+    }
+
+    /// Handles `@export_file_path`, which stores the raw path instead of a `uid://` reference.
+    #[cfg(since_api = "4.5")]
+    pub fn export_file_path<T: Export>(filter: impl AsRef<str>) -> PropertyHintInfo {
+        let field_ty = T::Via::property_info("");
+
+        file_hint_for_field(&field_ty, PropertyHint::FILE_PATH, filter.as_ref())
+    }
+
+    fn file_hint_for_field(
+        field_ty: &PropertyInfo,
+        hint: PropertyHint,
+        filter: &str,
+    ) -> PropertyHintInfo {
         // Returned value depends on field type.
         match field_ty.variant_type {
             // GString field:
@@ -621,6 +656,65 @@ pub mod export_info_functions {
         }
     }
 
+    /// Handles `@export_node_path(...)`, restricting the selectable nodes to the given classes (and their subclasses).
+    ///
+    /// `T` must be `NodePath`.
+    pub fn export_node_path<T: NodePathExport>(classes: &[ClassId]) -> PropertyHintInfo {
+        let hint_string = classes
+            .iter()
+            .map(|class| class.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: GString::from(&hint_string),
+        }
+    }
+
+    /// Handles `@export_custom(hint, hint_string, ...)`; the usage is passed separately.
+    pub fn export_custom(hint: PropertyHint, hint_string: impl AsRef<str>) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint,
+            hint_string: GString::from(hint_string.as_ref()),
+        }
+    }
+
+    /// Applies `element_hint` to each element of an exported array, like GDScript does for hints on typed arrays.
+    ///
+    /// `T` must be a typed `Array<E>` or packed array (Godot 4.3+). Formats: `"2/1:0,10"` (range on `Array<i64>`), `"4/2:One,Two"` (enum on
+    /// `Array<GString>`), ...
+    pub fn export_array_elements<T: ElementsExport>(
+        element_hint: PropertyHintInfo,
+    ) -> PropertyHintInfo {
+        let array_hint = T::export_hint();
+        let element_type = array_hint.hint_string.to_string();
+
+        // Element type strings have the form "2:", "24/17:Texture2D" etc.; the variant type comes first.
+        // Packed arrays only have them since Godot 4.3; before, they don't implement `ElementsExport`.
+        let variant_ord = match array_hint.hint {
+            PropertyHint::TYPE_STRING if !element_type.contains(';') => {
+                element_type.split([':', '/']).next()
+            }
+            _ => None,
+        };
+
+        let Some(variant_ord) = variant_ord else {
+            panic!(
+                "#[export(elements, ...)] only supports typed Array<T> or packed array field types\n\
+                encountered: {array_hint:?}"
+            );
+        };
+
+        let hint_ord = element_hint.hint.ord();
+        let hint_string = format!("{variant_ord}/{hint_ord}:{}", element_hint.hint_string);
+
+        PropertyHintInfo {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: GString::from(&hint_string),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -708,6 +802,7 @@ mod export_impls {
     impl_property_by_godot_convert!(GString);
     impl_property_by_godot_convert!(StringName);
     impl_property_by_godot_convert!(NodePath);
+    impl NodePathExport for NodePath {}
 
     impl_property_by_godot_convert!(Color);
     impl_property_by_godot_convert!(Variant);
@@ -14,7 +14,7 @@ use crate::meta;
 use crate::meta::{InParamTuple, ObjectToOwned};
 use crate::obj::{Bounds, Gd, GodotClass, WithSignals, bounds};
 use crate::registry::signal::signal_receiver::{IndirectSignalReceiver, SignalReceiver};
-use crate::registry::signal::{ConnectHandle, TypedSignal};
+use crate::registry::signal::{ConnectGuard, ConnectHandle, TypedSignal};
 
 /// Builder for customizing signal connections.
 ///
@@ -171,6 +171,25 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
         self.inner_connect_godot_fn::<F>(godot_fn, &bound)
     }
 
+    /// Connect a non-member function, disconnecting it when the returned guard is dropped.
+    ///
+    /// Same as [`connect()`][Self::connect], followed by [`ConnectHandle::into_guard()`]. For methods, call `into_guard()` on the handle
+    /// returned by the other `connect_*` methods. To manage many connections together, use a [`SignalScope`][crate::registry::signal::SignalScope].
+    ///
+    /// Example usage:
+    /// ```ignore
+    /// let guard = sig.builder().connect_scoped(|arg| { /* closure */ });
+    /// // ...
+    /// drop(guard); // Disconnects.
+    /// ```
+    pub fn connect_scoped<F>(self, function: F) -> ConnectGuard
+    where
+        for<'c_rcv> F: SignalReceiver<(), Ps>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, (), Ps, F>: From<&'c_rcv mut F>,
+    {
+        self.connect(function).into_guard()
+    }
+
     /// Connect a method with `&mut self` as the first parameter (user classes only).
     ///
     /// # Related APIs
@@ -15,7 +15,8 @@ use crate::sys;
 /// Returned by connections made by the `connect_*` methods of
 /// [`TypedSignal`][crate::registry::signal::TypedSignal] and [`ConnectBuilder`][crate::registry::signal::ConnectBuilder].
 ///
-/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect].
+/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect]. Dropping the handle does _not_ disconnect;
+/// to tie the connection's lifetime to a Rust value, convert it with [`into_guard()`][Self::into_guard] or add it to a [`SignalScope`].
 pub struct ConnectHandle {
     receiver_object: Gd<Object>,
     signal_name: CowStr,
@@ -60,4 +61,139 @@ impl ConnectHandle {
                 .receiver_object
                 .is_connected(&*self.signal_name, &self.callable)
     }
+
+    /// Turns the handle into a [`ConnectGuard`], which disconnects when dropped.
+    pub fn into_guard(self) -> ConnectGuard {
+        ConnectGuard { handle: Some(self) }
+    }
+
+    /// Disconnects if the connection still exists; does nothing otherwise (e.g. if the object has been freed).
+    fn disconnect_if_connected(self) {
+        if self.is_connected() {
+            self.disconnect();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// RAII guard for a typed signal connection, disconnecting it when dropped.
+///
+/// Returned by [`ConnectBuilder::connect_scoped()`][crate::registry::signal::ConnectBuilder::connect_scoped] and
+/// [`ConnectHandle::into_guard()`]. If the connection no longer exists when the guard is dropped -- for example because the object has been
+/// freed -- dropping does nothing.
+///
+/// To manage many connections at once, see [`SignalScope`].
+#[must_use = "dropping the guard immediately disconnects the signal"]
+pub struct ConnectGuard {
+    // Only `None` after `release()` or `disconnect()`.
+    handle: Option<ConnectHandle>,
+}
+
+impl ConnectGuard {
+    /// Disconnects the signal right away, rather than on drop.
+    pub fn disconnect(mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Whether the guarded connection still exists. See [`ConnectHandle::is_connected()`].
+    pub fn is_connected(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_connected())
+    }
+
+    /// Releases the connection from the guard, keeping it alive.
+    pub fn release(mut self) -> ConnectHandle {
+        self.handle
+            .take()
+            .expect("ConnectGuard must hold a handle until consumed")
+    }
+}
+
+impl Drop for ConnectGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Collection of typed signal connections, which are all disconnected together.
+///
+/// Connections are disconnected when the scope is dropped, or explicitly via [`disconnect_all()`][Self::disconnect_all]. Connections that
+/// have already been disconnected otherwise, or whose object has been freed, are skipped.
+///
+/// # Example
+/// A UI panel that wires up its signals on open and tears them down on close:
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Button;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Control)]
+/// struct SettingsPanel {
+///     scope: godot::register::SignalScope,
+///     base: Base<Control>,
+/// }
+///
+/// #[godot_api]
+/// impl SettingsPanel {
+///     fn open(&mut self, save: Gd<Button>, cancel: Gd<Button>) {
+///         self.scope.add(save.signals().pressed().connect(|| godot_print!("Saved.")));
+///         self.scope.add(cancel.signals().pressed().connect(|| godot_print!("Cancelled.")));
+///     }
+///
+///     fn close(&mut self) {
+///         self.scope.disconnect_all();
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct SignalScope {
+    handles: Vec<ConnectHandle>,
+}
+
+impl SignalScope {
+    /// Creates an empty scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a connection to the scope.
+    pub fn add(&mut self, handle: ConnectHandle) {
+        self.handles.push(handle);
+    }
+
+    /// Disconnects all connections in the scope, leaving it empty and ready for reuse.
+    pub fn disconnect_all(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Number of connections held by the scope, including ones which may have been disconnected in other ways.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the scope holds no connections.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+impl Extend<ConnectHandle> for SignalScope {
+    fn extend<I: IntoIterator<Item = ConnectHandle>>(&mut self, iter: I) {
+        self.handles.extend(iter);
+    }
+}
+
+impl Drop for SignalScope {
+    fn drop(&mut self) {
+        self.disconnect_all();
+    }
 }
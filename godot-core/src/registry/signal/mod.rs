@@ -24,7 +24,7 @@ use crate::meta;
 // Used in `godot` crate.
 pub mod re_export {
     pub use super::connect_builder::ConnectBuilder;
-    pub use super::connect_handle::ConnectHandle;
+    pub use super::connect_handle::{ConnectGuard, ConnectHandle, SignalScope};
     pub use super::signal_receiver::{IndirectSignalReceiver, SignalReceiver};
     pub use super::typed_signal::TypedSignal;
 }
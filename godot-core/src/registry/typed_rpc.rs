@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::classes::Node;
+use crate::global::Error;
+use crate::meta::OutParamTuple;
+use crate::obj::Gd;
+
+/// Type-safe remote procedure call, declared with `#[rpc]`.
+///
+/// Obtained through the `rpcs()` collection that `#[godot_api]` generates for classes with at least one `#[rpc]` method. Each RPC accessor
+/// returns a type with `rpc()` and `rpc_id()` methods, which take the same parameters as the Rust method and dereference to `TypedRpc`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     health: f32,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[rpc(any_peer)]
+///     fn take_damage(&mut self, amount: f32) {
+///         self.health -= amount;
+///     }
+///
+///     fn on_hit(&self, attacker_peer: i64) {
+///         // Instead of: self.base_mut().rpc_id(attacker_peer, "take_damage", vslice![5.0]).
+///         self.rpcs().take_damage().rpc_id(attacker_peer, 5.0).expect("RPC failed");
+///     }
+/// }
+/// ```
+///
+/// Since the method name and parameter types are taken from the `#[rpc]` declaration, renaming or changing the signature of the method
+/// causes compile errors at every call site, rather than failures at runtime.
+///
+/// RPCs configured with `call_local` run the method on the local peer right away. If that happens while the object is bound (e.g. inside
+/// a `&mut self` method), the call fails with a double-borrow panic. In such cases, obtain the RPC first, then send it once the object is
+/// no longer bound: `let rpc = gd.bind().rpcs().my_rpc(); rpc.rpc(...)`.
+pub struct TypedRpc<Ps> {
+    node: Gd<Node>,
+    method_name: &'static str,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<Ps: OutParamTuple> TypedRpc<Ps> {
+    #[doc(hidden)]
+    pub fn extract(node: Gd<Node>, method_name: &'static str) -> Self {
+        Self {
+            node,
+            method_name,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the method, as registered with Godot.
+    pub fn method_name(&self) -> &'static str {
+        self.method_name
+    }
+
+    /// Sends the RPC to all peers (and the local peer, if configured with `call_local`), with arguments in tuple form.
+    ///
+    /// Usually, you would use the generated `rpc()` method with individual parameters instead.
+    pub fn rpc_tuple(&self, args: Ps) -> Result<(), RpcError> {
+        self.check_inside_tree()?;
+
+        let mut node = self.node.clone();
+        let error = node.rpc(self.method_name, &args.to_variant_array());
+        self.check_error(error)
+    }
+
+    /// Sends the RPC to a specific peer, with arguments in tuple form.
+    ///
+    /// Usually, you would use the generated `rpc_id()` method with individual parameters instead.
+    pub fn rpc_id_tuple(&self, peer_id: i64, args: Ps) -> Result<(), RpcError> {
+        self.check_inside_tree()?;
+
+        let mut node = self.node.clone();
+        let error = node.rpc_id(peer_id, self.method_name, &args.to_variant_array());
+        self.check_error(error)
+    }
+
+    /// Outside the tree, nodes have no multiplayer API. Inside, Godot always provides a peer (`OfflineMultiplayerPeer` by default), through
+    /// which `call_local` RPCs still run locally.
+    fn check_inside_tree(&self) -> Result<(), RpcError> {
+        if self.node.is_inside_tree() {
+            Ok(())
+        } else {
+            Err(RpcError {
+                node_path: self.node_path(),
+                method_name: self.method_name,
+                godot_error: None,
+            })
+        }
+    }
+
+    fn check_error(&self, error: Error) -> Result<(), RpcError> {
+        if error == Error::OK {
+            return Ok(());
+        }
+
+        Err(RpcError {
+            node_path: self.node_path(),
+            method_name: self.method_name,
+            godot_error: Some(error),
+        })
+    }
+
+    fn node_path(&self) -> String {
+        if self.node.is_inside_tree() {
+            self.node.get_path().to_string()
+        } else {
+            format!("{} (not in tree)", self.node.get_name())
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error returned when sending a [`TypedRpc`] fails.
+#[derive(Debug)]
+pub struct RpcError {
+    node_path: String,
+    method_name: &'static str,
+    godot_error: Option<Error>,
+}
+
+impl RpcError {
+    /// Returns `true` if the node was not inside the scene tree, so the RPC could not be sent at all.
+    pub fn is_outside_tree(&self) -> bool {
+        self.godot_error.is_none()
+    }
+
+    /// The error reported by Godot's `Node::rpc()` or `Node::rpc_id()`, if the call reached the engine.
+    pub fn godot_error(&self) -> Option<Error> {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node_path = &self.node_path;
+        let method_name = self.method_name;
+
+        match self.godot_error {
+            None => write!(
+                f,
+                "can't send RPC '{method_name}' from node '{node_path}': node is not inside the scene tree"
+            ),
+            Some(error) => write!(
+                f,
+                "RPC '{method_name}' from node '{node_path}' failed; Godot error: {error:?}"
+            ),
+        }
+    }
+}
+
+impl StdError for RpcError {}
@@ -9,15 +9,12 @@ use std::cell::Cell;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
 
-#[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
-#[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi as sys;
 
 use crate::godot_error;
+use crate::meta::error::{BorrowError, BorrowInfo};
 use crate::obj::{Base, Gd, GodotClass, Inherits, Singleton};
-use crate::storage::log_pre_drop;
+use crate::storage::{StorageInaccessible, StorageMut, StorageRef, borrow_failed, log_pre_drop};
 
 #[derive(Copy, Clone, Debug)]
 pub enum Lifecycle {
@@ -83,17 +80,27 @@ pub unsafe trait Storage {
     /// The base object that this storage contains.
     fn base(&self) -> &Base<<Self::Instance as GodotClass>::Base>;
 
+    /// Returns a shared reference to this storage's instance, or an error if it is exclusively borrowed.
+    fn try_get(&self) -> Result<StorageRef<'_, Self::Instance>, BorrowError>;
+
+    /// Returns a mutable/exclusive reference to this storage's instance, or an error if it is already borrowed.
+    fn try_get_mut(&self) -> Result<StorageMut<'_, Self::Instance>, BorrowError>;
+
     /// Returns a shared reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
-    fn get(&self) -> RefGuard<'_, Self::Instance>;
+    fn get(&self) -> StorageRef<'_, Self::Instance> {
+        self.try_get().unwrap_or_else(|err| borrow_failed(err))
+    }
 
     /// Returns a mutable/exclusive reference to this storage's instance.
     ///
     /// This will ensure Rust's rules surrounding references are upheld. Possibly panicking at runtime if
     /// they are violated.
-    fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
+    fn get_mut(&self) -> StorageMut<'_, Self::Instance> {
+        self.try_get_mut().unwrap_or_else(|err| borrow_failed(err))
+    }
 
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
@@ -102,7 +109,12 @@ pub unsafe trait Storage {
     fn get_inaccessible<'a: 'b, 'b>(
         &'a self,
         instance: &'b mut Self::Instance,
-    ) -> InaccessibleGuard<'b, Self::Instance>;
+    ) -> StorageInaccessible<'b, Self::Instance>;
+
+    /// Borrows of this storage's instance which are currently live.
+    ///
+    /// Only tracked with strict safeguards; empty otherwise.
+    fn live_borrows(&self) -> Vec<BorrowInfo>;
 
     /// Returns whether this storage is currently alive or being destroyed.
     ///
@@ -178,9 +190,9 @@ pub struct VirtualMethodReceiver<'a, T: GodotClass> {
 
 enum VirtualMethodReceiverInner<'a, T: GodotClass> {
     /// &self.
-    Ref(RefGuard<'a, T>),
+    Ref(StorageRef<'a, T>),
     /// &mut self.
-    Mut(MutGuard<'a, T>),
+    Mut(StorageMut<'a, T>),
     /// this: Gd<Self>.
     GdSelf(Gd<T>),
     /// Implementation detail – required to swap the values.
@@ -12,34 +12,39 @@ mod multi_threaded;
 mod single_threaded;
 
 use std::any::type_name;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
 
+#[cfg(feature = "experimental-threads")]
+use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
+#[cfg(not(feature = "experimental-threads"))]
+use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 pub use instance_storage::*;
 
+use crate::meta::error::{BorrowError, BorrowKind};
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Shared code for submodules
 
-fn bind_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
-
-    eprint!("{tracker}");
-
-    panic!(
-        "Gd<T>::bind() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
+fn borrow_error<T>(
+    kind: BorrowKind,
+    err: Box<dyn std::error::Error>,
+    tracker: &DebugBorrowTracker,
+) -> BorrowError {
+    BorrowError::new(
+        kind,
+        type_name::<T>(),
+        err.to_string(),
+        tracker.live_borrows(),
     )
 }
 
-fn bind_mut_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
-
-    eprint!("{tracker}");
+fn borrow_failed(err: BorrowError) -> ! {
+    if cfg!(safeguards_strict) {
+        eprint!("{}", err.live_borrows_report());
+    }
 
-    panic!(
-        "Gd<T>::bind_mut() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
-    )
+    panic!("{err}")
 }
 
 fn bug_inaccessible<T>(err: Box<dyn std::error::Error>) -> ! {
@@ -120,84 +125,206 @@ mod log_inactive {
     pub fn log_drop<T: StorageRefCounted>(_storage: &T) {}
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Guards for the user instance
+
+/// Shared borrow of the user instance inside a [`Storage`]. Tracked in Debug mode.
+pub struct StorageRef<'a, T> {
+    guard: RefGuard<'a, T>,
+    // Dropped after the guard.
+    _tracked: TrackedBorrow<'a>,
+}
+
+impl<'a, T> StorageRef<'a, T> {
+    fn new(guard: RefGuard<'a, T>, tracked: TrackedBorrow<'a>) -> Self {
+        Self {
+            guard,
+            _tracked: tracked,
+        }
+    }
+}
+
+impl<T> Deref for StorageRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for StorageRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.guard.fmt(f)
+    }
+}
+
+/// Exclusive borrow of the user instance inside a [`Storage`]. Tracked in Debug mode.
+pub struct StorageMut<'a, T> {
+    guard: MutGuard<'a, T>,
+    // Dropped after the guard.
+    _tracked: TrackedBorrow<'a>,
+}
+
+impl<'a, T> StorageMut<'a, T> {
+    fn new(guard: MutGuard<'a, T>, tracked: TrackedBorrow<'a>) -> Self {
+        Self {
+            guard,
+            _tracked: tracked,
+        }
+    }
+}
+
+impl<T> Deref for StorageMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for StorageMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for StorageMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.guard.fmt(f)
+    }
+}
+
+/// Exclusive borrow that is made inaccessible through `base_mut()`, allowing re-entrant borrows. Tracked in Debug mode.
+pub struct StorageInaccessible<'a, T> {
+    _guard: InaccessibleGuard<'a, T>,
+    // Dropped after the guard.
+    _tracked: TrackedBorrow<'a>,
+}
+
+impl<'a, T> StorageInaccessible<'a, T> {
+    fn new(guard: InaccessibleGuard<'a, T>, tracked: TrackedBorrow<'a>) -> Self {
+        Self {
+            _guard: guard,
+            _tracked: tracked,
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tracking borrows in Debug mode
 
 #[cfg(safeguards_strict)]
-use borrow_info::DebugBorrowTracker;
+use borrow_info::{DebugBorrowTracker, TrackedBorrow};
 #[cfg(not(safeguards_strict))]
-use borrow_info_noop::DebugBorrowTracker;
+use borrow_info_noop::{DebugBorrowTracker, TrackedBorrow};
 
 use crate::obj::{Base, GodotClass};
 
 #[cfg(safeguards_strict)]
 mod borrow_info {
-    use std::backtrace::Backtrace;
+    use std::backtrace::{Backtrace, BacktraceStatus};
     use std::fmt;
     use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    use crate::meta::error::{BorrowInfo, BorrowKind};
 
-    struct TrackedBorrow {
+    struct LiveBorrow {
+        id: u64,
+        kind: BorrowKind,
+        thread_id: ThreadId,
         backtrace: Backtrace,
-        is_mut: bool,
     }
 
-    /// Informational-only info about ongoing borrows.
+    #[derive(Default)]
+    struct LiveBorrows {
+        next_id: u64,
+        borrows: Vec<LiveBorrow>,
+    }
+
+    /// Informational-only info about all ongoing borrows of one instance.
+    ///
+    /// Each guard handed out by the storage holds a [`TrackedBorrow`], which removes its entry on drop.
     pub(super) struct DebugBorrowTracker {
-        // Currently just tracks the last borrow. Could technically track 1 mut or N ref borrows, but would need destructor integration.
-        // Also never clears it when a guard drops, assuming that once a borrow fails, there must be at least one previous borrow conflicting.
-        // Is also not yet integrated with "inaccessible" borrows (reborrow through base_mut).
-        last_borrow: Mutex<Option<TrackedBorrow>>,
+        live: Mutex<LiveBorrows>,
     }
 
     impl DebugBorrowTracker {
         pub fn new() -> Self {
             Self {
-                last_borrow: Mutex::new(None),
+                live: Mutex::new(LiveBorrows::default()),
             }
         }
 
         // Currently considers RUST_BACKTRACE due to performance reasons; force_capture() can be quite slow.
         // User is expected to set the env var during debug sessions.
-
-        #[track_caller]
-        pub fn track_ref_borrow(&self) {
-            let mut guard = self.last_borrow.lock().unwrap();
-            *guard = Some(TrackedBorrow {
+        pub fn track(&self, kind: BorrowKind) -> TrackedBorrow<'_> {
+            let mut live = self.live.lock().unwrap();
+
+            let id = live.next_id;
+            live.next_id += 1;
+            live.borrows.push(LiveBorrow {
+                id,
+                kind,
+                thread_id: thread::current().id(),
                 backtrace: Backtrace::capture(),
-                is_mut: false,
             });
+
+            TrackedBorrow { tracker: self, id }
         }
 
-        #[track_caller]
-        pub fn track_mut_borrow(&self) {
-            let mut guard = self.last_borrow.lock().unwrap();
-            *guard = Some(TrackedBorrow {
-                backtrace: Backtrace::capture(),
-                is_mut: true,
-            });
+        pub fn live_borrows(&self) -> Vec<BorrowInfo> {
+            let live = self.live.lock().unwrap();
+
+            live.borrows
+                .iter()
+                .map(|borrow| {
+                    let backtrace = match borrow.backtrace.status() {
+                        BacktraceStatus::Captured => Some(borrow.backtrace.to_string()),
+                        _ => None,
+                    };
+
+                    BorrowInfo::new(borrow.kind, borrow.thread_id, backtrace)
+                })
+                .collect()
+        }
+
+        fn untrack(&self, id: u64) {
+            let mut live = self.live.lock().unwrap();
+
+            // Borrows are mostly released in reverse order, so search from the back.
+            if let Some(index) = live.borrows.iter().rposition(|borrow| borrow.id == id) {
+                live.borrows.remove(index);
+            }
         }
     }
 
-    impl fmt::Display for DebugBorrowTracker {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let guard = self.last_borrow.lock().unwrap();
-            if let Some(borrow) = &*guard {
-                let mutability = if borrow.is_mut { "bind_mut" } else { "bind" };
+    /// Entry of a live borrow in [`DebugBorrowTracker`]; removed on drop.
+    pub(super) struct TrackedBorrow<'a> {
+        tracker: &'a DebugBorrowTracker,
+        id: u64,
+    }
 
-                let prefix = format!("backtrace of previous `{mutability}` borrow");
-                let backtrace = crate::format_backtrace!(prefix, &borrow.backtrace);
+    impl Drop for TrackedBorrow<'_> {
+        fn drop(&mut self) {
+            self.tracker.untrack(self.id);
+        }
+    }
 
-                writeln!(f, "{backtrace}")
-            } else {
-                writeln!(f, "no previous borrows tracked.")
-            }
+    impl fmt::Debug for TrackedBorrow<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TrackedBorrow")
+                .field("id", &self.id)
+                .finish()
         }
     }
 }
 
 #[cfg(not(safeguards_strict))]
 mod borrow_info_noop {
-    use std::fmt;
+    use std::marker::PhantomData;
+
+    use crate::meta::error::{BorrowInfo, BorrowKind};
 
     pub(super) struct DebugBorrowTracker;
 
@@ -206,14 +333,15 @@ mod borrow_info_noop {
             Self
         }
 
-        pub fn track_ref_borrow(&self) {}
-
-        pub fn track_mut_borrow(&self) {}
-    }
+        pub fn track(&self, _kind: BorrowKind) -> TrackedBorrow<'_> {
+            TrackedBorrow(PhantomData)
+        }
 
-    impl fmt::Display for DebugBorrowTracker {
-        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-            Ok(())
+        pub fn live_borrows(&self) -> Vec<BorrowInfo> {
+            Vec::new()
         }
     }
+
+    #[derive(Debug)]
+    pub(super) struct TrackedBorrow<'a>(PhantomData<&'a ()>);
 }
@@ -8,12 +8,16 @@
 use std::cell;
 
 #[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::blocking::GdCell;
 #[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+use godot_cell::panicking::GdCell;
 
+use crate::meta::error::{BorrowError, BorrowInfo, BorrowKind};
 use crate::obj::{Base, GodotClass};
-use crate::storage::{DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
+use crate::storage::{
+    DebugBorrowTracker, Lifecycle, Storage, StorageInaccessible, StorageMut, StorageRef,
+    StorageRefCounted,
+};
 
 pub struct InstanceStorage<T: GodotClass> {
     user_instance: GdCell<T>,
@@ -59,33 +63,40 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         &self.base
     }
 
-    fn get(&self) -> RefGuard<'_, T> {
+    fn try_get(&self) -> Result<StorageRef<'_, T>, BorrowError> {
         let guard = self
             .user_instance
             .borrow()
-            .unwrap_or_else(|e| super::bind_failed::<T>(e, &self.borrow_tracker));
+            .map_err(|e| super::borrow_error::<T>(BorrowKind::Shared, e, &self.borrow_tracker))?;
 
-        self.borrow_tracker.track_ref_borrow();
-        guard
+        let tracked = self.borrow_tracker.track(BorrowKind::Shared);
+        Ok(StorageRef::new(guard, tracked))
     }
 
-    fn get_mut(&self) -> MutGuard<'_, T> {
-        let guard = self
-            .user_instance
-            .borrow_mut()
-            .unwrap_or_else(|e| super::bind_mut_failed::<T>(e, &self.borrow_tracker));
+    fn try_get_mut(&self) -> Result<StorageMut<'_, T>, BorrowError> {
+        let guard = self.user_instance.borrow_mut().map_err(|e| {
+            super::borrow_error::<T>(BorrowKind::Exclusive, e, &self.borrow_tracker)
+        })?;
 
-        self.borrow_tracker.track_mut_borrow();
-        guard
+        let tracked = self.borrow_tracker.track(BorrowKind::Exclusive);
+        Ok(StorageMut::new(guard, tracked))
     }
 
     fn get_inaccessible<'stor: 'inst, 'inst>(
         &'stor self,
         value: &'inst mut Self::Instance,
-    ) -> InaccessibleGuard<'inst, T> {
-        self.user_instance
+    ) -> StorageInaccessible<'inst, T> {
+        let guard = self
+            .user_instance
             .make_inaccessible(value)
-            .unwrap_or_else(|e| super::bug_inaccessible::<T>(e))
+            .unwrap_or_else(|e| super::bug_inaccessible::<T>(e));
+
+        let tracked = self.borrow_tracker.track(BorrowKind::Inaccessible);
+        StorageInaccessible::new(guard, tracked)
+    }
+
+    fn live_borrows(&self) -> Vec<BorrowInfo> {
+        self.borrow_tracker.live_borrows()
     }
 
     fn get_lifecycle(&self) -> Lifecycle {
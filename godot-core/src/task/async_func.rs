@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Support for `#[func] async fn`.
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::thread;
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::classes::RefCounted;
+use crate::global::godot_error;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, NewGd};
+use crate::task::{TaskHandle, spawn};
+
+/// Name of the signal which is emitted with the output of an async `#[func]`.
+const COMPLETED_SIGNAL: &str = "completed";
+
+thread_local! {
+    /// Tasks of async `#[func]`s, which may still be pending. Finished tasks are pruned whenever a new one is spawned.
+    static ASYNC_FUNC_TASKS: RefCell<Vec<TaskHandle>> = const { RefCell::new(Vec::new()) };
+
+    /// Set while pending tasks are canceled during engine shutdown; their signals are then no longer emitted.
+    static TEARING_DOWN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Spawns the future of an async `#[func]` and returns a signal, which GDScript can `await`.
+///
+/// The signal belongs to a fresh `RefCounted` object that is kept alive by the task. Once the future completes, the signal is emitted with
+/// the output as its single argument. If the task panics or is canceled, the error is printed and the signal is emitted with `null`, so
+/// that awaiting callers are resumed.
+///
+/// Emission is always deferred: the caller can only start awaiting after the function returned, but the future may complete during its
+/// first poll already.
+#[doc(hidden)]
+pub fn spawn_async_func<T, F>(func_name: &'static str, future: F) -> Signal
+where
+    T: ToGodot + 'static,
+    F: Future<Output = T> + 'static,
+{
+    let mut awaitable = RefCounted::new_gd();
+    awaitable.add_user_signal(COMPLETED_SIGNAL);
+
+    let signal = Signal::from_object_signal(&awaitable, COMPLETED_SIGNAL);
+    let emitter = AsyncFuncEmitter {
+        awaitable: Some(awaitable),
+        func_name,
+    };
+
+    // Panics are tracked by the runtime like for any other task, see `has_godot_task_panicked()`.
+    let task = spawn(async move {
+        let output = future.await;
+        emitter.complete(output.to_variant());
+    });
+
+    ASYNC_FUNC_TASKS.with_borrow_mut(|tasks| {
+        tasks.retain(TaskHandle::is_pending);
+
+        if task.is_pending() {
+            tasks.push(task);
+        }
+    });
+
+    signal
+}
+
+/// Cancels all pending async `#[func]` tasks; called during engine shutdown, before the async runtime is torn down.
+///
+/// Awaiting callers are not resumed anymore, as the engine no longer processes deferred calls at this point.
+pub(crate) fn cleanup() {
+    let tasks = ASYNC_FUNC_TASKS.take();
+
+    TEARING_DOWN.set(true);
+    for task in tasks {
+        task.cancel();
+    }
+    TEARING_DOWN.set(false);
+}
+
+/// Lives inside the task of an async `#[func]`; if dropped before completion, the task was canceled or panicked.
+struct AsyncFuncEmitter {
+    awaitable: Option<Gd<RefCounted>>,
+    func_name: &'static str,
+}
+
+impl AsyncFuncEmitter {
+    fn complete(mut self, output: Variant) {
+        self.emit_deferred(output);
+    }
+
+    fn emit_deferred(&mut self, output: Variant) {
+        let Some(awaitable) = self.awaitable.take() else {
+            return;
+        };
+
+        // The callable owns the object until the signal has been emitted.
+        Callable::from_once_fn("spawn_async_func::emit", move |_args| {
+            // The object may have been freed manually in the meantime.
+            if !awaitable.is_instance_valid() {
+                return;
+            }
+
+            let signal = Signal::from_object_signal(&awaitable, COMPLETED_SIGNAL);
+            signal.emit(&[output]);
+        })
+        .call_deferred(&[]);
+    }
+}
+
+impl Drop for AsyncFuncEmitter {
+    fn drop(&mut self) {
+        if self.awaitable.is_none() {
+            return;
+        }
+
+        if TEARING_DOWN.get() {
+            self.awaitable = None;
+            return;
+        }
+
+        let reason = if thread::panicking() {
+            "panicked"
+        } else {
+            "was canceled"
+        };
+
+        godot_error!(
+            "async #[func] {} {reason} before completion; awaiting callers receive null",
+            self.func_name
+        );
+        self.emit_deferred(Variant::nil());
+    }
+}
@@ -6,15 +6,20 @@
  */
 
 use std::cell::RefCell;
+use std::fmt::Display;
 use std::future::Future;
-use std::marker::PhantomData;
+use std::hash::{Hash, Hasher};
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::task::{Context, Poll, Wake, Waker};
 use std::thread::{self, LocalKey, ThreadId};
 
-use crate::builtin::{Callable, Variant};
+use crate::builtin::{Callable, RustCallable, Variant};
+use crate::classes::Node;
+use crate::obj::{Gd, Inherits, InstanceId};
 use crate::private::handle_panic;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -23,7 +28,11 @@ use crate::private::handle_panic;
 /// Create a new async background task.
 ///
 /// This function allows creating a new async task in which Godot signals can be awaited, like it is possible in GDScript. The
-/// [`TaskHandle`] that is returned provides synchronous introspection into the current state of the task.
+/// [`JoinHandle`] that is returned provides synchronous introspection into the current state of the task, and can be awaited from other
+/// tasks to obtain the future's output.
+///
+/// When called from within another task, the new task becomes a _child_ of the current one: canceling the parent (or a panic in the
+/// parent) also cancels the child. A parent that completes normally leaves its children running.
 ///
 /// Signals can be converted to futures in the following ways:
 ///
@@ -38,7 +47,7 @@ use crate::private::handle_panic;
 /// [`TypedSignal::to_fallible_future()`]: crate::registry::signal::TypedSignal::to_fallible_future
 ///
 /// # Panics
-/// If called from any other thread than the main thread.
+/// If called from any other thread than the main thread. Use [`spawn_send()`][crate::task::spawn_send] for tasks on other threads.
 ///
 /// # Examples
 /// With typed signals:
@@ -87,55 +96,120 @@ use crate::private::handle_panic;
 /// });
 /// ```
 #[doc(alias = "async")]
-pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+pub fn spawn<T: 'static>(future: impl Future<Output = T> + 'static) -> JoinHandle<T> {
+    spawn_task(async move { Some(future.await) })
+}
+
+/// Create a new async background task, whose lifetime is bound to a node.
+///
+/// Works like [`spawn()`], but the task is canceled automatically once `owner` exits the scene tree or is freed. This makes it safe to
+/// access the owner from within the task (e.g. in per-node AI coroutines), without having to check for its validity after every `await`.
+///
+/// Canceling happens immediately when the node's `tree_exiting` signal is emitted, or when the node is removed from the object database.
+/// Awaiting the returned [`JoinHandle`] then yields [`JoinError::Canceled`].
+///
+/// # Panics
+/// If called from any other thread than the main thread.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Enemy {
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Enemy {
+///     fn ready(&mut self) {
+///         let mut node = self.to_gd().upcast::<Node2D>();
+///         let owner = node.clone();
+///         let tree = self.base().get_tree().unwrap();
+///
+///         godot::task::spawn_bound(&owner, async move {
+///             loop {
+///                 let physics_frame = Signal::from_object_signal(&tree, "physics_frame");
+///                 physics_frame.to_future::<()>().await;
+///
+///                 // Never runs after the enemy has left the tree.
+///                 node.translate(Vector2::new(1.0, 0.0));
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub fn spawn_bound<N, T>(owner: &Gd<N>, future: impl Future<Output = T> + 'static) -> JoinHandle<T>
+where
+    N: Inherits<Node>,
+    T: 'static,
+{
+    spawn_task(BoundFuture::new(owner.instance_id(), future))
+}
+
+/// Spawns a task, whose future resolves to `None` if it was canceled from within.
+fn spawn_task<T: 'static>(future: impl Future<Output = Option<T>> + 'static) -> JoinHandle<T> {
     // Spawning new tasks is only allowed on the main thread for now.
     // We can not accept Sync + Send futures since all object references (i.e. Gd<T>) are not thread-safe. So a future has to remain on the
     // same thread it was created on. Godots signals on the other hand can be emitted on any thread, so it can't be guaranteed on which thread
     // a future will be polled.
     // By limiting async tasks to the main thread we can redirect all signal callbacks back to the main thread via `call_deferred`.
     //
-    // Once thread-safe futures are possible the restriction can be lifted.
+    // Thread-safe futures are handled separately by `spawn_send()`.
     assert!(
         crate::init::is_main_thread(),
         "godot_task() can only be used on the main thread"
     );
 
+    let output = Rc::new(RefCell::new(JoinOutput::default()));
+    let guard = JoinGuard {
+        output: output.clone(),
+    };
+
+    let task_future = async move {
+        // If no value is produced, the guard is dropped and reports cancellation.
+        if let Some(value) = future.await {
+            guard.complete(value);
+        }
+    };
+
     let (task_handle, godot_waker) = ASYNC_RUNTIME.with_runtime_mut(move |rt| {
-        let task_handle = rt.add_task(Box::pin(future));
-        let godot_waker = Arc::new(GodotWaker::new(
-            task_handle.index,
-            task_handle.id,
-            thread::current().id(),
-        ));
-
-        (task_handle, godot_waker)
+        let (index, id) = rt.add_task(Box::pin(task_future));
+        let godot_waker = Arc::new(GodotWaker::new(index, id, thread::current().id()));
+
+        (JoinHandle::new(index, id, output), godot_waker)
     });
 
     poll_future(godot_waker);
     task_handle
 }
 
-/// Handle for an active background task.
+/// Handle for an active background task, which produces a value of type `T`.
 ///
 /// This handle provides introspection into the current state of the task, as well as providing a way to cancel it.
 ///
+/// The handle can be awaited from another task. It then resolves to the task's output, or to a [`JoinError`] if the task was canceled or
+/// panicked.
+///
 /// The associated task will **not** be canceled if this handle is dropped.
-pub struct TaskHandle {
+pub struct JoinHandle<T> {
     index: usize,
     id: u64,
-    _no_send_sync: PhantomData<*const ()>,
+    // Rc also makes the handle !Send + !Sync.
+    output: Rc<RefCell<JoinOutput<T>>>,
 }
 
-impl TaskHandle {
-    fn new(index: usize, id: u64) -> Self {
-        Self {
-            index,
-            id,
-            _no_send_sync: PhantomData,
-        }
+/// Handle for a task without output, see [`JoinHandle`].
+pub type TaskHandle = JoinHandle<()>;
+
+impl<T> JoinHandle<T> {
+    fn new(index: usize, id: u64, output: Rc<RefCell<JoinOutput<T>>>) -> Self {
+        Self { index, id, output }
     }
 
     /// Cancels the task if it is still pending and does nothing if it is already completed.
+    ///
+    /// All child tasks spawned by this task, which are still pending, are canceled as well.
     pub fn cancel(self) {
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
             let Some(task) = rt.tasks.get(self.index) else {
@@ -157,27 +231,284 @@ impl TaskHandle {
                 return;
             }
 
-            rt.clear_task(self.index);
+            rt.cancel_task(self.index, self.id);
         })
     }
 
     /// Synchronously checks if the task is still pending or has already completed.
     pub fn is_pending(&self) -> bool {
-        ASYNC_RUNTIME.with_runtime(|rt| {
-            let slot = rt
-                .tasks
-                .get(self.index)
-                .unwrap_or_else(|| unreachable!("missing future slot at index {}", self.index));
-
-            if slot.id != self.id {
-                return false;
+        matches!(self.output.borrow().state, JoinState::Pending)
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut output = self.output.borrow_mut();
+
+        match std::mem::replace(&mut output.state, JoinState::Taken) {
+            JoinState::Pending => {
+                output.state = JoinState::Pending;
+                output.waker = Some(cx.waker().clone());
+                Poll::Pending
             }
+            JoinState::Ready(value) => Poll::Ready(Ok(value)),
+            JoinState::Failed(error) => Poll::Ready(Err(error)),
+            JoinState::Taken => panic!("JoinHandle polled after completion"),
+        }
+    }
+}
 
-            matches!(
-                slot.value,
-                FutureSlotState::Pending(_) | FutureSlotState::Polling
-            )
-        })
+/// Error returned when awaiting a [`JoinHandle`], whose task did not run to completion.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum JoinError {
+    /// The task was canceled, either directly or through its parent, or because the engine shut down.
+    Canceled,
+
+    /// The task panicked.
+    Panicked,
+}
+
+impl Display for JoinError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Canceled => write!(f, "The task was canceled before it completed"),
+            Self::Panicked => write!(f, "The task panicked before it completed"),
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+/// Output of a task, shared between the task and its [`JoinHandle`].
+struct JoinOutput<T> {
+    state: JoinState<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> Default for JoinOutput<T> {
+    fn default() -> Self {
+        Self {
+            state: JoinState::Pending,
+            waker: None,
+        }
+    }
+}
+
+enum JoinState<T> {
+    Pending,
+    Ready(T),
+    Failed(JoinError),
+    Taken,
+}
+
+/// Lives inside the task's future and reports its outcome; if dropped before completion, the task was canceled or panicked.
+struct JoinGuard<T> {
+    output: Rc<RefCell<JoinOutput<T>>>,
+}
+
+impl<T> JoinGuard<T> {
+    fn complete(self, value: T) {
+        self.resolve(JoinState::Ready(value));
+    }
+
+    fn resolve(&self, state: JoinState<T>) {
+        let waker = {
+            let mut output = self.output.borrow_mut();
+            if !matches!(output.state, JoinState::Pending) {
+                return;
+            }
+
+            output.state = state;
+            output.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for JoinGuard<T> {
+    fn drop(&mut self) {
+        let error = if thread::panicking() {
+            JoinError::Panicked
+        } else {
+            JoinError::Canceled
+        };
+
+        // No-op if the task has completed.
+        self.resolve(JoinState::Failed(error));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Owner-bound tasks
+
+/// Future of a [`spawn_bound()`] task. Resolves to `None` if the owner is gone.
+struct BoundFuture<F> {
+    future: Pin<Box<F>>,
+    owner_id: InstanceId,
+    flags: Arc<BoundFlags>,
+    /// Task whose [`OwnerWatch`] is connected to the owner's `tree_exiting` signal, once the future has been polled.
+    ///
+    /// The connected callable itself is not stored: it must be dropped by Godot when the owner is freed.
+    watched_task: Option<(usize, u64)>,
+}
+
+#[derive(Default)]
+struct BoundFlags {
+    owner_gone: AtomicBool,
+    /// Set once the future is dropped, after which the watch no longer needs to cancel anything.
+    detached: AtomicBool,
+}
+
+impl<F: Future> BoundFuture<F> {
+    fn new(owner_id: InstanceId, future: F) -> Self {
+        Self {
+            future: Box::pin(future),
+            owner_id,
+            flags: Arc::default(),
+            watched_task: None,
+        }
+    }
+
+    fn owner(&self) -> Option<Gd<Node>> {
+        // Liveness check through the ObjectDB; nodes are manually managed, so this doesn't extend the owner's lifetime.
+        Gd::try_from_instance_id(self.owner_id).ok()
+    }
+
+    /// Connects a callable to the owner, which cancels the task on `tree_exiting` -- or when it is dropped because the owner is freed.
+    fn connect_watch(&mut self, mut owner: Gd<Node>) {
+        let task = ASYNC_RUNTIME
+            .with_runtime(|rt| rt.current_task)
+            .expect("BoundFuture must be polled by the async runtime");
+
+        let callable = Callable::from_custom(OwnerWatch {
+            task,
+            flags: Some(self.flags.clone()),
+        });
+
+        owner.connect("tree_exiting", &callable);
+        self.watched_task = Some(task);
+    }
+}
+
+impl<F: Future> Future for BoundFuture<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if this.flags.owner_gone.load(Ordering::Relaxed) {
+            return Poll::Ready(None);
+        }
+
+        let Some(owner) = this.owner() else {
+            return Poll::Ready(None);
+        };
+
+        if this.watched_task.is_none() {
+            this.connect_watch(owner);
+        }
+
+        this.future.as_mut().poll(cx).map(Some)
+    }
+}
+
+impl<F> Drop for BoundFuture<F> {
+    fn drop(&mut self) {
+        self.flags.detached.store(true, Ordering::Relaxed);
+
+        let Some(task) = self.watched_task.take() else {
+            return;
+        };
+
+        if let Ok(mut owner) = Gd::<Node>::try_from_instance_id(self.owner_id) {
+            // Compares equal to the connected watch.
+            let callable = Callable::from_custom(OwnerWatch { task, flags: None });
+
+            if owner.is_connected("tree_exiting", &callable) {
+                owner.disconnect("tree_exiting", &callable);
+            }
+        }
+    }
+}
+
+/// Callable connected to the owner; cancels the task when invoked or dropped (Godot drops connections of freed objects).
+///
+/// Watches compare equal if they belong to the same task, which allows disconnecting without holding on to the connected callable.
+struct OwnerWatch {
+    task: (usize, u64),
+    /// `None` for watches that are only used to look up the connected one.
+    flags: Option<Arc<BoundFlags>>,
+}
+
+impl OwnerWatch {
+    fn trigger(&self) {
+        let Some(flags) = &self.flags else {
+            return;
+        };
+
+        if flags.detached.load(Ordering::Relaxed) || flags.owner_gone.swap(true, Ordering::Relaxed)
+        {
+            return;
+        }
+
+        let (index, id) = self.task;
+
+        // The runtime may be borrowed already, e.g. if the owner is freed while another task's future is dropped. In that case, cancel
+        // at the end of the frame. The task can't make progress in the meantime, since it checks `owner_gone` when polled.
+        let canceled = ASYNC_RUNTIME
+            .try_with(|cell| match cell.try_borrow_mut() {
+                Ok(mut rt) => {
+                    if let Some(rt) = rt.as_mut() {
+                        rt.cancel_task(index, id);
+                    }
+                    true
+                }
+                Err(_) => false,
+            })
+            .unwrap_or(true);
+
+        if !canceled {
+            Callable::from_once_fn("spawn_bound::cancel", move |_args| {
+                ASYNC_RUNTIME.with_runtime_mut(|rt| rt.cancel_task(index, id));
+            })
+            .call_deferred(&[]);
+        }
+    }
+}
+
+impl PartialEq for OwnerWatch {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task
+    }
+}
+
+impl Hash for OwnerWatch {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.task.hash(state);
+    }
+}
+
+impl Display for OwnerWatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "spawn_bound::owner_watch")
+    }
+}
+
+impl RustCallable for OwnerWatch {
+    fn invoke(&mut self, _args: &[&Variant]) -> Variant {
+        self.trigger();
+        Variant::nil()
+    }
+}
+
+impl Drop for OwnerWatch {
+    fn drop(&mut self) {
+        self.trigger();
     }
 }
 
@@ -201,7 +532,7 @@ pub(crate) fn cleanup() {
 }
 
 #[cfg(feature = "trace")]
-pub fn has_godot_task_panicked(task_handle: TaskHandle) -> bool {
+pub fn has_godot_task_panicked<T>(task_handle: JoinHandle<T>) -> bool {
     ASYNC_RUNTIME.with_runtime(|rt| rt.panicked_tasks.contains(&task_handle.id))
 }
 
@@ -223,6 +554,10 @@ enum FutureSlotState<T> {
 struct FutureSlot<T> {
     value: FutureSlotState<T>,
     id: u64,
+    /// Tasks spawned while this task was being polled, as `(index, id)` pairs.
+    children: Vec<(usize, u64)>,
+    /// Cancellation was requested (by a parent) while the future was being polled.
+    cancel_requested: bool,
 }
 
 impl<T> FutureSlot<T> {
@@ -231,6 +566,8 @@ impl<T> FutureSlot<T> {
         Self {
             value: FutureSlotState::Pending(value),
             id,
+            children: Vec::new(),
+            cancel_requested: false,
         }
     }
 
@@ -244,6 +581,8 @@ impl<T> FutureSlot<T> {
     /// This transitions the slot into the [`FutureSlotState::Gone`] state.
     fn clear(&mut self) {
         self.value = FutureSlotState::Gone;
+        self.children.clear();
+        self.cancel_requested = false;
     }
 
     /// Attempts to extract the future with the given ID from the slot.
@@ -289,6 +628,8 @@ impl<T> FutureSlot<T> {
 struct AsyncRuntime {
     tasks: Vec<FutureSlot<Pin<Box<dyn Future<Output = ()>>>>>,
     next_task_id: u64,
+    /// Task that is currently being polled, as `(index, id)`. Becomes the parent of newly spawned tasks.
+    current_task: Option<(usize, u64)>,
     #[cfg(feature = "trace")]
     panicked_tasks: std::collections::HashSet<u64>,
 }
@@ -299,6 +640,7 @@ impl AsyncRuntime {
             // We only create a new async runtime inside a thread_local, which has lazy initialization on first use.
             tasks: Vec::with_capacity(16),
             next_task_id: 0,
+            current_task: None,
             #[cfg(feature = "trace")]
             panicked_tasks: std::collections::HashSet::default(),
         }
@@ -317,7 +659,11 @@ impl AsyncRuntime {
     /// free slot, a new slot is added which may grow the underlying [`Vec`].
     ///
     /// The future storage always starts out with a capacity of 10 tasks.
-    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> TaskHandle {
+    ///
+    /// If a task is currently being polled, the new task is registered as its child.
+    ///
+    /// Returns the index and ID of the new task.
+    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> (usize, u64) {
         let id = self.next_id();
         let index_slot = self
             .tasks
@@ -339,7 +685,26 @@ impl AsyncRuntime {
             }
         };
 
-        TaskHandle::new(index, id)
+        if let Some((parent_index, parent_id)) = self.current_task {
+            if self.tasks[parent_index].id == parent_id {
+                // Forget children that completed or were canceled in the meantime, so that long-running parents don't accumulate them.
+                let mut children = std::mem::take(&mut self.tasks[parent_index].children);
+                children
+                    .retain(|&(child_index, child_id)| self.is_task_alive(child_index, child_id));
+                children.push((index, id));
+
+                self.tasks[parent_index].children = children;
+            }
+        }
+
+        (index, id)
+    }
+
+    /// Whether the slot at `index` still holds the task with the given ID.
+    fn is_task_alive(&self, index: usize, id: u64) -> bool {
+        self.tasks
+            .get(index)
+            .is_some_and(|slot| slot.id == id && !slot.is_empty())
     }
 
     /// Extract a pending task from the storage.
@@ -363,12 +728,56 @@ impl AsyncRuntime {
         self.tasks[index].clear();
     }
 
+    /// Cancel a task and, recursively, all of its pending children.
+    ///
+    /// Tasks that are currently being polled are only marked and will be canceled once their poll returns.
+    fn cancel_task(&mut self, index: usize, id: u64) {
+        let Some(slot) = self.tasks.get_mut(index) else {
+            return;
+        };
+
+        if slot.id != id {
+            return;
+        }
+
+        match slot.value {
+            FutureSlotState::Pending(_) => {
+                let children = std::mem::take(&mut slot.children);
+                slot.clear();
+
+                for (child_index, child_id) in children {
+                    self.cancel_task(child_index, child_id);
+                }
+            }
+            FutureSlotState::Polling => slot.cancel_requested = true,
+            FutureSlotState::Empty | FutureSlotState::Gone => {}
+        }
+    }
+
+    /// Remove a future that just panicked from the storage, and cancel all of its children.
+    fn clear_panicked_task(&mut self, index: usize) {
+        let children = std::mem::take(&mut self.tasks[index].children);
+        self.clear_task(index);
+
+        for (child_index, child_id) in children {
+            self.cancel_task(child_index, child_id);
+        }
+    }
+
     /// Move a future back into its slot.
     ///
     /// # Panic
     /// - If the underlying slot is not in the [`FutureSlotState::Polling`] state.
+    ///
+    /// If cancellation was requested during polling, the task is canceled instead.
     fn park_task(&mut self, index: usize, future: Pin<Box<dyn Future<Output = ()>>>) {
-        self.tasks[index].park(future);
+        let slot = &mut self.tasks[index];
+        slot.park(future);
+
+        if slot.cancel_requested {
+            let id = slot.id;
+            self.cancel_task(index, id);
+        }
     }
 
     /// Track that a future caused a panic.
@@ -419,6 +828,9 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
     let waker = Waker::from(godot_waker.clone());
     let mut ctx = Context::from_waker(&waker);
 
+    // Tasks can be polled while another task is being polled, e.g. the initial poll of a task spawned from within another task.
+    let mut previous_task = None;
+
     // Move future out of the runtime while we are polling it to avoid holding a mutable reference for the entire runtime.
     let future = ASYNC_RUNTIME.with_runtime_mut(|rt| {
         match rt.take_task_for_polling(godot_waker.runtime_index, godot_waker.task_id) {
@@ -432,7 +844,12 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
                 unreachable!("the same GodotWaker has been called recursively");
             }
 
-            FutureSlotState::Pending(future) => Some(future),
+            FutureSlotState::Pending(future) => {
+                previous_task = rt
+                    .current_task
+                    .replace((godot_waker.runtime_index, godot_waker.task_id));
+                Some(future)
+            }
         }
     });
 
@@ -451,12 +868,14 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
         (future.as_mut().poll(&mut ctx), future)
     });
 
+    ASYNC_RUNTIME.with_runtime_mut(|rt| rt.current_task = previous_task);
+
     let Ok((poll_result, future)) = panic_result else {
         // Polling the future caused a panic. The task state has to be cleaned up and we want track the panic if the trace feature is enabled.
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
             #[cfg(feature = "trace")]
             rt.track_panic(godot_waker.task_id);
-            rt.clear_task(godot_waker.runtime_index);
+            rt.clear_panicked_task(godot_waker.runtime_index);
         });
 
         return;
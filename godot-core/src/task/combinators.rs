@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Awaits all futures concurrently, resolving to their outputs in the original order.
+///
+/// The futures are polled within the current task; use [`spawn()`][crate::task::spawn] and await the resulting
+/// [`JoinHandle`][crate::task::JoinHandle]s if they should run as independent tasks.
+///
+/// All futures must be of the same type. Signal futures with equal parameters already are; other futures can be boxed as
+/// `Pin<Box<dyn Future<Output = T>>>`.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn get_signals() -> Vec<Signal> { unimplemented!() }
+/// let signals: Vec<Signal> = get_signals();
+///
+/// godot::task::spawn(async move {
+///     // Continue once every actor has finished their animation.
+///     let futures = signals.iter().map(|signal| signal.to_future::<()>());
+///     godot::task::join_all(futures).await;
+/// });
+/// ```
+pub fn join_all<F: Future>(futures: impl IntoIterator<Item = F>) -> JoinAll<F> {
+    let futures: Vec<_> = futures.into_iter().map(Box::pin).map(Some).collect();
+    let outputs = futures.iter().map(|_| None).collect();
+
+    JoinAll { futures, outputs }
+}
+
+/// Awaits the first future to complete, resolving to its output and index.
+///
+/// When the `Select` future resolves, all other futures are dropped, which cancels them: signal futures are disconnected from their signal.
+/// Note that dropping a [`JoinHandle`][crate::task::JoinHandle] does not cancel its task; call `cancel()` explicitly if needed.
+///
+/// All futures must be of the same type, see [`join_all()`].
+///
+/// # Panics
+/// If `futures` is empty, since the resulting future could never resolve.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn get_signals() -> (Signal, Signal) { unimplemented!() }
+/// let (timeout, player_input) = get_signals();
+///
+/// godot::task::spawn(async move {
+///     let ((), index) = godot::task::select([timeout.to_future::<()>(), player_input.to_future()]).await;
+///
+///     if index == 1 {
+///         println!("Cutscene skipped.");
+///     }
+/// });
+/// ```
+pub fn select<F: Future>(futures: impl IntoIterator<Item = F>) -> Select<F> {
+    let futures: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    assert!(!futures.is_empty(), "select() requires at least one future");
+
+    Select { futures }
+}
+
+/// Future returned by [`join_all()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct JoinAll<F: Future> {
+    futures: Vec<Option<Pin<Box<F>>>>,
+    outputs: Vec<Option<F::Output>>,
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut all_done = true;
+
+        for (slot, output) in this.futures.iter_mut().zip(this.outputs.iter_mut()) {
+            let Some(future) = slot else {
+                continue;
+            };
+
+            match future.as_mut().poll(cx) {
+                Poll::Ready(value) => {
+                    *output = Some(value);
+                    *slot = None;
+                }
+                Poll::Pending => all_done = false,
+            }
+        }
+
+        if !all_done {
+            return Poll::Pending;
+        }
+
+        let outputs = std::mem::take(&mut this.outputs)
+            .into_iter()
+            .map(|output| output.expect("JoinAll polled after completion"))
+            .collect();
+
+        Poll::Ready(outputs)
+    }
+}
+
+/// Future returned by [`select()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Select<F: Future> {
+    futures: Vec<Pin<Box<F>>>,
+}
+
+impl<F: Future> Future for Select<F> {
+    type Output = (F::Output, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let ready = this
+            .futures
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, future)| match future.as_mut().poll(cx) {
+                Poll::Ready(value) => Some((value, index)),
+                Poll::Pending => None,
+            });
+
+        if ready.is_some() {
+            // Drop the remaining futures right away, rather than when the Select is dropped.
+            this.futures.clear();
+        }
+
+        match ready {
+            Some(result) => Poll::Ready(result),
+            None => Poll::Pending,
+        }
+    }
+}
@@ -155,8 +155,12 @@ impl<R: IntoDynamicSend> Drop for SignalFutureResolver<R> {
 
         // If we got a waker we trigger it to get the future polled. If there is no waker, then the future has not been polled yet and we
         // simply wait for the runtime to perform the first poll.
-        if let Some(ref waker) = data.waker {
-            waker.wake_by_ref();
+        // The lock is released first: wakers of `spawn_send` tasks poll immediately, which locks the data again.
+        let waker = data.waker.take();
+        drop(data);
+
+        if let Some(waker) = waker {
+            waker.wake();
         }
     }
 }
@@ -193,7 +197,7 @@ impl<T> SignalFutureState<T> {
 pub struct FallibleSignalFuture<R: InParamTuple + IntoDynamicSend> {
     data: Arc<Mutex<SignalFutureData<R::Target>>>,
     callable: SignalFutureResolver<R>,
-    signal: Signal,
+    signal: SendSignal,
 }
 
 impl<R: InParamTuple + IntoDynamicSend> FallibleSignalFuture<R> {
@@ -218,7 +222,7 @@ impl<R: InParamTuple + IntoDynamicSend> FallibleSignalFuture<R> {
         Self {
             data,
             callable,
-            signal,
+            signal: SendSignal(signal),
         }
     }
 
@@ -276,33 +280,95 @@ impl<R: InParamTuple + IntoDynamicSend> Future for FallibleSignalFuture<R> {
 
 impl<R: InParamTuple + IntoDynamicSend> Drop for FallibleSignalFuture<R> {
     fn drop(&mut self) {
-        // The callable might alredy be destroyed, this occurs during engine shutdown.
-        if self.signal.is_null() {
-            return;
-        }
-
         let mut data_lock = self.data.lock().unwrap();
 
         data_lock.state = SignalFutureState::Dropped;
 
         drop(data_lock);
 
-        // We create a new Godot Callable from our RustCallable so we get independent reference counting.
-        let gd_callable = Callable::from_custom(self.callable.clone());
-
-        // is_connected will return true if the signal was never emited before the future is dropped.
-        //
-        // There is a TOCTOU issue here that can occur when the FallibleSignalFuture is dropped at the same time as the signal object is
-        // freed on a different thread.
-        // We check in the beginning if the signal object is still alive, and we check here again, but the signal object still can be freed
-        // between our check and our usage of the object in `is_connected` and `disconnect`. The race condition will manifest in a
-        // non-unwinding panic that is hard to track down.
-        if !self.signal.is_null() && self.signal.is_connected(&gd_callable) {
-            self.signal.disconnect(&gd_callable);
+        if crate::init::is_main_thread() {
+            disconnect_resolver(&self.signal.0, &self.callable);
+        } else {
+            // Futures of `spawn_send()` tasks can be dropped on any thread, but the signal object must only be accessed on the main thread.
+            let deferred = DeferredDisconnect {
+                signal: Mutex::new(self.signal.clone()),
+                resolver: self.callable.clone(),
+            };
+
+            Callable::from_custom(deferred).call_deferred(&[]);
         }
     }
 }
 
+/// Disconnects the resolver of a dropped signal future, if the signal has not been emitted yet. Must be called on the main thread.
+fn disconnect_resolver<R: InParamTuple + IntoDynamicSend>(
+    signal: &Signal,
+    resolver: &SignalFutureResolver<R>,
+) {
+    // The callable might alredy be destroyed, this occurs during engine shutdown.
+    if signal.is_null() {
+        return;
+    }
+
+    // We create a new Godot Callable from our RustCallable so we get independent reference counting.
+    let gd_callable = Callable::from_custom(resolver.clone());
+
+    // is_connected will return true if the signal was never emited before the future is dropped.
+    //
+    // There is a TOCTOU issue here that can occur when the signal object is freed on a different thread while the future is dropped.
+    // We check in the beginning if the signal object is still alive, and we check here again, but the signal object still can be freed
+    // between our check and our usage of the object in `is_connected` and `disconnect`. The race condition will manifest in a
+    // non-unwinding panic that is hard to track down.
+    if !signal.is_null() && signal.is_connected(&gd_callable) {
+        signal.disconnect(&gd_callable);
+    }
+}
+
+/// Runs [`disconnect_resolver()`] on the main thread, for signal futures dropped on other threads.
+struct DeferredDisconnect<R: IntoDynamicSend> {
+    // Mutex only makes the signal `Sync`; it is accessed exclusively through `&mut self`.
+    signal: Mutex<SendSignal>,
+    resolver: SignalFutureResolver<R>,
+}
+
+impl<R: IntoDynamicSend> std::hash::Hash for DeferredDisconnect<R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.resolver, state);
+    }
+}
+
+impl<R: IntoDynamicSend> PartialEq for DeferredDisconnect<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.resolver == other.resolver
+    }
+}
+
+impl<R: IntoDynamicSend> Display for DeferredDisconnect<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DeferredDisconnect::<{}>", std::any::type_name::<R>())
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> RustCallable for DeferredDisconnect<R> {
+    fn invoke(&mut self, _args: &[&Variant]) -> Variant {
+        let signal = self
+            .signal
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        disconnect_resolver(&signal.0, &self.resolver);
+        Variant::nil()
+    }
+}
+
+/// Signal that can be moved to other threads, so that signal futures can be awaited in [`spawn_send()`][crate::task::spawn_send] tasks.
+#[derive(Clone)]
+struct SendSignal(Signal);
+
+// SAFETY: A Godot Signal only consists of the object's ID and the signal's StringName, both of which can be sent across threads. The object
+// itself is never accessed without looking it up in the ObjectDB first.
+unsafe impl Send for SendSignal {}
+
 impl Signal {
     /// Creates a fallible future for this signal.
     ///
@@ -9,22 +9,40 @@
 //!
 //! This module contains:
 //! - Implementations of [`Future`] for [`Signal`][crate::builtin::Signal] and [`TypedSignal`][crate::registry::signal::TypedSignal].
-//! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - A way to [`spawn`] new async tasks by using the engine as the async runtime, and to await their output through [`JoinHandle`].
+//! - Combinators to [`join_all`] or [`select`] futures, e.g. signal futures.
+//! - A way to [`spawn_send`] thread-safe async tasks from any thread.
+//! - Support for `#[func] async fn`, whose output is delivered to GDScript through an awaitable signal.
+//! - Timer and frame futures driven by the engine's main loop, such as `sleep()` and `next_frame()` (Godot 4.5+).
 
+pub(crate) mod async_func;
 mod async_runtime;
+mod combinators;
 mod futures;
+mod send_runtime;
+#[cfg(since_api = "4.5")]
+mod timers;
 
 // Public re-exports
-pub use async_runtime::{TaskHandle, spawn};
+pub use async_runtime::{JoinError, JoinHandle, TaskHandle, spawn, spawn_bound};
+pub use combinators::{JoinAll, Select, join_all, select};
 pub use futures::{
     DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend, SignalFuture,
 };
+pub use send_runtime::{SendTaskHandle, spawn_send};
+#[cfg(since_api = "4.5")]
+pub use timers::{
+    NextFrame, Sleep, Timeout, TimeoutError, next_frame, next_physics_frame, sleep, timeout,
+};
 
 // For use in integration tests.
 #[cfg(feature = "trace")]
 mod reexport_test {
     pub use super::async_runtime::has_godot_task_panicked;
     pub use super::futures::{SignalFutureResolver, create_test_signal_future_resolver};
+    pub use super::send_runtime::has_send_task_panicked;
+    #[cfg(since_api = "4.5")]
+    pub use super::timers::pending_timer_count;
 }
 
 #[cfg(feature = "trace")]
@@ -32,8 +50,18 @@ pub use reexport_test::*;
 
 // Crate-local re-exports.
 mod reexport_crate {
-    pub(crate) use super::async_runtime::cleanup;
     pub(crate) use super::futures::{ThreadConfined, impl_dynamic_send};
+    #[cfg(since_api = "4.5")]
+    pub(crate) use super::timers::on_main_loop_frame;
 }
 
 pub(crate) use reexport_crate::*;
+
+/// Drops all pending tasks during engine shutdown.
+pub(crate) fn cleanup() {
+    async_func::cleanup();
+    #[cfg(since_api = "4.5")]
+    timers::cleanup();
+    send_runtime::cleanup();
+    async_runtime::cleanup();
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::private::handle_panic;
+use crate::sys;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Create a new async task from any thread.
+///
+/// This is the thread-safe counterpart to [`spawn()`][crate::task::spawn]. Since the future is [`Send`], it is not bound to the main thread:
+/// it can be spawned on worker threads and is polled on whichever thread wakes it up. In particular, a signal future is polled on the thread
+/// that emitted the signal, right inside the emission.
+///
+/// Signal futures ([`SignalFuture`][crate::task::SignalFuture], [`FallibleSignalFuture`][crate::task::FallibleSignalFuture]) can be awaited
+/// like in `spawn()`, but they must be created before being moved into the task, since `Gd` pointers and [`Signal`][crate::builtin::Signal]
+/// are not `Send`.
+///
+/// The returned [`SendTaskHandle`] provides synchronous introspection into the current state of the task. It can be shared between threads.
+///
+/// # Example
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # use godot::classes::Node;
+/// # use godot::obj::NewAlloc;
+/// let node = Node::new_alloc();
+/// let ready = Signal::from_object_signal(&node, "ready").to_future::<()>();
+///
+/// std::thread::spawn(move || {
+///     godot::task::spawn_send(async move {
+///         ready.await;
+///         println!("Node is ready.");
+///     });
+/// });
+/// ```
+pub fn spawn_send(future: impl Future<Output = ()> + Send + 'static) -> SendTaskHandle {
+    let task = SEND_RUNTIME.lock().add_task(Box::pin(future));
+
+    poll_send_task(task.clone());
+    SendTaskHandle { task }
+}
+
+/// Handle for an active task created by [`spawn_send()`].
+///
+/// This handle provides introspection into the current state of the task, as well as providing a way to cancel it. Unlike
+/// [`TaskHandle`][crate::task::TaskHandle], it is `Send` and `Sync`.
+///
+/// The associated task will **not** be canceled if this handle is dropped, as long as it can still be woken up.
+pub struct SendTaskHandle {
+    task: Arc<SendTask>,
+}
+
+impl SendTaskHandle {
+    /// Cancels the task if it is still pending and does nothing if it is already completed.
+    ///
+    /// If the task is currently being polled on another thread (or cancels itself), it is dropped as soon as that poll returns.
+    pub fn cancel(self) {
+        self.task.cancel();
+    }
+
+    /// Synchronously checks if the task is still pending or has already completed.
+    pub fn is_pending(&self) -> bool {
+        !matches!(*self.task.lock_state(), SendTaskState::Done)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runtime
+
+/// Registry of all tasks that have not completed yet.
+///
+/// Pending tasks are kept alive by the wakers stored inside their futures (and by their handles). Once a task can no longer be woken up,
+/// e.g. because the awaited object was freed without resolving the future, it is dropped. The registry only holds weak references, so that
+/// the remaining tasks can be dropped during shutdown.
+static SEND_RUNTIME: sys::Global<SendRuntime> = sys::Global::default();
+
+/// Will be called during engine shutdown.
+///
+/// Like tasks on the main thread, all remaining futures must be dropped while the engine is still around.
+pub(crate) fn cleanup() {
+    // Don't hold the lock while dropping futures, as their destructors may access the runtime.
+    let tasks: Vec<Arc<SendTask>> = SEND_RUNTIME
+        .lock()
+        .tasks
+        .drain()
+        .filter_map(|(_, task)| task.upgrade())
+        .collect();
+
+    for task in tasks {
+        task.cancel();
+    }
+}
+
+#[cfg(feature = "trace")]
+pub fn has_send_task_panicked(task_handle: SendTaskHandle) -> bool {
+    let id = task_handle.task.id;
+
+    // Drop the handle before locking: if it is the last reference, dropping the task accesses the runtime.
+    drop(task_handle);
+    SEND_RUNTIME.lock().panicked_tasks.contains(&id)
+}
+
+type SendFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+#[derive(Default)]
+struct SendRuntime {
+    tasks: HashMap<u64, Weak<SendTask>>,
+    next_task_id: u64,
+    #[cfg(feature = "trace")]
+    panicked_tasks: std::collections::HashSet<u64>,
+}
+
+impl SendRuntime {
+    fn add_task(&mut self, future: SendFuture) -> Arc<SendTask> {
+        let id = self.next_task_id;
+        self.next_task_id += 1;
+
+        let task = Arc::new(SendTask {
+            id,
+            state: Mutex::new(SendTaskState::Idle(future)),
+        });
+
+        self.tasks.insert(id, Arc::downgrade(&task));
+        task
+    }
+}
+
+/// The current state of a task.
+enum SendTaskState {
+    /// Task is waiting to be woken up.
+    Idle(SendFuture),
+
+    /// Task is being polled on some thread.
+    ///
+    /// Wake-ups and cancellations that arrive in the meantime are recorded and handled by the polling thread once the poll returns.
+    Polling { notified: bool, canceled: bool },
+
+    /// Task has completed, panicked or was canceled.
+    Done,
+}
+
+struct SendTask {
+    id: u64,
+    state: Mutex<SendTaskState>,
+}
+
+impl SendTask {
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, SendTaskState> {
+        // Futures are never polled while the lock is held, so the mutex cannot be poisoned by them.
+        self.state.lock().expect("SendTask state poisoned")
+    }
+
+    fn cancel(&self) {
+        let future = {
+            let mut state = self.lock_state();
+
+            match std::mem::replace(&mut *state, SendTaskState::Done) {
+                SendTaskState::Idle(future) => future,
+
+                // The polling thread drops the future once it's done.
+                SendTaskState::Polling { notified, .. } => {
+                    *state = SendTaskState::Polling {
+                        notified,
+                        canceled: true,
+                    };
+                    return;
+                }
+
+                SendTaskState::Done => return,
+            }
+        };
+
+        // Drop the future outside the lock.
+        drop(future);
+        self.unregister();
+    }
+
+    fn unregister(&self) {
+        SEND_RUNTIME.lock().tasks.remove(&self.id);
+    }
+
+    /// Finishes a poll that returned `Pending`.
+    ///
+    /// Returns the future back if it needs to be polled once more.
+    fn park(&self, future: SendFuture) -> Option<SendFuture> {
+        let mut state = self.lock_state();
+
+        let SendTaskState::Polling { notified, canceled } = *state else {
+            unreachable!("SendTask must be in Polling state while being polled");
+        };
+
+        if canceled {
+            *state = SendTaskState::Done;
+            drop(state);
+
+            drop(future);
+            self.unregister();
+            None
+        } else if notified {
+            *state = SendTaskState::Polling {
+                notified: false,
+                canceled: false,
+            };
+            Some(future)
+        } else {
+            *state = SendTaskState::Idle(future);
+            None
+        }
+    }
+
+    fn finish(&self) {
+        *self.lock_state() = SendTaskState::Done;
+        self.unregister();
+    }
+}
+
+// Reached when the task can no longer be woken up. The runtime never holds strong references while locked, so this cannot deadlock.
+impl Drop for SendTask {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+// Polls the task right away, on the waking thread.
+impl Wake for SendTask {
+    fn wake(self: Arc<Self>) {
+        poll_send_task(self);
+    }
+}
+
+fn poll_send_task(task: Arc<SendTask>) {
+    let mut future = {
+        let mut state = task.lock_state();
+        let polling = SendTaskState::Polling {
+            notified: false,
+            canceled: false,
+        };
+
+        match std::mem::replace(&mut *state, polling) {
+            SendTaskState::Idle(future) => future,
+
+            // Another thread (or the future itself) is polling right now; make sure it polls again.
+            SendTaskState::Polling { canceled, .. } => {
+                *state = SendTaskState::Polling {
+                    notified: true,
+                    canceled,
+                };
+                return;
+            }
+
+            SendTaskState::Done => {
+                *state = SendTaskState::Done;
+                return;
+            }
+        }
+    };
+
+    let waker = Waker::from(task.clone());
+
+    loop {
+        let error_context = || "Godot async task (spawn_send) failed".to_string();
+
+        // If Future::poll() panics, the future is immediately dropped and cannot be accessed again,
+        // thus any state that may not have been unwind-safe cannot be observed later.
+        let mut unwind_future = AssertUnwindSafe(future);
+        let mut ctx = Context::from_waker(&waker);
+
+        let panic_result = handle_panic(error_context, move || {
+            (unwind_future.as_mut().poll(&mut ctx), unwind_future)
+        });
+
+        match panic_result {
+            Err(_) => {
+                #[cfg(feature = "trace")]
+                SEND_RUNTIME.lock().panicked_tasks.insert(task.id);
+
+                task.finish();
+                return;
+            }
+
+            Ok((Poll::Ready(()), _)) => {
+                task.finish();
+                return;
+            }
+
+            Ok((Poll::Pending, polled)) => match task.park(polled.0) {
+                Some(repoll) => future = repoll,
+                None => return,
+            },
+        }
+    }
+}
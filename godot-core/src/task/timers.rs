@@ -0,0 +1,477 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use crate::builtin::Callable;
+use crate::classes::{Engine, SceneTree, Time};
+use crate::obj::Singleton;
+use crate::sys;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Creates a future that resolves after `duration` has passed in the game.
+///
+/// By default, the timer behaves like a [`SceneTreeTimer`][crate::classes::SceneTreeTimer] created with `process_always = false`: it does not
+/// advance while the scene tree is paused, and it is scaled by [`Engine::time_scale`][crate::classes::Engine::get_time_scale]. Both can be
+/// changed with [`Sleep::process_always()`] and [`Sleep::ignore_time_scale()`].
+///
+/// Timers are advanced once per process frame, so the actual duration is rounded up to the next frame.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+///
+/// godot::task::spawn(async {
+///     godot::task::sleep(Duration::from_secs(2)).await;
+///     println!("2 seconds later.");
+/// });
+/// ```
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        duration: duration.as_secs_f64(),
+        clock: ClockKind::default(),
+        deadline: None,
+        entry_id: None,
+    }
+}
+
+/// Creates a future that resolves at the start of the next process frame.
+///
+/// Frames are counted even while the scene tree is paused.
+pub fn next_frame() -> NextFrame {
+    NextFrame {
+        physics: false,
+        target: None,
+        entry_id: None,
+    }
+}
+
+/// Creates a future that resolves at the start of the next physics frame.
+///
+/// Physics frames are tracked through the [`SceneTree::physics_frame`][crate::classes::SceneTree] signal. If the main loop is not a
+/// `SceneTree`, the future never resolves.
+pub fn next_physics_frame() -> NextFrame {
+    NextFrame {
+        physics: true,
+        target: None,
+        entry_id: None,
+    }
+}
+
+/// Awaits `future`, but gives up once the timer `after` has elapsed.
+///
+/// `after` can be a [`Duration`], or a [`Sleep`] future if pause and time scale settings should be customized. If the timer elapses first,
+/// `future` is dropped and [`TimeoutError`] is returned.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// # use godot::builtin::Signal;
+/// # fn get_signal() -> Signal { unimplemented!() }
+/// let signal: Signal = get_signal();
+///
+/// godot::task::spawn(async move {
+///     let result = godot::task::timeout(signal.to_future::<()>(), Duration::from_secs(5)).await;
+///
+///     if result.is_err() {
+///         println!("Signal was not emitted within 5 seconds.");
+///     }
+/// });
+/// ```
+pub fn timeout<F: Future>(future: F, after: impl Into<Sleep>) -> Timeout<F> {
+    Timeout {
+        future: Box::pin(future),
+        sleep: after.into(),
+    }
+}
+
+/// Future returned by [`sleep()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Sleep {
+    duration: f64,
+    clock: ClockKind,
+    deadline: Option<f64>,
+    entry_id: Option<u64>,
+}
+
+impl Sleep {
+    /// Keeps the timer running while the scene tree is paused.
+    pub fn process_always(mut self) -> Self {
+        self.clock.process_always = true;
+        self
+    }
+
+    /// Measures the duration in real time, unaffected by [`Engine::time_scale`][crate::classes::Engine::get_time_scale].
+    pub fn ignore_time_scale(mut self) -> Self {
+        self.clock.ignore_time_scale = true;
+        self
+    }
+}
+
+impl From<Duration> for Sleep {
+    fn from(duration: Duration) -> Self {
+        sleep(duration)
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let now_usec = Time::singleton().get_ticks_usec();
+
+        let replaced_waker = {
+            let mut driver = FRAME_DRIVER.lock();
+
+            // Use the current time rather than the clock of the last frame, so the duration is measured from the moment of the first poll.
+            let clock_index = this.clock.index();
+            let now = driver.clock_now(clock_index, now_usec);
+            let deadline = *this.deadline.get_or_insert(now + this.duration);
+
+            if now >= deadline {
+                return Poll::Ready(());
+            }
+
+            let index = this
+                .entry_id
+                .and_then(|id| driver.timers.iter().position(|entry| entry.id == id));
+
+            match index {
+                Some(index) => replace_waker(&mut driver.timers[index].waker, cx.waker()),
+                None => {
+                    let id = driver.next_entry_id();
+                    driver.timers.push(TimerEntry {
+                        id,
+                        clock_index,
+                        deadline,
+                        waker: cx.waker().clone(),
+                    });
+                    this.entry_id = Some(id);
+                    None
+                }
+            }
+        };
+
+        // Wakers may hold task state; drop them outside the lock.
+        drop(replaced_waker);
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        let Some(id) = self.entry_id else {
+            return;
+        };
+
+        let removed = {
+            let mut driver = FRAME_DRIVER.lock();
+            remove_entry(&mut driver.timers, |entry| entry.id == id)
+        };
+        drop(removed);
+    }
+}
+
+/// Future returned by [`next_frame()`] and [`next_physics_frame()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct NextFrame {
+    physics: bool,
+    target: Option<u64>,
+    entry_id: Option<u64>,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+
+        let replaced_waker = {
+            let mut driver = FRAME_DRIVER.lock();
+
+            let current = if this.physics {
+                driver.physics_frames
+            } else {
+                driver.process_frames
+            };
+
+            let target = *this.target.get_or_insert(current + 1);
+
+            if current >= target {
+                return Poll::Ready(());
+            }
+
+            let physics = this.physics;
+            let index = this.entry_id.and_then(|id| {
+                let waiters = driver.frame_waiters(physics);
+                waiters.iter().position(|waiter| waiter.id == id)
+            });
+
+            match index {
+                Some(index) => {
+                    replace_waker(&mut driver.frame_waiters(physics)[index].waker, cx.waker())
+                }
+                None => {
+                    let id = driver.next_entry_id();
+                    driver.frame_waiters(physics).push(FrameWaiter {
+                        id,
+                        waker: cx.waker().clone(),
+                    });
+                    this.entry_id = Some(id);
+                    None
+                }
+            }
+        };
+
+        drop(replaced_waker);
+        Poll::Pending
+    }
+}
+
+impl Drop for NextFrame {
+    fn drop(&mut self) {
+        let Some(id) = self.entry_id else {
+            return;
+        };
+
+        let removed = {
+            let mut driver = FRAME_DRIVER.lock();
+            remove_entry(driver.frame_waiters(self.physics), |waiter| waiter.id == id)
+        };
+        drop(removed);
+    }
+}
+
+/// Future returned by [`timeout()`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Timeout<F: Future> {
+    future: Pin<Box<F>>,
+    sleep: Sleep,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if let Poll::Ready(value) = this.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(value));
+        }
+
+        match Pin::new(&mut this.sleep).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(TimeoutError)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Error returned by [`Timeout`] if the timer elapsed before the inner future completed.
+#[derive(Debug)]
+pub struct TimeoutError;
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The future did not complete before the timeout elapsed")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Frame driver
+
+static FRAME_DRIVER: sys::Global<FrameDriver> = sys::Global::default();
+
+/// Advances all clocks and wakes up due futures. Called once per process frame, from the main loop callback.
+pub(crate) fn on_main_loop_frame() {
+    let now_usec = Time::singleton().get_ticks_usec();
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok());
+
+    let paused = tree.as_ref().is_some_and(|tree| tree.is_paused());
+    let time_scale = Engine::singleton().get_time_scale();
+
+    let (wakers, connect_physics) = {
+        let mut driver = FRAME_DRIVER.lock();
+
+        for index in 0..ClockKind::COUNT {
+            driver.clocks[index] = driver.clock_now(index, now_usec);
+        }
+
+        driver.last_ticks_usec = Some(now_usec);
+        driver.paused = paused;
+        driver.time_scale = time_scale;
+
+        driver.process_frames += 1;
+        let mut wakers: Vec<Waker> = std::mem::take(&mut driver.process_waiters)
+            .into_iter()
+            .map(|waiter| waiter.waker)
+            .collect();
+
+        let clocks = driver.clocks;
+        let (due, pending) = std::mem::take(&mut driver.timers)
+            .into_iter()
+            .partition::<Vec<_>, _>(|timer| clocks[timer.clock_index] >= timer.deadline);
+
+        driver.timers = pending;
+        wakers.extend(due.into_iter().map(|timer| timer.waker));
+
+        let connect_physics = !driver.physics_connected && tree.is_some();
+        driver.physics_connected |= connect_physics;
+
+        (wakers, connect_physics)
+    };
+
+    // Wake outside the lock: wakers may poll right away and register new timers.
+    for waker in wakers {
+        waker.wake();
+    }
+
+    if let (true, Some(mut tree)) = (connect_physics, tree) {
+        let callable = Callable::from_fn("task::on_physics_frame", |_args| on_physics_frame());
+        tree.connect("physics_frame", &callable);
+    }
+}
+
+fn on_physics_frame() {
+    let wakers = {
+        let mut driver = FRAME_DRIVER.lock();
+        driver.physics_frames += 1;
+        std::mem::take(&mut driver.physics_waiters)
+    };
+
+    for waiter in wakers {
+        waiter.waker.wake();
+    }
+}
+
+/// Will be called during engine shutdown.
+pub(crate) fn cleanup() {
+    // Wakers may hold task state; drop them outside the lock.
+    let driver = std::mem::take(&mut *FRAME_DRIVER.lock());
+    drop(driver);
+}
+
+/// Number of timers that are currently waiting for their deadline.
+#[cfg(feature = "trace")]
+pub fn pending_timer_count() -> usize {
+    FRAME_DRIVER.lock().timers.len()
+}
+
+#[derive(Default)]
+struct FrameDriver {
+    /// Game time in seconds, one clock per [`ClockKind`].
+    clocks: [f64; ClockKind::COUNT],
+    last_ticks_usec: Option<u64>,
+    /// Pause state and time scale of the last frame; used to extrapolate the clocks until the next frame.
+    paused: bool,
+    time_scale: f64,
+    timers: Vec<TimerEntry>,
+    /// Last ID handed out to a timer or frame waiter.
+    last_entry_id: u64,
+
+    process_frames: u64,
+    process_waiters: Vec<FrameWaiter>,
+    physics_frames: u64,
+    physics_waiters: Vec<FrameWaiter>,
+    physics_connected: bool,
+}
+
+impl FrameDriver {
+    /// Value of the clock at `index` at time `now_usec`, including the time that passed since the last frame.
+    fn clock_now(&self, index: usize, now_usec: u64) -> f64 {
+        let clock = self.clocks[index];
+        let kind = ClockKind::from_index(index);
+
+        let Some(last) = self.last_ticks_usec else {
+            return clock;
+        };
+
+        if self.paused && !kind.process_always {
+            return clock;
+        }
+
+        let delta = now_usec.saturating_sub(last) as f64 / 1_000_000.0;
+        if kind.ignore_time_scale {
+            clock + delta
+        } else {
+            clock + delta * self.time_scale
+        }
+    }
+
+    fn frame_waiters(&mut self, physics: bool) -> &mut Vec<FrameWaiter> {
+        if physics {
+            &mut self.physics_waiters
+        } else {
+            &mut self.process_waiters
+        }
+    }
+
+    fn next_entry_id(&mut self) -> u64 {
+        self.last_entry_id += 1;
+        self.last_entry_id
+    }
+}
+
+struct TimerEntry {
+    /// Identifies the [`Sleep`] future that registered this entry.
+    id: u64,
+    clock_index: usize,
+    deadline: f64,
+    waker: Waker,
+}
+
+struct FrameWaiter {
+    /// Identifies the [`NextFrame`] future that registered this entry.
+    id: u64,
+    waker: Waker,
+}
+
+#[derive(Copy, Clone, Default)]
+struct ClockKind {
+    process_always: bool,
+    ignore_time_scale: bool,
+}
+
+impl ClockKind {
+    const COUNT: usize = 4;
+
+    fn index(self) -> usize {
+        (self.process_always as usize) | ((self.ignore_time_scale as usize) << 1)
+    }
+
+    fn from_index(index: usize) -> Self {
+        Self {
+            process_always: index & 1 != 0,
+            ignore_time_scale: index & 2 != 0,
+        }
+    }
+}
+
+/// Updates a registered waker if it doesn't wake the same task as `waker`. Returns the replaced waker, to be dropped outside the lock.
+fn replace_waker(registered: &mut Waker, waker: &Waker) -> Option<Waker> {
+    if registered.will_wake(waker) {
+        None
+    } else {
+        Some(std::mem::replace(registered, waker.clone()))
+    }
+}
+
+/// Removes the first entry matching `predicate`. Returns it, to be dropped outside the lock.
+fn remove_entry<T>(entries: &mut Vec<T>, predicate: impl Fn(&T) -> bool) -> Option<T> {
+    let index = entries.iter().position(predicate)?;
+    Some(entries.swap_remove(index))
+}
@@ -0,0 +1,597 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::builtin::{GString, StringName, VarArray, VarDictionary, VariantType};
+use crate::classes::ClassDb;
+use crate::global::{self, MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::obj::{EngineBitfield, EngineEnum, Singleton};
+use crate::registry::class::loaded_class_ids;
+
+/// Format in which [`export_class_api()`] writes the API of Rust classes.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum ApiExportFormat {
+    /// One GDScript stub file `<ClassName>.gd` per class. See [`ClassApi::to_gdscript_stub()`].
+    GdscriptStubs,
+
+    /// A single `extension_api.json` file describing all classes. See [`class_api_to_json()`].
+    Json,
+}
+
+/// API of a class registered from Rust, as seen by Godot.
+///
+/// Obtained through [`collect_class_api()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClassApi {
+    pub name: String,
+
+    /// Direct base class, e.g. `"Node2D"`.
+    pub base: String,
+
+    /// Methods declared by this class (not inherited), sorted by name.
+    pub methods: Vec<MethodApi>,
+
+    /// Properties declared by this class, in registration order. Groups and categories are not included.
+    pub properties: Vec<PropertyApi>,
+
+    /// Signals declared by this class, sorted by name.
+    pub signals: Vec<SignalApi>,
+
+    /// Integer constants that are not part of an enum, in registration order.
+    pub constants: Vec<ConstantApi>,
+
+    /// Enums and bitfields, in registration order.
+    pub enums: Vec<EnumApi>,
+}
+
+/// Method of a [`ClassApi`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct MethodApi {
+    pub name: String,
+    pub params: Vec<PropertyApi>,
+
+    /// Default values of the last `default_args.len()` parameters, in GDScript syntax (see [`var_to_str()`][crate::global::var_to_str]).
+    pub default_args: Vec<String>,
+
+    /// Return type. Has an empty name; its type is `"void"` if the method returns nothing.
+    pub return_value: PropertyApi,
+
+    pub is_static: bool,
+    pub is_virtual: bool,
+    pub is_vararg: bool,
+}
+
+/// Property, parameter or return value.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PropertyApi {
+    pub name: String,
+
+    /// Type in GDScript syntax, e.g. `"int"`, `"Array[Node]"`, `"MyClass.Mode"` or `"Variant"`.
+    pub type_name: String,
+    pub variant_type: VariantType,
+}
+
+/// Signal of a [`ClassApi`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignalApi {
+    pub name: String,
+    pub params: Vec<PropertyApi>,
+}
+
+/// Integer constant, either standalone or as part of an [`EnumApi`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConstantApi {
+    pub name: String,
+    pub value: i64,
+}
+
+/// Enum or bitfield of a [`ClassApi`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct EnumApi {
+    pub name: String,
+
+    /// Always `false` before Godot 4.3, which cannot distinguish bitfields from enums.
+    pub is_bitfield: bool,
+    pub values: Vec<ConstantApi>,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Collection
+
+/// Collects the API of all classes that this extension has registered so far, sorted by class name.
+///
+/// The information is queried from Godot's `ClassDB`, so it reflects exactly what GDScript and other languages see: `#[func]`, `#[var]`,
+/// `#[signal]` and `#[constant]` items, as well as everything registered through the [builder API][crate::builder]. Classes are only
+/// available once their [`InitLevel`][crate::init::InitLevel] has been loaded.
+pub fn collect_class_api() -> Vec<ClassApi> {
+    let mut class_names: Vec<String> = loaded_class_ids()
+        .iter()
+        .map(|class_id| class_id.to_string())
+        .collect();
+    class_names.sort();
+
+    class_names
+        .iter()
+        .map(|name| collect_class(&StringName::from(name.as_str())))
+        .collect()
+}
+
+fn collect_class(class: &StringName) -> ClassApi {
+    let db = ClassDb::singleton();
+
+    let mut methods: Vec<MethodApi> = db
+        .class_get_method_list_ex(class)
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .map(|dict| to_method(&dict))
+        .collect();
+    methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let properties = db
+        .class_get_property_list_ex(class)
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .filter(|dict| !is_group_or_category(dict))
+        .map(|dict| to_property(&dict))
+        .collect();
+
+    let mut signals: Vec<SignalApi> = db
+        .class_get_signal_list_ex(class)
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .map(|dict| SignalApi {
+            name: dict.at("name").to::<GString>().to_string(),
+            params: to_params(&dict),
+        })
+        .collect();
+    signals.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let enums = db
+        .class_get_enum_list_ex(class)
+        .no_inheritance(true)
+        .done()
+        .as_slice()
+        .iter()
+        .map(|enum_name| to_enum(class, &enum_name.to_string()))
+        .collect();
+
+    let constants = db
+        .class_get_integer_constant_list_ex(class)
+        .no_inheritance(true)
+        .done()
+        .as_slice()
+        .iter()
+        .map(GString::to_string)
+        .filter(|constant| {
+            db.class_get_integer_constant_enum(class, constant)
+                .is_empty()
+        })
+        .map(|constant| ConstantApi {
+            value: db.class_get_integer_constant(class, &constant),
+            name: constant,
+        })
+        .collect();
+
+    ClassApi {
+        name: class.to_string(),
+        base: db.get_parent_class(class).to_string(),
+        methods,
+        properties,
+        signals,
+        constants,
+        enums,
+    }
+}
+
+fn to_method(dict: &VarDictionary) -> MethodApi {
+    let flags = dict.at("flags").to::<i64>() as u64;
+    let is_set = |flag: MethodFlags| flags & flag.ord() != 0;
+
+    let default_args = dict
+        .at("default_args")
+        .to::<VarArray>()
+        .iter_shared()
+        .map(|value| global::var_to_str(&value).to_string())
+        .collect();
+
+    MethodApi {
+        name: dict.at("name").to::<GString>().to_string(),
+        params: to_params(dict),
+        default_args,
+        return_value: to_property(&dict.at("return").to::<VarDictionary>()),
+        is_static: is_set(MethodFlags::STATIC),
+        is_virtual: is_set(MethodFlags::VIRTUAL),
+        is_vararg: is_set(MethodFlags::VARARG),
+    }
+}
+
+fn to_params(dict: &VarDictionary) -> Vec<PropertyApi> {
+    // Godot stores arguments in an untyped array.
+    dict.at("args")
+        .to::<VarArray>()
+        .iter_shared()
+        .map(|arg| to_property(&arg.to::<VarDictionary>()))
+        .collect()
+}
+
+fn to_property(dict: &VarDictionary) -> PropertyApi {
+    let variant_type = dict.at("type").to::<VariantType>();
+    let class_name = dict.at("class_name").to::<StringName>().to_string();
+    let hint = dict.at("hint").to::<PropertyHint>();
+    let hint_string = dict.at("hint_string").to::<GString>().to_string();
+    let usage = dict.at("usage").to::<i64>() as u64;
+
+    let type_name = match variant_type {
+        VariantType::NIL if usage & PropertyUsageFlags::NIL_IS_VARIANT.ord() != 0 => {
+            "Variant".to_string()
+        }
+        VariantType::NIL => "void".to_string(),
+
+        // Objects carry their class, enums and bitfields their qualified name (e.g. `MyClass.Mode`).
+        VariantType::OBJECT | VariantType::INT if !class_name.is_empty() => class_name,
+        VariantType::OBJECT => "Object".to_string(),
+
+        VariantType::ARRAY if hint == PropertyHint::ARRAY_TYPE && !hint_string.is_empty() => {
+            format!("Array[{hint_string}]")
+        }
+
+        #[cfg(since_api = "4.4")]
+        VariantType::DICTIONARY if hint == PropertyHint::DICTIONARY_TYPE => {
+            match hint_string.split_once(';') {
+                Some((key, value)) => format!("Dictionary[{key}, {value}]"),
+                None => "Dictionary".to_string(),
+            }
+        }
+
+        _ => global::type_string(variant_type.ord() as i64).to_string(),
+    };
+
+    PropertyApi {
+        name: dict.at("name").to::<GString>().to_string(),
+        type_name,
+        variant_type,
+    }
+}
+
+fn to_enum(class: &StringName, enum_name: &str) -> EnumApi {
+    let db = ClassDb::singleton();
+
+    let values = db
+        .class_get_enum_constants_ex(class, enum_name)
+        .no_inheritance(true)
+        .done()
+        .as_slice()
+        .iter()
+        .map(GString::to_string)
+        .map(|constant| ConstantApi {
+            value: db.class_get_integer_constant(class, &constant),
+            name: constant,
+        })
+        .collect();
+
+    #[cfg(since_api = "4.3")]
+    let is_bitfield = db.is_class_enum_bitfield(class, enum_name);
+    #[cfg(before_api = "4.3")]
+    let is_bitfield = false;
+
+    EnumApi {
+        name: enum_name.to_string(),
+        is_bitfield,
+        values,
+    }
+}
+
+fn is_group_or_category(dict: &VarDictionary) -> bool {
+    let usage = dict.at("usage").to::<i64>() as u64;
+    let mask =
+        PropertyUsageFlags::GROUP | PropertyUsageFlags::SUBGROUP | PropertyUsageFlags::CATEGORY;
+
+    usage & mask.ord() != 0
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Output
+
+/// Writes the API of all classes registered by this extension to the directory `dir`, which is created if necessary.
+///
+/// This is meant to be called from [`ExtensionLibrary::on_stage_init()`][crate::init::ExtensionLibrary::on_stage_init], once all classes
+/// are registered. The output is deterministic, so it can be checked into version control and diffed in CI. A typical setup exports only
+/// on request, e.g. by running `godot --headless --quit` with an environment variable:
+///
+/// ```no_run
+/// use godot::init::*;
+/// use godot::tools::{ApiExportFormat, export_class_api};
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_stage_init(stage: InitStage) {
+///         if stage == InitStage::Scene {
+///             if let Ok(dir) = std::env::var("MY_GAME_API_DIR") {
+///                 export_class_api(&dir, ApiExportFormat::Json).expect("API export failed");
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Generated `.gd` stubs declare the same `class_name` as the Rust classes. Keep them outside the Godot project, or in a directory with a
+/// `.gdignore` file, to avoid conflicts.
+pub fn export_class_api(dir: impl AsRef<Path>, format: ApiExportFormat) -> std::io::Result<()> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    let classes = collect_class_api();
+    match format {
+        ApiExportFormat::GdscriptStubs => {
+            for class in &classes {
+                std::fs::write(
+                    dir.join(format!("{}.gd", class.name)),
+                    class.to_gdscript_stub(),
+                )?;
+            }
+        }
+        ApiExportFormat::Json => {
+            std::fs::write(dir.join("extension_api.json"), class_api_to_json(&classes))?;
+        }
+    }
+
+    Ok(())
+}
+
+impl ClassApi {
+    /// Renders this class as GDScript source, with the same members and signatures but empty bodies.
+    ///
+    /// Intended for IDE completion and code review. Functions with a return type return a default value of that type.
+    pub fn to_gdscript_stub(&self) -> String {
+        let mut out = String::new();
+
+        // Writing to String is infallible.
+        let _ = writeln!(
+            out,
+            "# Generated by godot-rust from the registered API of class `{}`.",
+            self.name
+        );
+        let _ = writeln!(out, "class_name {}", self.name);
+        let _ = writeln!(out, "extends {}", self.base);
+
+        if !self.signals.is_empty() {
+            out.push('\n');
+            for signal in &self.signals {
+                let _ = writeln!(
+                    out,
+                    "signal {}({})",
+                    signal.name,
+                    gdscript_params(&signal.params, &[])
+                );
+            }
+        }
+
+        for enum_ in &self.enums {
+            out.push('\n');
+            if enum_.is_bitfield {
+                out.push_str("# Bitfield.\n");
+            }
+
+            let _ = writeln!(out, "enum {} {{", enum_.name);
+            for value in &enum_.values {
+                let _ = writeln!(out, "\t{} = {},", value.name, value.value);
+            }
+            out.push_str("}\n");
+        }
+
+        if !self.constants.is_empty() {
+            out.push('\n');
+            for constant in &self.constants {
+                let _ = writeln!(out, "const {} = {}", constant.name, constant.value);
+            }
+        }
+
+        if !self.properties.is_empty() {
+            out.push('\n');
+            for property in &self.properties {
+                let _ = writeln!(out, "var {}: {}", property.name, property.type_name);
+            }
+        }
+
+        for method in &self.methods {
+            out.push('\n');
+            if method.is_virtual {
+                out.push_str("# Virtual; may be overridden by scripts.\n");
+            }
+            if method.is_vararg {
+                out.push_str("# Accepts additional arguments.\n");
+            }
+
+            let _ = writeln!(
+                out,
+                "{static_}func {}({}) -> {}:",
+                method.name,
+                gdscript_params(&method.params, &method.default_args),
+                method.return_value.type_name,
+                static_ = if method.is_static { "static " } else { "" },
+            );
+
+            match gdscript_default_value(&method.return_value) {
+                Some(value) => {
+                    let _ = writeln!(out, "\treturn {value}");
+                }
+                None => out.push_str("\tpass\n"),
+            }
+        }
+
+        out
+    }
+}
+
+fn gdscript_params(params: &[PropertyApi], default_args: &[String]) -> String {
+    let first_default = params.len().saturating_sub(default_args.len());
+
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let mut decl = format!("{}: {}", param.name, param.type_name);
+            if let Some(default) = i
+                .checked_sub(first_default)
+                .and_then(|d| default_args.get(d))
+            {
+                let _ = write!(decl, " = {default}");
+            }
+            decl
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// GDScript expression producing a default value of the given type, or `None` for `void`.
+fn gdscript_default_value(ty: &PropertyApi) -> Option<String> {
+    let value = match ty.variant_type {
+        VariantType::NIL if ty.type_name == "void" => return None,
+        VariantType::NIL | VariantType::OBJECT => "null".to_string(),
+        VariantType::INT if ty.type_name != "int" => format!("0 as {}", ty.type_name),
+        VariantType::ARRAY => "[]".to_string(),
+        VariantType::DICTIONARY => "{}".to_string(),
+        _ => format!("{}()", ty.type_name),
+    };
+
+    Some(value)
+}
+
+/// Renders the API of the given classes as a JSON document, with a top-level `classes` array.
+///
+/// Each class is an object with the keys `name`, `base`, `methods`, `properties`, `signals`, `constants` and `enums`, mirroring the
+/// fields of [`ClassApi`]. Types are given in GDScript syntax.
+pub fn class_api_to_json(classes: &[ClassApi]) -> String {
+    let mut out = String::new();
+    out.push_str("{\n  \"classes\": [");
+
+    for (i, class) in classes.iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+
+        let methods = json_list(&class.methods, 4, |method| {
+            format!(
+                "{{\"name\": {}, \"params\": [{}], \"default_args\": [{}], \"return_type\": {}, \
+                 \"is_static\": {}, \"is_virtual\": {}, \"is_vararg\": {}}}",
+                json_string(&method.name),
+                json_params(&method.params),
+                method
+                    .default_args
+                    .iter()
+                    .map(|arg| json_string(arg))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                json_string(&method.return_value.type_name),
+                method.is_static,
+                method.is_virtual,
+                method.is_vararg,
+            )
+        });
+        let properties = json_list(&class.properties, 4, json_property);
+        let signals = json_list(&class.signals, 4, |signal| {
+            format!(
+                "{{\"name\": {}, \"params\": [{}]}}",
+                json_string(&signal.name),
+                json_params(&signal.params)
+            )
+        });
+        let constants = json_list(&class.constants, 4, json_constant);
+        let enums = json_list(&class.enums, 4, |enum_| {
+            format!(
+                "{{\"name\": {}, \"is_bitfield\": {}, \"values\": [{}]}}",
+                json_string(&enum_.name),
+                enum_.is_bitfield,
+                enum_
+                    .values
+                    .iter()
+                    .map(json_constant)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        });
+
+        let _ = write!(
+            out,
+            "    {{\n      \"name\": {},\n      \"base\": {},\n      \"methods\": {methods},\n      \"properties\": {properties},\n      \
+             \"signals\": {signals},\n      \"constants\": {constants},\n      \"enums\": {enums}\n    }}",
+            json_string(&class.name),
+            json_string(&class.base),
+        );
+    }
+
+    if !classes.is_empty() {
+        out.push_str("\n  ");
+    }
+    out.push_str("]\n}\n");
+    out
+}
+
+/// JSON array with one element per line, at the given indentation depth (2 spaces per level).
+fn json_list<T>(items: &[T], depth: usize, to_json: impl Fn(&T) -> String) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+
+    let indent = "  ".repeat(depth);
+    let elements: Vec<String> = items
+        .iter()
+        .map(|item| format!("{indent}{}", to_json(item)))
+        .collect();
+
+    format!("[\n{}\n{}]", elements.join(",\n"), "  ".repeat(depth - 1))
+}
+
+fn json_params(params: &[PropertyApi]) -> String {
+    params
+        .iter()
+        .map(json_property)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn json_property(property: &PropertyApi) -> String {
+    format!(
+        "{{\"name\": {}, \"type\": {}}}",
+        json_string(&property.name),
+        json_string(&property.type_name)
+    )
+}
+
+fn json_constant(constant: &ConstantApi) -> String {
+    format!(
+        "{{\"name\": {}, \"value\": {}}}",
+        json_string(&constant.name),
+        constant.value
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
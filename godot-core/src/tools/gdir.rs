@@ -0,0 +1,319 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::ErrorKind;
+
+use crate::builtin::GString;
+use crate::classes::{DirAccess, FileAccess};
+use crate::global::Error;
+use crate::meta::{AsArg, arg_into_ref};
+use crate::obj::Gd;
+
+/// Open a directory and list or manipulate its contents.
+///
+/// This is a wrapper around [`DirAccess`], modeled after [`std::fs`]. It works with Godot's virtual file system, so paths can start with
+/// `res://` or `user://` in addition to absolute OS paths. Like [`GFile`][crate::tools::GFile], all fallible operations return
+/// [`std::io::Result`].
+///
+/// Directory listing is available through [`GDir::read_dir()`] or [`GDir::entries()`], which yield [`DirEntry`] items. The special
+/// entries `.` and `..` are never listed.
+///
+/// Operations such as [`create_dir_all()`][Self::create_dir_all], [`remove()`][Self::remove], [`rename()`][Self::rename] and
+/// [`copy()`][Self::copy] are associated functions, taking full paths like their `std::fs` counterparts.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use godot::builtin::GString;
+/// use godot::tools::GDir;
+///
+/// fn collect_levels(dir: &GString, levels: &mut Vec<GString>) -> std::io::Result<()> {
+///     for entry in GDir::read_dir(dir)? {
+///         let entry = entry?;
+///
+///         if entry.is_dir() {
+///             collect_levels(&entry.path(), levels)?;
+///         } else if entry.file_name().ends_with(".tscn") {
+///             levels.push(entry.path());
+///         }
+///     }
+///
+///     Ok(())
+/// }
+///
+/// fn backup_saves() -> std::io::Result<()> {
+///     GDir::create_dir_all("user://backup/saves")?;
+///     GDir::copy("user://save_game.sav", "user://backup/saves/save_game.sav")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## See also
+///
+/// - [`DirAccess`] class in Rust.
+/// - [Godot documentation](https://docs.godotengine.org/en/stable/classes/class_diraccess.html) for `DirAccess`.
+pub struct GDir {
+    da: Gd<DirAccess>,
+}
+
+impl GDir {
+    /// Open a directory.
+    ///
+    /// Fails with [`ErrorKind::NotFound`] if there is no directory at `path`.
+    pub fn open(path: impl AsArg<GString>) -> std::io::Result<Self> {
+        arg_into_ref!(path);
+
+        // Godot reports a missing directory as ERR_INVALID_PARAMETER, which is not specific enough.
+        if !Self::exists(path) {
+            return Err(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("can't open directory {path}: no such directory"),
+            ));
+        }
+
+        let da = DirAccess::open(path).ok_or_else(|| {
+            io_error(
+                DirAccess::get_open_error(),
+                format!("can't open directory {path}"),
+            )
+        })?;
+
+        Ok(Self { da })
+    }
+
+    /// Returns an iterator over the entries of the directory at `path`.
+    ///
+    /// Shorthand for `GDir::open(path)?.entries()`. Hidden files are skipped; open the directory with [`GDir::open()`] and call
+    /// [`set_include_hidden()`][Self::set_include_hidden] to list them.
+    pub fn read_dir(path: impl AsArg<GString>) -> std::io::Result<ReadDir> {
+        Self::open(path)?.entries()
+    }
+
+    /// Returns an iterator over the entries of this directory.
+    ///
+    /// The iterator takes over the directory handle, since Godot keeps the listing state inside the `DirAccess` object.
+    pub fn entries(mut self) -> std::io::Result<ReadDir> {
+        let dir_path = self.path();
+
+        check(
+            self.da.list_dir_begin(),
+            format!("can't list directory {dir_path}"),
+        )?;
+
+        Ok(ReadDir {
+            da: self.da,
+            dir_path,
+            finished: false,
+        })
+    }
+
+    /// Whether [`entries()`][Self::entries] lists hidden files. Default is `false`.
+    pub fn set_include_hidden(&mut self, include_hidden: bool) {
+        self.da.set_include_hidden(include_hidden);
+    }
+
+    /// Path of the opened directory.
+    #[doc(alias = "get_current_dir")]
+    pub fn path(&self) -> GString {
+        self.da.get_current_dir()
+    }
+
+    /// Retrieve inner pointer to the [`DirAccess`].
+    pub fn into_inner(self) -> Gd<DirAccess> {
+        self.da
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Operations on paths.
+
+    /// Creates a new, empty directory at `path`.
+    ///
+    /// The parent directory must exist; see [`create_dir_all()`][Self::create_dir_all] to create it as well.
+    #[doc(alias = "make_dir_absolute")]
+    pub fn create_dir(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check(
+            DirAccess::make_dir_absolute(path),
+            format!("can't create directory {path}"),
+        )
+    }
+
+    /// Creates a directory at `path`, including all missing parent directories.
+    ///
+    /// Succeeds if the directory already exists.
+    #[doc(alias = "make_dir_recursive_absolute")]
+    pub fn create_dir_all(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check(
+            DirAccess::make_dir_recursive_absolute(path),
+            format!("can't create directory {path}"),
+        )
+    }
+
+    /// Removes the file or the empty directory at `path`.
+    ///
+    /// Non-empty directories cannot be removed; remove their entries first.
+    #[doc(alias = "remove_absolute")]
+    pub fn remove(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        if !Self::exists(path) && !FileAccess::file_exists(path) {
+            return Err(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("can't remove {path}: no such file or directory"),
+            ));
+        }
+
+        check(
+            DirAccess::remove_absolute(path),
+            format!("can't remove {path}"),
+        )
+    }
+
+    /// Renames or moves the file or directory `from` to `to`.
+    ///
+    /// If `to` is an existing file, it is overwritten.
+    #[doc(alias = "rename_absolute")]
+    pub fn rename(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        check(
+            DirAccess::rename_absolute(from, to),
+            format!("can't rename {from} to {to}"),
+        )
+    }
+
+    /// Copies the file `from` to `to`, overwriting `to` if it exists.
+    ///
+    /// Only files can be copied, not directories.
+    #[doc(alias = "copy_absolute")]
+    pub fn copy(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        check(
+            DirAccess::copy_absolute(from, to),
+            format!("can't copy {from} to {to}"),
+        )
+    }
+
+    /// Whether a directory exists at `path`.
+    #[doc(alias = "dir_exists_absolute")]
+    pub fn exists(path: impl AsArg<GString>) -> bool {
+        arg_into_ref!(path);
+        DirAccess::dir_exists_absolute(path)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Directory listing.
+
+/// Iterator over the entries of a directory, returned by [`GDir::read_dir()`] and [`GDir::entries()`].
+pub struct ReadDir {
+    da: Gd<DirAccess>,
+    dir_path: GString,
+    finished: bool,
+}
+
+impl Iterator for ReadDir {
+    type Item = std::io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            let file_name = self.da.get_next();
+
+            if file_name.is_empty() {
+                self.finished = true;
+                self.da.list_dir_end();
+                return None;
+            }
+
+            // Godot only lists these if `include_navigational` is set, but the `DirAccess` may have been configured elsewhere.
+            if file_name == "." || file_name == ".." {
+                continue;
+            }
+
+            let is_dir = self.da.current_is_dir();
+            let path = self.dir_path.path_join(&file_name);
+
+            return Some(Ok(DirEntry {
+                path,
+                file_name,
+                is_dir,
+            }));
+        }
+    }
+}
+
+impl Drop for ReadDir {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.da.list_dir_end();
+        }
+    }
+}
+
+/// Entry inside a directory, yielded by [`ReadDir`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DirEntry {
+    path: GString,
+    file_name: GString,
+    is_dir: bool,
+}
+
+impl DirEntry {
+    /// Full path of the entry, i.e. the directory path joined with [`file_name()`][Self::file_name].
+    pub fn path(&self) -> GString {
+        self.path.clone()
+    }
+
+    /// Name of the entry, without the directory path.
+    pub fn file_name(&self) -> GString {
+        self.file_name.clone()
+    }
+
+    /// Whether the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Whether the entry is a file.
+    pub fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Private helpers.
+
+fn check(error: Error, context: String) -> std::io::Result<()> {
+    if error == Error::OK {
+        Ok(())
+    } else {
+        Err(io_error(error, context))
+    }
+}
+
+fn io_error(error: Error, context: String) -> std::io::Error {
+    let kind = match error {
+        Error::ERR_FILE_NOT_FOUND | Error::ERR_FILE_BAD_PATH => ErrorKind::NotFound,
+        Error::ERR_ALREADY_EXISTS => ErrorKind::AlreadyExists,
+        Error::ERR_FILE_NO_PERMISSION | Error::ERR_UNAUTHORIZED => ErrorKind::PermissionDenied,
+        Error::ERR_INVALID_PARAMETER => ErrorKind::InvalidInput,
+        _ => ErrorKind::Other,
+    };
+
+    std::io::Error::new(kind, format!("{context}; GodotError: {error:?}"))
+}
@@ -84,12 +84,14 @@ use crate::obj::Gd;
 ///
 /// ## See also
 ///
+/// - [`GDir`](crate::tools::GDir) for listing and manipulating directories.
 /// - [`FileAccess`] class in Rust.
 /// - [Godot documentation](https://docs.godotengine.org/en/stable/classes/class_fileaccess.html) for `FileAccess`.
 pub struct GFile {
     fa: Gd<FileAccess>,
     buffer: Vec<u8>,
-    last_buffer_size: usize,
+    buffer_start: u64,
+    buffer_len: usize,
     write_buffer: PackedByteArray,
     file_length: Option<u64>,
 }
@@ -432,7 +434,7 @@ impl GFile {
     #[doc(alias = "store_8")]
     pub fn write_u8(&mut self, value: u8) -> std::io::Result<()> {
         self.fa.store_8(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -444,7 +446,7 @@ impl GFile {
     #[doc(alias = "store_16")]
     pub fn write_u16(&mut self, value: u16) -> std::io::Result<()> {
         self.fa.store_16(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -456,7 +458,7 @@ impl GFile {
     #[doc(alias = "store_32")]
     pub fn write_u32(&mut self, value: u32) -> std::io::Result<()> {
         self.fa.store_32(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -468,7 +470,7 @@ impl GFile {
     #[doc(alias = "store_64")]
     pub fn write_u64(&mut self, value: u64) -> std::io::Result<()> {
         self.fa.store_64(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -480,7 +482,7 @@ impl GFile {
     #[doc(alias = "store_float")]
     pub fn write_f32(&mut self, value: f32) -> std::io::Result<()> {
         self.fa.store_float(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -492,7 +494,7 @@ impl GFile {
     #[doc(alias = "store_double")]
     pub fn write_f64(&mut self, value: f64) -> std::io::Result<()> {
         self.fa.store_double(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -522,7 +524,7 @@ impl GFile {
         #[cfg(not(feature = "double-precision"))]
         self.fa.store_float(value);
 
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -538,7 +540,7 @@ impl GFile {
         arg_into_ref!(value);
 
         self.fa.store_string(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -558,7 +560,7 @@ impl GFile {
         arg_into_ref!(value);
 
         self.fa.store_pascal_string(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -572,7 +574,7 @@ impl GFile {
         arg_into_ref!(value);
 
         self.fa.store_line(value);
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -592,7 +594,7 @@ impl GFile {
         arg_into_ref!(delim);
 
         self.fa.store_csv_line_ex(values).delim(delim).done();
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -611,7 +613,7 @@ impl GFile {
             .store_var_ex(&value)
             .full_objects(full_objects)
             .done();
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
         Ok(())
     }
@@ -683,10 +685,11 @@ impl GFile {
         file_length
     }
 
-    // The file length cache is cleared during writing operations, as this is the only place when the file length could be
-    // changed - unless file is modified by some other `GFile`, but we cannot do anything about it then.
-    fn clear_file_length(&mut self) {
+    // The file length cache and read buffer are cleared during writing operations, as this is the only place when the file
+    // length or contents could be changed - unless file is modified by some other `GFile`, but we cannot do anything about it then.
+    fn invalidate_caches(&mut self) {
         self.file_length = None;
+        self.buffer_len = 0;
     }
 
     // Private constructor function.
@@ -695,7 +698,8 @@ impl GFile {
         Self {
             fa,
             buffer: vec![0; Self::BUFFER_SIZE],
-            last_buffer_size: 0,
+            buffer_start: 0,
+            buffer_len: 0,
             write_buffer: PackedByteArray::new(),
             file_length,
         }
@@ -748,7 +752,7 @@ impl Write for GFile {
         self.pack_into_write_buffer(buf);
         self.fa
             .store_buffer(&self.write_buffer.subarray(0..buf.len()));
-        self.clear_file_length();
+        self.invalidate_caches();
         self.check_error()?;
 
         Ok(buf.len())
@@ -799,29 +803,33 @@ impl Seek for GFile {
 
 impl BufRead for GFile {
     fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
-        // We need to determine number of remaining bytes - otherwise the `FileAccess::get_buffer return in an error`.
-        let remaining_bytes = self.check_file_length() - self.fa.get_position();
-        let buffer_read_size = cmp::min(remaining_bytes as usize, Self::BUFFER_SIZE);
+        // Bytes read earlier are reused as long as the cursor is still within them. This keeps line-by-line reading from
+        // fetching the same chunk from Godot again after every consumed line.
+        let position = self.fa.get_position();
+        let buffer_end = self.buffer_start + self.buffer_len as u64;
 
-        // We need to keep the amount of last read side to be able to adjust cursor position in `consume`.
-        self.last_buffer_size = buffer_read_size;
-        self.buffer = vec![0; Self::BUFFER_SIZE];
+        if position < self.buffer_start || position >= buffer_end {
+            // We need to determine number of remaining bytes - otherwise the `FileAccess::get_buffer return in an error`.
+            let remaining_bytes = self.check_file_length().saturating_sub(position);
+            let buffer_read_size = cmp::min(remaining_bytes as usize, Self::BUFFER_SIZE);
 
-        let gd_buffer = self.fa.get_buffer(buffer_read_size as i64);
-        self.check_error()?;
+            let gd_buffer = self.fa.get_buffer(buffer_read_size as i64);
+            self.check_error()?;
 
-        let read_buffer = &mut self.buffer[0..gd_buffer.len()];
+            self.buffer[0..gd_buffer.len()].copy_from_slice(gd_buffer.as_slice());
+            self.buffer_start = position;
+            self.buffer_len = gd_buffer.len();
 
-        read_buffer.copy_from_slice(gd_buffer.as_slice());
+            // Cursor is being moved by `FileAccess::get_buffer()` call; it should only advance in `consume`.
+            self.fa.seek(position);
+        }
 
-        Ok(read_buffer)
+        let offset = (position - self.buffer_start) as usize;
+        Ok(&self.buffer[offset..self.buffer_len])
     }
 
     fn consume(&mut self, amt: usize) {
-        // Cursor is being moved by `FileAccess::get_buffer()` call, so we need to adjust it.
-        let offset = (self.last_buffer_size - amt) as i64;
-        let pos = SeekFrom::Current(-offset);
-
-        self.seek(pos).expect("failed to consume bytes during read");
+        let position = self.fa.get_position() + amt as u64;
+        self.fa.seek(position);
     }
 }
@@ -11,12 +11,20 @@
 //! or better integrated with Rust.
 
 mod autoload;
+mod class_api;
+mod gdir;
 mod gfile;
+#[cfg(feature = "codegen-full")]
+pub(crate) mod property_editor;
 mod save_load;
 mod translate;
 
 pub use autoload::*;
+pub use class_api::*;
+pub use gdir::*;
 pub use gfile::*;
+#[cfg(feature = "codegen-full")]
+pub use property_editor::{PropertyEditor, PropertySetter};
 pub use save_load::*;
 pub use translate::*;
 
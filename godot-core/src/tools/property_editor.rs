@@ -0,0 +1,175 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::{Control, EditorProperty};
+use crate::global::godot_error;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{Bounds, Gd, Inherits, NewAlloc, bounds};
+
+/// Typed Inspector editor for a single exported property.
+///
+/// Attach an editor to a field with `#[export(editor = MyEditor)]`. godot-rust then registers an inspector plugin for the class, which
+/// replaces Godot's default editor of that property with an instance of `MyEditor`. No string property names or manual
+/// `IEditorInspectorPlugin`/`IEditorProperty` implementations are necessary.
+///
+/// The editor can be any `Control` class. It is hosted inside an `EditorProperty`, which provides the property label, keying and
+/// revert buttons as usual.
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::HSlider;
+/// use godot::prelude::*;
+/// use godot::tools::{PropertyEditor, PropertySetter};
+///
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=HSlider)]
+/// struct PercentEditor {
+///     base: Base<HSlider>,
+///     setter: Option<PropertySetter<f32>>,
+/// }
+///
+/// impl PropertyEditor for PercentEditor {
+///     type Value = f32;
+///
+///     fn attach(&mut self, setter: PropertySetter<f32>) {
+///         // Store the setter, to call `setter.set(value)` from UI callbacks (e.g. the `value_changed` signal).
+///         self.setter = Some(setter);
+///     }
+///
+///     fn update_value(&mut self, value: f32) {
+///         self.base_mut().set_value_no_signal(value as f64 * 100.0);
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Fade {
+///     #[export(editor = PercentEditor)]
+///     opacity: f32,
+/// }
+/// ```
+pub trait PropertyEditor:
+    Inherits<Control> + NewAlloc + Bounds<Declarer = bounds::DeclUser>
+{
+    /// Type of the edited property.
+    ///
+    /// Usually the same as the field type, but any type that can be converted from the property's `Variant` works.
+    type Value: FromGodot + ToGodot;
+
+    /// Called once after the editor has been created for a property.
+    ///
+    /// The setter can be stored and used to commit values edited in the UI. The default implementation discards it, which is enough for
+    /// read-only editors.
+    fn attach(&mut self, setter: PropertySetter<Self::Value>) {
+        let _ = setter;
+    }
+
+    /// Called whenever the Inspector refreshes the property, e.g. when it is first shown, or after undo/redo.
+    ///
+    /// Should only update the UI, without committing the value again. For example, use `Range::set_value_no_signal()` rather than
+    /// `set_value()` if the `value_changed` signal is connected to the setter.
+    ///
+    /// Skipped while the editor is bound, e.g. when a `&mut self` method commits a value via [`PropertySetter::set()`] -- the editor
+    /// already displays that value.
+    fn update_value(&mut self, value: Self::Value);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Commits values of a property edited by a [`PropertyEditor`].
+///
+/// Changes are routed through the Inspector, which records them in the editor's undo/redo history and marks the edited object as modified.
+pub struct PropertySetter<V> {
+    host: Gd<EditorProperty>,
+    _value: PhantomData<fn(V)>,
+}
+
+impl<V: ToGodot> PropertySetter<V> {
+    fn new(host: Gd<EditorProperty>) -> Self {
+        Self {
+            host,
+            _value: PhantomData,
+        }
+    }
+
+    /// Sets the property to `value`, as an undoable action.
+    pub fn set(&self, value: V) {
+        let mut host = self.host.clone();
+        let property = host.get_edited_property();
+
+        host.emit_changed(&property, &value.to_variant());
+    }
+
+    /// Name of the edited property, as registered with Godot.
+    pub fn property_name(&self) -> StringName {
+        self.host.get_edited_property()
+    }
+}
+
+impl<V> Clone for PropertySetter<V> {
+    fn clone(&self) -> Self {
+        Self {
+            host: self.host.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Type-erased [`PropertyEditor`], stored in the `EditorProperty` generated by `#[export(editor = ...)]`.
+#[doc(hidden)]
+pub struct ErasedPropertyEditor {
+    update_fn: Box<dyn FnMut(&StringName, Variant)>,
+}
+
+impl ErasedPropertyEditor {
+    /// Instantiates `E` as child of `host`, and attaches a setter committing through `host`.
+    pub fn new<E: PropertyEditor>(mut host: Gd<EditorProperty>) -> Self {
+        let mut editor = E::new_alloc();
+        editor.bind_mut().attach(PropertySetter::new(host.clone()));
+
+        let control = editor.clone().upcast::<Control>();
+        host.add_child(&control);
+        host.add_focusable(&control);
+
+        let update_fn = move |property: &StringName, value: Variant| {
+            let value = match value.try_to::<E::Value>() {
+                Ok(value) => value,
+                Err(err) => {
+                    godot_error!(
+                        "property editor for `{property}`: value has unexpected type: {err}"
+                    );
+                    return;
+                }
+            };
+
+            // Fails if the editor is bound, i.e. the update was triggered by its own setter. It already displays that value.
+            if let Ok(mut editor) = editor.try_bind_mut() {
+                editor.update_value(value);
+            }
+        };
+
+        Self {
+            update_fn: Box::new(update_fn),
+        }
+    }
+
+    /// Forwards the current value of the edited property to the editor; to be called from `IEditorProperty::update_property()`.
+    pub fn update(&mut self, mut host: Gd<EditorProperty>) {
+        let Some(object) = host.get_edited_object() else {
+            return;
+        };
+
+        let property = host.get_edited_property();
+        let value = object.get(&property);
+        (self.update_fn)(&property, value);
+    }
+}
@@ -7,29 +7,71 @@
 
 use std::collections::{HashMap, HashSet};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream};
 use quote::quote;
 
 use crate::ParseResult;
-use crate::util::{KvParser, ListParser, bail, ident};
+use crate::util::{KvParser, ListParser, bail, ident, require_api_version};
 
 pub struct FieldExport {
     pub export_type: ExportType,
+    /// Custom Inspector editor from `#[export(editor = Type)]`; can be combined with the other keys.
+    #[cfg_attr(not(feature = "codegen-full"), allow(dead_code))]
+    pub editor: Option<TokenStream>,
     pub span: Span,
 }
 
 impl FieldExport {
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
+        let editor = parser.handle_expr_with_key("editor")?;
         let export_type = ExportType::new_from_kv(parser)?;
-        Ok(Self { export_type, span })
+
+        let editor = match editor {
+            Some((key, editor)) => Some(Self::validate_editor(key, editor, &export_type)?),
+            None => None,
+        };
+
+        Ok(Self {
+            export_type,
+            editor,
+            span,
+        })
+    }
+
+    #[cfg(feature = "codegen-full")]
+    fn validate_editor(
+        key: Ident,
+        editor: TokenStream,
+        export_type: &ExportType,
+    ) -> ParseResult<TokenStream> {
+        if matches!(export_type, ExportType::Storage) {
+            return bail!(
+                key,
+                "`editor` cannot be combined with `storage`, as storage properties are not shown in the Inspector"
+            );
+        }
+
+        Ok(editor)
+    }
+
+    #[cfg(not(feature = "codegen-full"))]
+    fn validate_editor(
+        key: Ident,
+        _editor: TokenStream,
+        _export_type: &ExportType,
+    ) -> ParseResult<TokenStream> {
+        bail!(
+            key,
+            "#[export(editor = ...)] requires the full engine API (default features of the `godot` crate)"
+        )
     }
 
     pub fn to_export_hint(&self) -> Option<TokenStream> {
         self.export_type.to_export_hint()
     }
 
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         self.export_type.to_export_usage()
     }
 }
@@ -134,6 +176,43 @@ pub enum ExportType {
     /// - `GLOBAL_DIR`
     File { global: bool, kind: FileKind },
 
+    /// ### GDScript annotations
+    /// - `@export_file_path`
+    ///
+    /// ### Property hints
+    /// - `FILE_PATH`
+    ///
+    /// Unlike `@export_file`, which stores a `uid://` reference since Godot 4.4, this stores the raw path.
+    FilePath { filter: Option<TokenStream> },
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { classes: Vec<TokenStream> },
+
+    /// ### GDScript annotations
+    /// - `@export_custom`
+    ///
+    /// ### Property hints
+    /// - any, as specified
+    ///
+    /// ### Property usage
+    /// - as specified, or `DEFAULT`
+    Custom {
+        hint: Ident,
+        hint_string: Option<TokenStream>,
+        usage: Vec<Ident>,
+    },
+
+    /// ### GDScript annotations
+    /// - any of the above on a typed array, e.g. `@export_range(0, 10) var a: Array[int]`
+    ///
+    /// ### Property hints
+    /// - `TYPE_STRING`, with the element hint encoded in the hint string
+    ArrayElements { element: Box<ExportType> },
+
     /// ### GDScript annotations
     /// - `@export_multiline`
     ///
@@ -167,7 +246,14 @@ impl ExportType {
     /// - `@export_{flags/enum}("elem1", "elem2:key2", ...)`
     ///   becomes
     ///   `#[export(flags/enum = (elem1, elem2 = key2, ...))]`
+    ///
+    /// The `elements` key applies the other key to the elements of a typed array: `#[export(elements, range = (0, 10))]`.
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        if let Some(elements) = parser.handle_alone_with_span("elements")? {
+            let element = Self::new_from_kv(parser)?;
+            return Self::new_array_elements(elements, element);
+        }
+
         if parser.handle_alone("storage")? {
             return Self::new_storage();
         }
@@ -280,6 +366,21 @@ impl ExportType {
             });
         }
 
+        if let Some((key, value)) = parser.handle_any_entry("file_path") {
+            require_api_version!("4.5", &key, "#[export(file_path)]")?;
+
+            let filter = value.map(|kv| kv.expr()).transpose()?;
+            return Ok(Self::FilePath { filter });
+        }
+
+        if let Some(list_parser) = parser.handle_list("node_path")? {
+            return Self::new_node_path(list_parser);
+        }
+
+        if let Some(list_parser) = parser.handle_list("custom")? {
+            return Self::new_custom(parser.span(), list_parser);
+        }
+
         if parser.handle_alone("multiline")? {
             return Ok(Self::Multiline);
         }
@@ -299,6 +400,80 @@ impl ExportType {
         Ok(Self::Storage)
     }
 
+    fn new_array_elements(elements: Ident, element: Self) -> ParseResult<Self> {
+        match element {
+            Self::Default => bail!(
+                elements,
+                "`elements` must be combined with another key, e.g. `#[export(elements, range = (0, 10))]`"
+            ),
+            Self::Storage
+            | Self::File { .. }
+            | Self::FilePath { .. }
+            | Self::ArrayElements { .. } => {
+                bail!(
+                    elements,
+                    "`elements` cannot be combined with this key; file and dir exports support arrays already"
+                )
+            }
+            Self::Custom { usage, .. } if !usage.is_empty() => bail!(
+                elements,
+                "`elements` cannot be combined with `custom(usage = ...)`, as usage applies to the whole array"
+            ),
+            element => Ok(Self::ArrayElements {
+                element: Box::new(element),
+            }),
+        }
+    }
+
+    fn new_node_path(mut parser: ListParser) -> ParseResult<Self> {
+        let mut classes = Vec::new();
+        while parser.peek().is_some() {
+            classes.push(parser.next_expr()?);
+        }
+
+        parser.finish()?;
+        Ok(Self::NodePath { classes })
+    }
+
+    fn new_custom(span: Span, mut parser: ListParser) -> ParseResult<Self> {
+        let mut hint = None;
+        let mut hint_string = None;
+        let mut usage = Vec::new();
+
+        // Keys without value are rejected by next_allowed_key_optional_value().
+        while let Some((key, Some(value))) =
+            parser.next_allowed_key_optional_value(&[], &["hint", "hint_string", "usage"])?
+        {
+            match key.to_string().as_str() {
+                "hint" => hint = Some(value.ident()?),
+                "hint_string" => hint_string = Some(value.expr()?),
+                "usage" => {
+                    let mut flags = ListParser::new_from_tree(value.single()?, Delimiter::Bracket)?;
+                    while let Some(flag) = flags.next_ident()? {
+                        usage.push(flag);
+                    }
+                    flags.finish()?;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        parser.finish()?;
+
+        let Some(hint) = hint else {
+            return bail!(
+                span,
+                "`custom` requires a `hint = ...` key, e.g. `#[export(custom(hint = ENUM, hint_string = \"A,B\"))]`"
+            );
+        };
+
+        Ok(Self::Custom {
+            hint,
+            hint_string,
+            usage,
+        })
+    }
+
     fn new_range_list(mut parser: ListParser) -> ParseResult<Self> {
         const FLAG_OPTIONS: [&str; 7] = [
             "or_greater",
@@ -538,13 +713,39 @@ impl ExportType {
             },
 
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            Self::FilePath { filter } => {
+                let filter = filter.clone().unwrap_or(quote! { "" });
+                quote_export_func! { export_file_path<T>(#filter) }
+            }
+
+            Self::NodePath { classes } => quote_export_func! {
+                export_node_path<T>(&[
+                    #( <#classes as ::godot::obj::GodotClass>::class_id() ),*
+                ])
+            },
+
+            Self::Custom {
+                hint, hint_string, ..
+            } => {
+                let hint_string = hint_string.clone().unwrap_or(quote! { "" });
+                quote_export_func! {
+                    export_custom(::godot::global::PropertyHint::#hint, #hint_string)
+                }
+            }
+
+            Self::ArrayElements { element } => {
+                let element_hint = element.to_export_hint()?;
+                quote_export_func! { export_array_elements<T>(#element_hint) }
+            }
         }
     }
 
-    /// Returns a `PropertyUsageFlags` identifier if this export type has a _usage_.
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    /// Returns `PropertyUsageFlags` identifiers if this export type has a _usage_.
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         match self {
-            Self::Storage => Some(ident("STORAGE")),
+            Self::Storage => Some(vec![ident("STORAGE")]),
+            Self::Custom { usage, .. } if !usage.is_empty() => Some(usage.clone()),
             _ => None,
         }
     }
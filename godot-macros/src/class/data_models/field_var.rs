@@ -435,6 +435,8 @@ impl GetterSetterImpl {
                 registered_name: Some(godot_function_name.to_string()),
                 is_script_virtual: false,
                 rpc_info: None,
+                error_mapping: None,
+                is_async: false,
             },
             None,
         );
@@ -30,6 +30,12 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// Strategy type implementing `ErrorMapping`, if the returned `Result` is mapped to a Godot value.
+    pub error_mapping: Option<TokenStream>,
+
+    /// True for `async fn`, which are spawned as tasks and return an awaitable signal to Godot.
+    pub is_async: bool,
 }
 
 impl FuncDefinition {
@@ -64,6 +70,7 @@ pub fn make_virtual_callback(
         signature_info,
         before_kind,
         interface_trait,
+        &ReturnConversion::default(),
     );
     let sig_params = signature_info.params_type();
     let sig_ret = &signature_info.return_type;
@@ -107,7 +114,20 @@ pub fn make_method_registration(
 
     let signature_info = &func_definition.signature_info;
     let sig_params = signature_info.params_type();
-    let sig_ret = &signature_info.return_type;
+
+    // String literals
+    let class_name_str = class_name.to_string();
+    let method_name_str = func_definition.godot_name();
+
+    let return_conversion = ReturnConversion {
+        error_mapping: func_definition.error_mapping.as_ref(),
+        async_func_name: func_definition
+            .is_async
+            .then(|| format!("{class_name_str}::{method_name_str}")),
+    };
+
+    // Godot sees the converted return type, both in calls and in the registered method info.
+    let sig_ret = return_conversion.godot_return_type(&signature_info.return_type);
 
     let is_script_virtual = func_definition.is_script_virtual;
     let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
@@ -121,6 +141,7 @@ pub fn make_method_registration(
         signature_info,
         BeforeKind::Without,
         interface_trait,
+        &return_conversion,
     );
 
     let default_parameters = make_default_argument_vec(
@@ -128,10 +149,6 @@ pub fn make_method_registration(
         &signature_info.param_types,
     )?;
 
-    // String literals
-    let class_name_str = class_name.to_string();
-    let method_name_str = func_definition.godot_name();
-
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
 
     // Both varcall and ptrcall functions are always generated and registered, even when default parameters are present via #[opt].
@@ -318,12 +335,15 @@ pub enum BeforeKind {
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
+///
+/// The value returned by the user method is converted according to `return_conversion`.
 fn make_forwarding_closure(
     class_name: &Ident,
     trait_base_class: &Ident,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
     interface_trait: Option<&venial::TypeExpr>,
+    return_conversion: &ReturnConversion,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
@@ -385,9 +405,9 @@ fn make_forwarding_closure(
                 // Methods are non-virtual.
 
                 sig_tuple_annotation = TokenStream::new();
-                method_call = quote! {
+                method_call = return_conversion.wrap_call(quote! {
                     __gdext_self.#method_name( #(#params),* )
-                };
+                });
             };
 
             quote! {
@@ -415,6 +435,10 @@ fn make_forwarding_closure(
                 TokenStream::new()
             };
 
+            let method_call = return_conversion.wrap_call(quote! {
+                #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+            });
+
             quote! {
                 // Identifiers need to share the span to avoid proc macro hygiene issues
                 // similar to https://github.com/godot-rust/gdext/pull/1397.
@@ -426,25 +450,93 @@ fn make_forwarding_closure(
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #method_call
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
-            //
+            let method_call =
+                return_conversion.wrap_call(quote! { #class_name::#method_name(#(#params),*) });
+
             // Identifiers need to share the span to avoid proc macro hygiene issues
             // similar to https://github.com/godot-rust/gdext/pull/1397.
             quote! {
                 |_, #param_ident| {
                     let #params_tuple = #param_ident;
-                    #class_name::#method_name(#(#params),*)
+                    #method_call
+                }
+            }
+        }
+    }
+}
+
+/// Conversion of the value returned by a user method, before it is passed to Godot.
+#[derive(Default)]
+struct ReturnConversion<'a> {
+    /// Strategy type implementing `ErrorMapping`, for `Result` return types.
+    error_mapping: Option<&'a TokenStream>,
+
+    /// For `async fn`: name of the function used in diagnostics, e.g. `"MyClass::load_level"`.
+    async_func_name: Option<String>,
+}
+
+impl ReturnConversion<'_> {
+    /// Type seen by Godot, given the return type of the user method.
+    fn godot_return_type(&self, return_type: &TokenStream) -> TokenStream {
+        if self.async_func_name.is_some() {
+            quote! { ::godot::builtin::Signal }
+        } else if let Some(mapping) = self.error_mapping {
+            quote! { ::godot::register::private::MappedReturn<#mapping, #return_type> }
+        } else {
+            return_type.clone()
+        }
+    }
+
+    /// Wraps the call expression of the user method.
+    fn wrap_call(&self, method_call: TokenStream) -> TokenStream {
+        let map_result = |output: TokenStream| match self.error_mapping {
+            Some(mapping) => quote! {
+                ::godot::register::private::map_func_result::<#mapping, _>(#output)
+            },
+            None => output,
+        };
+
+        match &self.async_func_name {
+            // The future is created outside the task, so it doesn't borrow the instance storage.
+            // Result mapping happens inside the task, once the output is available.
+            Some(func_name) => {
+                let output = map_result(quote! { __gdext_future.await });
+                quote! {
+                    {
+                        let __gdext_future = #method_call;
+                        ::godot::private::spawn_async_func(#func_name, async move { #output })
+                    }
                 }
             }
+            None => map_result(method_call),
         }
     }
 }
 
+/// Whether a return type is syntactically a `Result`, e.g. `Result<T, E>`, `std::result::Result<T, E>` or `io::Result<T>`.
+///
+/// Type aliases with other names are not recognized; those need an explicit `#[func(on_error = ...)]`.
+pub(crate) fn is_result_type(return_ty: &Option<venial::TypeExpr>) -> bool {
+    let Some(return_ty) = return_ty else {
+        return false;
+    };
+
+    // Last path segment before the generic arguments.
+    let last_before_generics = return_ty
+        .tokens
+        .iter()
+        .take_while(|tt| !matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '<'))
+        .last();
+
+    matches!(last_before_generics, Some(TokenTree::Ident(ident)) if ident == "Result")
+}
+
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
 fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
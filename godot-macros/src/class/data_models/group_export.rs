@@ -16,6 +16,23 @@ use proc_macro2::Literal;
 use crate::ParseResult;
 use crate::util::{KvParser, bail};
 
+/// Specifies a category which starts with a given field, like `@export_category` in GDScript.
+///
+/// Categories are displayed as headers in the Inspector and end any previous group or subgroup.
+pub struct FieldCategory {
+    pub(crate) name: Literal,
+}
+
+impl FieldCategory {
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let Some(name) = parser.handle_literal("name", "String")? else {
+            return bail!(parser.span(), "missing required argument: `name = \"...\".");
+        };
+
+        Ok(Self { name })
+    }
+}
+
 /// Specifies group or subgroup which starts with a given field.
 /// Group membership for properties in Godot is based on the order of their registration –
 /// i.e. given field belongs to group declared beforehand (for example with some previous field).
@@ -37,3 +54,54 @@ impl FieldGroup {
         Ok(Self { name, prefix })
     }
 }
+
+/// Tracks the group that is active while fields are declared, to validate nested subgroups.
+///
+/// Subgroups can be nested with the `/` separator (e.g. `"Movement/Air"`), which is only supported inside a group.
+#[derive(Default)]
+pub struct GroupTracker {
+    in_group: bool,
+}
+
+impl GroupTracker {
+    pub(crate) fn on_category(&mut self) {
+        self.in_group = false;
+    }
+
+    pub(crate) fn on_group(&mut self, group: &FieldGroup) {
+        self.in_group = !literal_value(&group.name).is_empty();
+    }
+
+    pub(crate) fn on_subgroup(&mut self, subgroup: &FieldGroup) -> ParseResult<()> {
+        let name = literal_value(&subgroup.name);
+        if !name.contains('/') {
+            return Ok(());
+        }
+
+        if name.split('/').any(str::is_empty) {
+            return bail!(
+                &subgroup.name,
+                "nested subgroup `{name}` must not contain empty path segments"
+            );
+        }
+
+        if !self.in_group {
+            return bail!(
+                &subgroup.name,
+                "nested subgroup `{name}` requires an enclosing #[export_group]"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Value of a string literal, without quotes. Escape sequences are kept as-is; they don't matter for validation.
+fn literal_value(literal: &Literal) -> String {
+    let repr = literal.to_string();
+
+    repr.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .map(str::to_string)
+        .unwrap_or(repr)
+}
@@ -59,6 +59,7 @@ struct FuncAttr {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    pub on_error: Option<TokenStream>,
 }
 
 #[derive(Default)]
@@ -73,6 +74,9 @@ pub(crate) struct InherentImplAttr {
 
     /// When typed signal generation is explicitly disabled by the user.
     pub no_typed_signals: bool,
+
+    /// Error mapping strategy for all `#[func]` methods in this block that return `Result`.
+    pub on_error: Option<TokenStream>,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -88,7 +92,12 @@ pub fn transform_inherent_impl(
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
+    let (funcs, signals) = process_godot_fns(
+        &class_name,
+        &mut impl_block,
+        meta.secondary,
+        meta.on_error.as_ref(),
+    )?;
     let consts = process_godot_constants(&mut impl_block)?;
 
     let inherent_impl_docs =
@@ -137,6 +146,16 @@ pub fn transform_inherent_impl(
     #[cfg(not(feature = "codegen-full"))]
     let rpc_registrations = TokenStream::new();
 
+    // Only primary impl, like RPC registrations.
+    #[cfg(feature = "codegen-full")]
+    let rpc_symbol_types = if meta.secondary {
+        TokenStream::new()
+    } else {
+        crate::class::make_rpc_collection(&class_name, &funcs)
+    };
+    #[cfg(not(feature = "codegen-full"))]
+    let rpc_symbol_types = TokenStream::new();
+
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def, None))
@@ -212,6 +231,7 @@ pub fn transform_inherent_impl(
             #fill_storage
             #class_registration
             #signal_symbol_types
+            #rpc_symbol_types
             #inherent_impl_docs
         };
 
@@ -252,6 +272,7 @@ fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
     is_secondary_impl: bool,
+    block_on_error: Option<&TokenStream>,
 ) -> ParseResult<(Vec<FuncDefinition>, Vec<SignalDefinition>)> {
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
@@ -267,9 +288,10 @@ fn process_godot_fns(
             continue;
         };
 
+        // `async` is only supported for #[func], validated below.
+        let is_async = function.qualifiers.tk_async.is_some();
         if function.qualifiers.tk_default.is_some()
             || function.qualifiers.tk_const.is_some()
-            || function.qualifiers.tk_async.is_some()
             || function.qualifiers.tk_unsafe.is_some()
             || function.qualifiers.tk_extern.is_some()
             || function.qualifiers.extern_abi.is_some()
@@ -298,6 +320,29 @@ fn process_godot_fns(
 
                 let external_attributes = function.attributes.clone();
 
+                // `Result` return types are mapped with the strategy from #[func(on_error)], #[godot_api(on_error)] or the default one.
+                // An explicit #[func(on_error)] also applies to return types not recognized as `Result`, e.g. type aliases.
+                let error_mapping = match func.on_error {
+                    Some(on_error) => Some(on_error),
+                    None if func::is_result_type(&function.return_ty) => Some(
+                        block_on_error
+                            .cloned()
+                            .unwrap_or_else(|| quote! { ::godot::register::ErrorToPanic }),
+                    ),
+                    None => None,
+                };
+
+                if func.is_virtual && error_mapping.is_some() {
+                    return bail!(
+                        &function.return_ty,
+                        "#[func(virtual)] does not support `Result` return types",
+                    );
+                }
+
+                if is_async {
+                    validate_async_func(function, func.is_virtual)?;
+                }
+
                 // Transforms the following.
                 //   from function:     #[attr] pub fn foo(&self, a: i32) -> i32 { ... }
                 //   into signature:    fn foo(&self, a: i32) -> i32
@@ -340,10 +385,18 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    error_mapping,
+                    is_async,
                 });
             }
 
             ItemAttrType::Signal(ref signal, ref _attr_val) => {
+                if is_async {
+                    return bail!(
+                        &function.qualifiers,
+                        "#[signal] does not support `async` functions"
+                    );
+                }
                 if is_secondary_impl {
                     return bail!(
                         function,
@@ -401,6 +454,26 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
+/// Async functions are spawned as tasks, whose futures must not borrow the instance.
+fn validate_async_func(function: &venial::Function, is_virtual: bool) -> ParseResult<()> {
+    if is_virtual {
+        return bail!(
+            &function.qualifiers,
+            "#[func(virtual)] does not support `async` functions"
+        );
+    }
+
+    if let Some((venial::FnParam::Receiver(recv), _)) = function.params.first() {
+        return bail!(
+            &recv.tk_self,
+            "async #[func] cannot borrow `self`, since the future outlives the call; \
+            use #[func(gd_self)] with a `Gd<Self>` parameter, or an associated function"
+        );
+    }
+
+    Ok(())
+}
+
 fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
     let mut constant_signatures = vec![];
 
@@ -621,12 +694,16 @@ fn parse_func_attr(attributes: &[venial::Attribute]) -> ParseResult<AttrParseRes
     // #[func(gd_self)]
     let has_gd_self = parser.handle_alone("gd_self")?;
 
+    // #[func(on_error = ErrorToNull)]
+    let on_error = parser.handle_expr("on_error")?;
+
     parser.finish()?;
 
     Ok(AttrParseResult::Func(FuncAttr {
         rename,
         is_virtual,
         has_gd_self,
+        on_error,
     }))
 }
 
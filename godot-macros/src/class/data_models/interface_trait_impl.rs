@@ -261,7 +261,7 @@ fn handle_register_class<'a>(
 
         #(#cfg_attrs)*
         impl ::godot::obj::cap::GodotRegisterClass for #class_name {
-            fn __godot_register_class(builder: &mut ::godot::builder::GodotBuilder<Self>) {
+            fn __godot_register_class(builder: &mut ::godot::register::ClassBuilder<Self>) {
                 <Self as #trait_path>::register_class(builder)
             }
         }
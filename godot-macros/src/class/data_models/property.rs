@@ -11,7 +11,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 use crate::class::data_models::fields::Fields;
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
 use crate::class::{Field, FieldVar, GetSet, GetterSetterImpl, UsageFlags};
 use crate::util::{format_funcs_collection_constant, format_funcs_collection_struct, ident};
 
@@ -52,6 +52,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             ty: field_type,
             var,
             export,
+            category,
             group,
             subgroup,
             ..
@@ -61,7 +62,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         let var = match (export, var) {
             (Some(export), None) => {
                 let usage_flags = if let Some(usage) = export.to_export_usage() {
-                    UsageFlags::Custom(vec![usage])
+                    UsageFlags::Custom(usage)
                 } else {
                     UsageFlags::InferredExport
                 };
@@ -75,7 +76,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             _ => continue,
         };
 
-        make_groups_registrations(group, subgroup, &mut export_tokens, class_name);
+        make_groups_registrations(category, group, subgroup, &mut export_tokens, class_name);
         let FieldVar {
             rename,
             getter,
@@ -254,15 +255,22 @@ fn make_accessor_func_constant(
     quote! { #funcs_collection::#constant }
 }
 
-/// Generates registrations for declared group and subgroup and pushes them to export tokens.
+/// Generates registrations for declared category, group and subgroup and pushes them to export tokens.
 ///
-/// Groups must be registered before subgroups (otherwise the ordering is broken).
+/// Categories must be registered before groups, and groups before subgroups (otherwise the ordering is broken).
 fn make_groups_registrations(
+    category: &Option<FieldCategory>,
     group: &Option<FieldGroup>,
     subgroup: &Option<FieldGroup>,
     export_tokens: &mut Vec<TokenStream>,
     class_name: &Ident,
 ) {
+    if let Some(FieldCategory { name }) = category {
+        export_tokens.push(quote! {
+            ::godot::register::private::register_category::<#class_name>(#name);
+        });
+    }
+
     export_tokens.push(make_group_registration(
         group,
         ident("register_group"),
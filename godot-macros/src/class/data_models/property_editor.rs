@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generates the Inspector integration for `#[export(editor = Type)]`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::class::Field;
+use crate::class::data_models::fields::Fields;
+
+/// Generates an editor plugin, inspector plugin and `EditorProperty` host, which route each property with a custom editor to that
+/// editor's `PropertyEditor` impl.
+///
+/// Returns an empty token stream if no field uses `#[export(editor)]`.
+pub fn make_property_editors(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let (property_names, editor_tys): (Vec<String>, Vec<&TokenStream>) = fields
+        .all_fields
+        .iter()
+        .filter_map(|field| {
+            let editor = field.export.as_ref()?.editor.as_ref()?;
+            Some((property_name(field), editor))
+        })
+        .unzip();
+
+    if editor_tys.is_empty() {
+        return TokenStream::new();
+    }
+
+    let host = format_ident!("__godot_{class_name}_PropertyHost");
+    let inspector = format_ident!("__godot_{class_name}_InspectorPlugin");
+    let plugin = format_ident!("__godot_{class_name}_EditorPlugin");
+    let prv = quote! { ::godot::private };
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, internal, base=EditorProperty)]
+        struct #host {
+            base: ::godot::obj::Base<::godot::classes::EditorProperty>,
+            editor: Option<#prv::ErasedPropertyEditor>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IEditorProperty for #host {
+            fn update_property(&mut self) {
+                let host = ::godot::obj::WithBaseField::to_gd(self).upcast();
+
+                if let Some(editor) = self.editor.as_mut() {
+                    editor.update(host);
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, internal, base=EditorInspectorPlugin)]
+        struct #inspector {
+            base: ::godot::obj::Base<::godot::classes::EditorInspectorPlugin>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IEditorInspectorPlugin for #inspector {
+            fn can_handle(&self, object: Option<::godot::obj::Gd<::godot::classes::Object>>) -> bool {
+                let class_id = <#class_name as ::godot::obj::GodotClass>::class_id();
+
+                // Also matches GDScript classes extending this one.
+                object.is_some_and(|object| object.is_class(&class_id.to_gstring()))
+            }
+
+            fn parse_property(
+                &mut self,
+                _object: Option<::godot::obj::Gd<::godot::classes::Object>>,
+                _type: ::godot::builtin::VariantType,
+                name: ::godot::builtin::GString,
+                _hint_type: ::godot::global::PropertyHint,
+                _hint_string: ::godot::builtin::GString,
+                _usage_flags: ::godot::global::PropertyUsageFlags,
+                _wide: bool,
+            ) -> bool {
+                let make_editor: fn(::godot::obj::Gd<::godot::classes::EditorProperty>) -> #prv::ErasedPropertyEditor =
+                    match name.to_string().as_str() {
+                        #(
+                            #property_names => #prv::ErasedPropertyEditor::new::<#editor_tys>,
+                        )*
+                        _ => return false,
+                    };
+
+                let mut host = <#host as ::godot::obj::NewAlloc>::new_alloc();
+                let editor = make_editor(host.clone().upcast());
+                host.bind_mut().editor = Some(editor);
+
+                // Replaces Godot's default editor for this property.
+                self.base_mut().add_property_editor(&name, &host);
+                true
+            }
+        }
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, internal, base=EditorPlugin)]
+        struct #plugin {
+            base: ::godot::obj::Base<::godot::classes::EditorPlugin>,
+            inspector: Option<::godot::obj::Gd<#inspector>>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IEditorPlugin for #plugin {
+            fn enter_tree(&mut self) {
+                let inspector = <#inspector as ::godot::obj::NewGd>::new_gd();
+                self.base_mut().add_inspector_plugin(&inspector);
+                self.inspector = Some(inspector);
+            }
+
+            fn exit_tree(&mut self) {
+                if let Some(inspector) = self.inspector.take() {
+                    self.base_mut().remove_inspector_plugin(&inspector);
+                }
+            }
+        }
+    }
+}
+
+/// Name under which the field is registered as a property, taking `#[var(rename)]` into account.
+fn property_name(field: &Field) -> String {
+    field
+        .var
+        .as_ref()
+        .and_then(|var| var.rename.as_ref())
+        .unwrap_or(&field.name)
+        .to_string()
+}
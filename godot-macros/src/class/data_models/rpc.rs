@@ -6,9 +6,10 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::class::FuncDefinition;
+use crate::util;
 
 /// Possible ways the user can specify RPC configuration.
 pub enum RpcAttr {
@@ -161,3 +162,149 @@ fn make_rpc_registration(func_def: &FuncDefinition) -> Option<TokenStream> {
 
     Some(registration)
 }
+
+/// Generates the `rpcs()` accessor, the RPC collection struct and one individual struct per `#[rpc]` method.
+///
+/// Like `make_rpc_registrations_fn()`, this generates nothing if the class declares no RPCs, since it requires `WithBaseField` and
+/// `Inherits<Node>`.
+pub fn make_rpc_collection(class_name: &Ident, funcs: &[FuncDefinition]) -> TokenStream {
+    let rpc_funcs: Vec<&FuncDefinition> = funcs
+        .iter()
+        .filter(|func| func.rpc_info.is_some())
+        .collect();
+
+    if rpc_funcs.is_empty() {
+        return TokenStream::new();
+    }
+
+    let collection_struct_name =
+        format_ident!("__godot_Rpcs_{class_name}", span = class_name.span());
+    let visibility_macro = util::format_class_visibility_macro(class_name);
+
+    let mut provider_methods = vec![];
+    let mut individual_structs = vec![];
+    for func in rpc_funcs {
+        let (provider_method, individual_struct) = make_rpc_individual(class_name, func);
+        provider_methods.push(provider_method);
+        individual_structs.push(individual_struct);
+    }
+
+    quote! {
+        #visibility_macro! {
+            #[allow(non_camel_case_types)]
+            #[doc(hidden)] // Only on struct, not methods, to allow completion in IDEs.
+            struct #collection_struct_name {
+                #[doc(hidden)]
+                __node: ::godot::obj::Gd<::godot::classes::Node>,
+            }
+        }
+
+        impl #collection_struct_name {
+            #( #provider_methods )*
+        }
+
+        impl #class_name {
+            /// Type-safe access to the remote procedure calls declared with `#[rpc]`.
+            ///
+            /// See [`TypedRpc`](::godot::register::TypedRpc) for details.
+            pub fn rpcs(&self) -> #collection_struct_name {
+                // Use fully-qualified syntax, so that error message isn't just "no method named `to_gd` found".
+                let gd = ::godot::obj::WithBaseField::to_gd(self);
+
+                #collection_struct_name {
+                    __node: gd.upcast::<::godot::classes::Node>(),
+                }
+            }
+        }
+
+        #( #individual_structs )*
+    }
+}
+
+/// Returns the accessor method inside the collection, and the individual struct with `rpc()` + `rpc_id()` methods.
+fn make_rpc_individual(
+    class_name: &Ident,
+    func_def: &FuncDefinition,
+) -> (TokenStream, TokenStream) {
+    let rust_name = func_def.rust_ident();
+    let godot_name = func_def.godot_name();
+    let param_names = &func_def.signature_info.param_idents;
+    let param_types = &func_def.signature_info.param_types;
+    let cfg_attrs: Vec<_> = util::extract_cfg_attrs(&func_def.external_attributes)
+        .into_iter()
+        .collect();
+    let doc_attrs: Vec<_> = util::extract_doc_attrs(&func_def.external_attributes)
+        .into_iter()
+        .collect();
+
+    let individual_struct_name = format_ident!(
+        "__godot_Rpc_{class_name}_{rust_name}",
+        span = rust_name.span()
+    );
+    let param_tuple = quote! { ( #( #param_types, )* ) };
+    let visibility_macro = util::format_class_visibility_macro(class_name);
+
+    let provider_method = quote! {
+        #(#cfg_attrs)*
+        #(#doc_attrs)*
+        pub fn #rust_name(&self) -> #individual_struct_name {
+            #individual_struct_name {
+                __typed: ::godot::register::TypedRpc::extract(self.__node.clone(), #godot_name),
+            }
+        }
+    };
+
+    let individual_struct = quote! {
+        #visibility_macro! {
+            #(#cfg_attrs)*
+            #[allow(non_camel_case_types)]
+            #[doc(hidden)] // RPC struct is hidden, but the method returning it is not (IDE completion).
+            struct #individual_struct_name {
+                #[doc(hidden)]
+                __typed: ::godot::register::TypedRpc<#param_tuple>,
+            }
+        }
+
+        // Concrete convenience API is macro-based; the rest is delegated to TypedRpc via Deref.
+        #(#cfg_attrs)*
+        impl #individual_struct_name {
+            /// Sends the RPC to all peers, see [`Node::rpc()`](::godot::classes::Node::rpc).
+            pub fn rpc(
+                &self,
+                #( #param_names: impl ::godot::meta::AsArg<#param_types>, )*
+            ) -> ::std::result::Result<(), ::godot::register::RpcError> {
+                use ::godot::meta::AsArg;
+                #(
+                    ::godot::meta::arg_into_owned!(infer #param_names);
+                )*
+
+                self.__typed.rpc_tuple((#( #param_names, )*))
+            }
+
+            /// Sends the RPC to the peer `peer_id`, see [`Node::rpc_id()`](::godot::classes::Node::rpc_id).
+            pub fn rpc_id(
+                &self,
+                peer_id: i64,
+                #( #param_names: impl ::godot::meta::AsArg<#param_types>, )*
+            ) -> ::std::result::Result<(), ::godot::register::RpcError> {
+                use ::godot::meta::AsArg;
+                #(
+                    ::godot::meta::arg_into_owned!(infer #param_names);
+                )*
+
+                self.__typed.rpc_id_tuple(peer_id, (#( #param_names, )*))
+            }
+        }
+
+        #(#cfg_attrs)*
+        impl ::std::ops::Deref for #individual_struct_name {
+            type Target = ::godot::register::TypedRpc<#param_tuple>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.__typed
+            }
+        }
+    };
+
+    (provider_method, individual_struct)
+}
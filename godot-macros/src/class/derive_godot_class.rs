@@ -10,7 +10,7 @@ use quote::{format_ident, quote, quote_spanned};
 use venial::Error;
 
 use crate::class::data_models::fields::{Fields, named_fields};
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup, GroupTracker};
 use crate::class::{
     BeforeKind, Field, FieldCond, FieldDefault, FieldExport, FieldVar, GetterSetter, SignatureInfo,
     make_property_impl, make_virtual_callback,
@@ -97,6 +97,11 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
 
     let godot_exports_impl = make_property_impl(class_name, &fields);
 
+    #[cfg(feature = "codegen-full")]
+    let property_editors = crate::class::make_property_editors(class_name, &fields);
+    #[cfg(not(feature = "codegen-full"))]
+    let property_editors = TokenStream::new();
+
     let godot_withbase_impl = make_with_base_impl(&fields.base_field, class_name);
 
     let (user_singleton_impl, singleton_init_level_const) = if struct_cfg.is_singleton {
@@ -201,6 +206,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_init_impl
         #godot_withbase_impl
         #godot_exports_impl
+        #property_editors
         #user_class_impl
         #init_expecter
         #visibility_macro
@@ -698,6 +704,7 @@ fn parse_fields(
     let mut deprecations = vec![];
     let mut errors = vec![];
     let mut has_tool_button = false;
+    let mut group_tracker = GroupTracker::default();
 
     // Attributes on struct fields
     for (named_field, _punct) in named_fields {
@@ -790,9 +797,18 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_category(name = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_category")? {
+            let category = FieldCategory::new_from_kv(&mut parser)?;
+            group_tracker.on_category();
+            field.category = Some(category);
+            parser.finish()?;
+        }
+
         // #[export_group(name = ..., prefix = ...)]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
             let group = FieldGroup::new_from_kv(&mut parser)?;
+            group_tracker.on_group(&group);
             field.group = Some(group);
             parser.finish()?;
         }
@@ -800,6 +816,7 @@ fn parse_fields(
         // #[export_subgroup(name = ..., prefix = ...)]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_subgroup")? {
             let subgroup = FieldGroup::new_from_kv(&mut parser)?;
+            group_tracker.on_subgroup(&subgroup)?;
             field.subgroup = Some(subgroup);
             parser.finish()?;
         }
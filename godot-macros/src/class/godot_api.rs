@@ -17,6 +17,7 @@ fn parse_inherent_impl_attr(meta: TokenStream) -> Result<super::InherentImplAttr
     let mut attr = KvParser::parse_required(item.attributes(), "godot_api", &meta)?;
     let secondary = attr.handle_alone("secondary")?;
     let no_typed_signals = attr.handle_alone("no_typed_signals")?;
+    let on_error = attr.handle_expr("on_error")?;
     attr.finish()?;
 
     if no_typed_signals && secondary {
@@ -29,6 +30,7 @@ fn parse_inherent_impl_attr(meta: TokenStream) -> Result<super::InherentImplAttr
     Ok(super::InherentImplAttr {
         secondary,
         no_typed_signals,
+        on_error,
     })
 }
 
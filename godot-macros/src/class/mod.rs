@@ -20,6 +20,8 @@ mod data_models {
     pub mod inherent_impl;
     pub mod interface_trait_impl;
     pub mod property;
+    #[cfg(feature = "codegen-full")]
+    pub mod property_editor;
     #[cfg_attr(not(feature = "codegen-full"), allow(dead_code))]
     pub mod rpc;
     pub mod signal;
@@ -33,6 +35,8 @@ pub(crate) use data_models::func::*;
 pub(crate) use data_models::inherent_impl::*;
 pub(crate) use data_models::interface_trait_impl::*;
 pub(crate) use data_models::property::*;
+#[cfg(feature = "codegen-full")]
+pub(crate) use data_models::property_editor::*;
 pub(crate) use data_models::rpc::*;
 pub(crate) use data_models::signal::*;
 pub(crate) use derive_godot_class::*;
@@ -150,7 +150,7 @@ impl CStyleEnumerator {
             _ => {
                 return bail!(
                     &enum_variant.fields,
-                    "GodotConvert only supports C-style enums"
+                    "#[godot(via = GString)] and integer via types only support C-style enums; use #[godot(via = Dictionary)] or #[godot(via = Array)] for enums carrying data"
                 );
             }
         }
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use super::dictionary_struct::DictionaryField;
+use crate::ParseResult;
+use crate::util::{KvParser, bail, ident};
+
+/// Stores info from enums whose enumerators may carry data, converted to a tagged `Dictionary` or `Array`.
+pub struct DataEnum {
+    pub enumerators: Vec<DataEnumerator>,
+    pub repr: DataEnumRepr,
+}
+
+/// How a data-carrying enum is represented in Godot.
+pub enum DataEnumRepr {
+    /// `#[godot(via = Dictionary)]`: `{ tag_key: "Enumerator", field: value, ... }`.
+    Dictionary { tag_key: Literal },
+
+    /// `#[godot(via = Array)]`: `["Enumerator", value, ...]`.
+    Array,
+}
+
+impl DataEnum {
+    pub fn parse_enum(enum_: &venial::Enum, repr: DataEnumRepr) -> ParseResult<Self> {
+        let enumerators = enum_
+            .variants
+            .items()
+            .map(DataEnumerator::parse_enumerator)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        if let DataEnumRepr::Dictionary { tag_key } = &repr {
+            let tag_key_str = tag_key.to_string();
+
+            for enumerator in enumerators.iter() {
+                for field in enumerator.fields.iter() {
+                    if field.is_serialized() && field.key.to_string() == tag_key_str {
+                        return bail!(
+                            &field.member,
+                            "field key {tag_key_str} collides with the enum tag key; use #[godot(rename = ...)] on the field or #[godot(tag = ...)] on the enum"
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(Self { enumerators, repr })
+    }
+
+    /// Comma-separated list of all tags, for error messages.
+    pub fn tag_list(&self) -> String {
+        self.enumerators
+            .iter()
+            .map(|e| e.tag.to_string().trim_matches('"').to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Each enumerator in a data-carrying enum.
+pub struct DataEnumerator {
+    /// The name of the enumerator.
+    pub name: Ident,
+
+    /// String literal identifying the enumerator in Godot. Defaults to the name; can be overridden with `#[godot(rename = "tag")]`.
+    pub tag: Literal,
+
+    /// Whether this is a unit enumerator (no braces or parentheses).
+    pub is_unit: bool,
+
+    /// Named or tuple fields; tuple fields use their index as dictionary key.
+    pub fields: Vec<DictionaryField>,
+}
+
+impl DataEnumerator {
+    fn parse_enumerator(enumerator: &venial::EnumVariant) -> ParseResult<Self> {
+        let mut tag = Literal::string(&enumerator.name.to_string());
+
+        if let Some(mut parser) = KvParser::parse(&enumerator.attributes, "godot")? {
+            if let Some(rename) = parser.handle_literal("rename", "string")? {
+                if !rename.to_string().starts_with('"') {
+                    return bail!(rename, "`rename` expects a string literal");
+                }
+
+                tag = rename;
+            }
+
+            parser.finish()?;
+        }
+
+        Ok(Self {
+            name: enumerator.name.clone(),
+            tag,
+            is_unit: matches!(enumerator.fields, venial::Fields::Unit),
+            fields: DictionaryField::parse_fields(&enumerator.fields)?,
+        })
+    }
+
+    /// Pattern matching this enumerator, binding each serialized field to a local variable.
+    ///
+    /// Returns the pattern and, for each serialized field, the field and its local variable.
+    pub fn make_pattern(&self) -> (TokenStream, Vec<(&DictionaryField, Ident)>) {
+        let name = &self.name;
+
+        let bindings: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|field| field.is_serialized())
+            .enumerate()
+            .map(|(i, field)| (field, ident(&format!("__field_{i}"))))
+            .collect();
+
+        let pattern = if self.is_unit {
+            quote! { Self::#name }
+        } else {
+            let members = bindings.iter().map(|(field, _)| &field.member);
+            let locals = bindings.iter().map(|(_, local)| local);
+
+            quote! { Self::#name { #( #members: #locals, )* .. } }
+        };
+
+        (pattern, bindings)
+    }
+
+    /// Expression constructing this enumerator, with `field_exprs` in the same order as `self.fields`.
+    pub fn make_constructor(&self, field_exprs: Vec<TokenStream>) -> TokenStream {
+        let name = &self.name;
+
+        if self.is_unit {
+            quote! { Self::#name }
+        } else {
+            let members = self.fields.iter().map(|field| &field.member);
+
+            quote! { Self::#name { #( #members: #field_exprs, )* } }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{ToTokens, quote};
+
+use crate::ParseResult;
+use crate::util::{KvParser, bail};
+
+/// Stores info from a struct with named fields, which is converted to a `Dictionary` with one key per field.
+pub struct DictionaryStruct {
+    pub fields: Vec<DictionaryField>,
+}
+
+impl DictionaryStruct {
+    /// Parses a struct with named fields, including per-field `#[godot(...)]` attributes.
+    pub fn parse_struct(struct_: &venial::Struct) -> ParseResult<Self> {
+        let venial::Fields::Named(_) = &struct_.fields else {
+            return bail!(
+                &struct_.fields,
+                "#[godot(via = Dictionary)] requires a struct with named fields"
+            );
+        };
+
+        let fields = DictionaryField::parse_fields(&struct_.fields)?;
+
+        Ok(Self { fields })
+    }
+}
+
+/// A single field, mapped to one dictionary key.
+pub struct DictionaryField {
+    /// The field name in Rust, or its index for tuple fields. Usable in `value.#member` and `Type { #member: value }`.
+    pub member: TokenStream,
+
+    /// The type of the field.
+    pub ty: venial::TypeExpr,
+
+    /// The dictionary key, as a string literal. Defaults to the field name (or index); can be overridden with `#[godot(rename = "key")]`.
+    pub key: Literal,
+
+    /// How the field behaves if its key is absent.
+    pub mode: FieldMode,
+}
+
+/// Behavior of a field during conversion.
+pub enum FieldMode {
+    /// The key must be present, otherwise conversion fails.
+    Required,
+
+    /// `#[godot(default)]` or `#[godot(default = expr)]`: if the key is absent, the field is initialized with `Default::default()` or `expr`.
+    Default { expr: Option<TokenStream> },
+
+    /// `#[godot(skip)]`: the field is never written to the dictionary and always initialized like a `default` field.
+    Skip { expr: Option<TokenStream> },
+}
+
+impl DictionaryField {
+    /// Parses named or tuple fields. Unit structs and enumerators have no fields.
+    pub fn parse_fields(fields: &venial::Fields) -> ParseResult<Vec<Self>> {
+        match fields {
+            venial::Fields::Unit => Ok(vec![]),
+            venial::Fields::Tuple(fields) => fields
+                .fields
+                .items()
+                .enumerate()
+                .map(|(index, field)| {
+                    let member = Literal::usize_unsuffixed(index);
+                    Self::parse_field(
+                        member.to_token_stream(),
+                        index.to_string(),
+                        &field.attributes,
+                        &field.ty,
+                    )
+                })
+                .collect(),
+            venial::Fields::Named(fields) => fields
+                .fields
+                .items()
+                .map(|field| {
+                    let field_name = field.name.to_string();
+                    Self::parse_field(
+                        field.name.to_token_stream(),
+                        field_name.trim_start_matches("r#").to_string(),
+                        &field.attributes,
+                        &field.ty,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn parse_field(
+        member: TokenStream,
+        default_key: String,
+        attributes: &[venial::Attribute],
+        ty: &venial::TypeExpr,
+    ) -> ParseResult<Self> {
+        let mut key = Literal::string(&default_key);
+        let mut mode = FieldMode::Required;
+
+        if let Some(mut parser) = KvParser::parse(attributes, "godot")? {
+            let rename = parser.handle_literal("rename", "string")?;
+            let skip = parser.handle_alone_with_span("skip")?;
+
+            let default_expr = match parser.handle_any("default") {
+                None => None,
+                Some(None) => Some(None),
+                Some(Some(value)) => Some(Some(value.expr()?)),
+            };
+
+            parser.finish()?;
+
+            if let Some(rename) = rename {
+                if !rename.to_string().starts_with('"') {
+                    return bail!(rename, "`rename` expects a string literal");
+                }
+
+                if let Some(skip) = &skip {
+                    return bail!(skip, "`skip` and `rename` cannot be combined");
+                }
+
+                key = rename;
+            }
+
+            mode = match (skip, default_expr) {
+                (Some(_), expr) => FieldMode::Skip {
+                    expr: expr.flatten(),
+                },
+                (None, Some(expr)) => FieldMode::Default { expr },
+                (None, None) => FieldMode::Required,
+            };
+        }
+
+        Ok(Self {
+            member,
+            ty: ty.clone(),
+            key,
+            mode,
+        })
+    }
+
+    /// Whether the field is written to the dictionary in `to_godot()`.
+    pub fn is_serialized(&self) -> bool {
+        !matches!(self.mode, FieldMode::Skip { .. })
+    }
+
+    /// Expression that reads this field from the dictionary variable `dict`, for use in `try_from_godot()`.
+    ///
+    /// Returns early with a `ConvertError` if the key is missing or has the wrong type.
+    pub fn make_from_dict_expr(&self, dict: &Ident) -> TokenStream {
+        let DictionaryField { ty, key, .. } = self;
+
+        self.make_from_expr(
+            quote! { ::godot::private::convert_dict_field::<#ty>(&#dict, #key)? },
+            quote! { ::godot::private::convert_missing_key(&#dict, #key) },
+        )
+    }
+
+    /// Expression that reads this field from the array variable `array` at position `index`, for use in `try_from_godot()`.
+    ///
+    /// Returns early with a `ConvertError` if the array is too short or the element has the wrong type.
+    pub fn make_from_array_expr(&self, array: &Ident, index: usize) -> TokenStream {
+        let ty = &self.ty;
+
+        self.make_from_expr(
+            quote! { ::godot::private::convert_array_element::<#ty>(&#array, #index)? },
+            quote! { ::godot::private::convert_missing_element(&#array, #index) },
+        )
+    }
+
+    /// `converted` evaluates to `Option<T>`, `missing_error` to the `ConvertError` returned if it is `None` and the field is required.
+    fn make_from_expr(&self, converted: TokenStream, missing_error: TokenStream) -> TokenStream {
+        match &self.mode {
+            FieldMode::Required => quote! {
+                match #converted {
+                    Some(value) => value,
+                    None => return Err(#missing_error),
+                }
+            },
+            FieldMode::Default { expr: None } => quote! {
+                #converted.unwrap_or_default()
+            },
+            FieldMode::Default { expr: Some(expr) } => quote! {
+                #converted.unwrap_or_else(|| #expr)
+            },
+            FieldMode::Skip { expr: None } => quote! {
+                ::std::default::Default::default()
+            },
+            FieldMode::Skip { expr: Some(expr) } => quote! { #expr },
+        }
+    }
+}
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
 use crate::ParseResult;
@@ -17,6 +17,10 @@ pub enum GodotAttribute {
     Transparent { span: Span },
     /// `#[godot(via = via_type)]`
     Via { span: Span, via_type: ViaType },
+    /// `#[godot(via = Dictionary)]` or `#[godot(via = VarDictionary)]`, for enums optionally with `tag = "key"`.
+    ViaDictionary { span: Span, tag: Option<Literal> },
+    /// `#[godot(via = Array)]` or `#[godot(via = VarArray)]`
+    ViaArray { span: Span },
 }
 
 impl GodotAttribute {
@@ -36,6 +40,21 @@ impl GodotAttribute {
         }
 
         if let Some(via_type) = parser.handle_ident("via")? {
+            if via_type == "Dictionary" || via_type == "VarDictionary" {
+                let tag = parser.handle_literal("tag", "string")?;
+                if let Some(tag) = &tag {
+                    if !tag.to_string().starts_with('"') {
+                        return bail!(tag, "`tag` expects a string literal");
+                    }
+                }
+
+                return Ok(Self::ViaDictionary { span, tag });
+            }
+
+            if via_type == "Array" || via_type == "VarArray" {
+                return Ok(Self::ViaArray { span });
+            }
+
             return Ok(Self::Via {
                 span,
                 via_type: ViaType::parse_ident(via_type)?,
@@ -55,6 +74,8 @@ impl GodotAttribute {
         match self {
             GodotAttribute::Transparent { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
+            GodotAttribute::ViaDictionary { span, .. } => *span,
+            GodotAttribute::ViaArray { span } => *span,
         }
     }
 }
@@ -79,7 +100,7 @@ impl ViaType {
             other => {
                 return bail!(
                     ident,
-                    "Via type `{other}` is not supported, expected one of: GString, i8, i16, i32, i64, u8, u16, u32"
+                    "Via type `{other}` is not supported, expected one of: GString, i8, i16, i32, i64, u8, u16, u32, Dictionary, Array"
                 );
             }
         };
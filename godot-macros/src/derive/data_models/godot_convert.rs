@@ -5,10 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{ToTokens, quote};
 
 use super::c_style_enum::CStyleEnum;
+use super::data_enum::{DataEnum, DataEnumRepr};
+use super::dictionary_struct::DictionaryStruct;
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
 use crate::ParseResult;
@@ -72,8 +74,12 @@ impl GodotConvert {
 pub enum ConvertType {
     /// Deriving for a newtype struct.
     NewType { field: NewtypeStruct },
-    /// Deriving for an enum.
+    /// Deriving for a struct with named fields, converted to a `Dictionary`.
+    Struct { fields: DictionaryStruct },
+    /// Deriving for a C-style enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for an enum whose enumerators may carry data, converted to a tagged `Dictionary` or `Array`.
+    DataEnum { enum_: DataEnum },
 }
 
 impl ConvertType {
@@ -81,31 +87,42 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
         match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "#[derive(GodotConvert)] on structs currently only works with #[godot(transparent)]"
-                    );
-                };
-
-                Ok(Self::NewType {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
-            venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
-                    );
-                };
-
-                Ok(Self::Enum {
+                }),
+                GodotAttribute::ViaDictionary { tag: None, .. } => Ok(Self::Struct {
+                    fields: DictionaryStruct::parse_struct(struct_)?,
+                }),
+                GodotAttribute::ViaDictionary { tag: Some(tag), .. } => {
+                    bail!(tag, "`tag` is only supported for enums")
+                }
+                GodotAttribute::Via { .. } | GodotAttribute::ViaArray { .. } => bail!(
+                    attribute.span(),
+                    "#[derive(GodotConvert)] on structs requires #[godot(transparent)] or #[godot(via = Dictionary)]"
+                ),
+            },
+            venial::Item::Enum(enum_) => match attribute {
+                GodotAttribute::Via { via_type, .. } => Ok(Self::Enum {
                     variants: CStyleEnum::parse_enum(enum_)?,
                     via: via_type,
-                })
-            }
+                }),
+                GodotAttribute::ViaDictionary { tag, .. } => {
+                    let tag_key = tag.unwrap_or_else(|| Literal::string("type"));
+                    let repr = DataEnumRepr::Dictionary { tag_key };
+
+                    Ok(Self::DataEnum {
+                        enum_: DataEnum::parse_enum(enum_, repr)?,
+                    })
+                }
+                GodotAttribute::ViaArray { .. } => Ok(Self::DataEnum {
+                    enum_: DataEnum::parse_enum(enum_, DataEnumRepr::Array)?,
+                }),
+                GodotAttribute::Transparent { .. } => bail!(
+                    attribute.span(),
+                    "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
+                ),
+            },
             _ => unreachable!(), // already checked outside.
         }
     }
@@ -114,7 +131,12 @@ impl ConvertType {
     pub fn via_type(&self) -> TokenStream {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
+            ConvertType::Struct { .. } => quote! { ::godot::builtin::VarDictionary },
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::DataEnum { enum_ } => match enum_.repr {
+                DataEnumRepr::Dictionary { .. } => quote! { ::godot::builtin::VarDictionary },
+                DataEnumRepr::Array => quote! { ::godot::builtin::VarArray },
+            },
         }
     }
 }
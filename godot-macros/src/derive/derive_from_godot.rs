@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DataEnum, DataEnumRepr, DictionaryStruct, GodotConvert, NewtypeStruct,
+    ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 use crate::util;
 
@@ -24,6 +27,10 @@ pub fn make_fromgodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) -
     match data {
         ConvertType::NewType { field } => make_fromgodot_for_newtype_struct(name, field),
 
+        ConvertType::Struct { fields } => make_fromgodot_for_dictionary_struct(name, fields),
+
+        ConvertType::DataEnum { enum_ } => make_fromgodot_for_data_enum(name, enum_),
+
         ConvertType::Enum {
             variants,
             via: ViaType::GString { .. },
@@ -52,6 +59,99 @@ fn make_fromgodot_for_newtype_struct(name: &Ident, field: &NewtypeStruct) -> Tok
     }
 }
 
+/// Derives `FromGodot` for structs with named fields, converted from a dictionary.
+///
+/// Keys not corresponding to any field are ignored.
+fn make_fromgodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let dict = util::ident("via");
+    let members = struct_.fields.iter().map(|field| &field.member);
+    let field_exprs = struct_
+        .fields
+        .iter()
+        .map(|field| field.make_from_dict_expr(&dict));
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(#dict: ::godot::builtin::VarDictionary) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                Ok(Self {
+                    #(
+                        #members: #field_exprs,
+                    )*
+                })
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums carrying data, converted from a tagged dictionary or array.
+fn make_fromgodot_for_data_enum(name: &Ident, enum_: &DataEnum) -> TokenStream {
+    let via = util::ident("via");
+    let bad_variant_error = format!(
+        "invalid {name} variant tag, expected one of: {}",
+        enum_.tag_list()
+    );
+
+    let (via_type, read_tag) = match &enum_.repr {
+        DataEnumRepr::Dictionary { tag_key } => (
+            quote! { ::godot::builtin::VarDictionary },
+            quote! {
+                match ::godot::private::convert_dict_field::<::godot::builtin::GString>(&#via, #tag_key)? {
+                    Some(tag) => tag,
+                    None => return Err(::godot::private::convert_missing_key(&#via, #tag_key)),
+                }
+            },
+        ),
+        DataEnumRepr::Array => (
+            quote! { ::godot::builtin::VarArray },
+            quote! {
+                match ::godot::private::convert_array_element::<::godot::builtin::GString>(&#via, 0)? {
+                    Some(tag) => tag,
+                    None => return Err(::godot::private::convert_missing_element(&#via, 0)),
+                }
+            },
+        ),
+    };
+
+    let arms = enum_.enumerators.iter().map(|enumerator| {
+        let tag = &enumerator.tag;
+
+        // Array elements are numbered after the tag, skipping fields that are not serialized.
+        let mut index = 0;
+        let field_exprs = enumerator
+            .fields
+            .iter()
+            .map(|field| match &enum_.repr {
+                DataEnumRepr::Dictionary { .. } => field.make_from_dict_expr(&via),
+                DataEnumRepr::Array => {
+                    if field.is_serialized() {
+                        index += 1;
+                    }
+                    field.make_from_array_expr(&via, index)
+                }
+            })
+            .collect();
+
+        let constructor = enumerator.make_constructor(field_exprs);
+
+        quote! {
+            #tag => Ok(#constructor),
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(#via: #via_type) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                let tag: ::godot::builtin::GString = #read_tag;
+
+                match tag.to_string().as_str() {
+                    #( #arms )*
+                    _ => Err(::godot::meta::error::ConvertError::with_error_value(#bad_variant_error, tag)),
+                }
+            }
+        }
+    }
+}
+
 /// Derives `FromGodot` for enums with a via type of integers.
 fn make_fromgodot_for_int_enum(
     name: &Ident,
@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DataEnum, DataEnumRepr, DictionaryStruct, GodotConvert, NewtypeStruct,
+    ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
@@ -23,6 +26,10 @@ pub fn make_togodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) ->
     match data {
         ConvertType::NewType { field } => make_togodot_for_newtype_struct(name, field),
 
+        ConvertType::Struct { fields } => make_togodot_for_dictionary_struct(name, fields),
+
+        ConvertType::DataEnum { enum_ } => make_togodot_for_data_enum(name, enum_),
+
         ConvertType::Enum {
             variants,
             via: ViaType::GString { .. },
@@ -51,6 +58,77 @@ fn make_togodot_for_newtype_struct(name: &Ident, field: &NewtypeStruct) -> Token
     }
 }
 
+/// Derives `ToGodot` for structs with named fields, converted to a dictionary.
+fn make_togodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let (keys, members): (Vec<_>, Vec<_>) = struct_
+        .fields
+        .iter()
+        .filter(|field| field.is_serialized())
+        .map(|field| (&field.key, &field.member))
+        .unzip();
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                let mut dict = ::godot::builtin::VarDictionary::new();
+                #(
+                    dict.set(#keys, &::godot::meta::ToGodot::to_variant(&self.#members));
+                )*
+                dict
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums carrying data, converted to a tagged dictionary or array.
+fn make_togodot_for_data_enum(name: &Ident, enum_: &DataEnum) -> TokenStream {
+    let arms = enum_.enumerators.iter().map(|enumerator| {
+        let (pattern, bindings) = enumerator.make_pattern();
+        let tag = &enumerator.tag;
+
+        let stores = bindings.iter().map(|(field, local)| {
+            let value = quote! { &::godot::meta::ToGodot::to_variant(#local) };
+
+            match &enum_.repr {
+                DataEnumRepr::Dictionary { .. } => {
+                    let key = &field.key;
+                    quote! { container.set(#key, #value); }
+                }
+                DataEnumRepr::Array => quote! { container.push(#value); },
+            }
+        });
+
+        let tag_value = quote! { &::godot::meta::ToGodot::to_variant(&#tag) };
+        let store_tag = match &enum_.repr {
+            DataEnumRepr::Dictionary { tag_key } => quote! { container.set(#tag_key, #tag_value); },
+            DataEnumRepr::Array => quote! { container.push(#tag_value); },
+        };
+
+        quote! {
+            #pattern => {
+                #store_tag
+                #( #stores )*
+            }
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                let mut container = <Self::Via as ::std::default::Default>::default();
+                match self {
+                    #( #arms )*
+                }
+                container
+            }
+        }
+    }
+}
+
 /// Derives `ToGodot` for enums with a via type of integers.
 fn make_togodot_for_int_enum(
     name: &Ident,
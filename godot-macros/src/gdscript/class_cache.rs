@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parser for `.godot/global_script_class_cache.cfg`, which Godot's editor maintains for all `class_name` declarations.
+//!
+//! The file is a `ConfigFile` with a single `list` entry, holding an array of dictionaries:
+//! ```text
+//! list=[{
+//! "base": &"Node2D",
+//! "class": &"Player",
+//! "icon": "",
+//! "language": &"GDScript",
+//! "path": "res://player.gd"
+//! }]
+//! ```
+
+/// One entry in the global class cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalClass {
+    pub name: String,
+
+    /// Direct base class; either an engine class or another global class.
+    pub base: String,
+
+    /// Script path in `res://` form.
+    pub path: String,
+    pub language: String,
+}
+
+/// Parses all entries of the class cache. Unknown keys are ignored.
+pub fn parse_class_cache(content: &str) -> Result<Vec<GlobalClass>, String> {
+    let mut classes = Vec::new();
+    let mut current: Option<Vec<(String, String)>> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        // Dictionaries end with `}` and start with `{`; both can appear on the same line, as in `}, {`.
+        if line.starts_with('}') {
+            let entries = current
+                .take()
+                .ok_or("unbalanced `}` in global class cache")?;
+
+            classes.push(make_class(&entries)?);
+        }
+
+        if line.ends_with('{') {
+            current = Some(Vec::new());
+            continue;
+        }
+
+        if let Some(entries) = current.as_mut() {
+            if let Some((key, value)) = line.split_once(':') {
+                entries.push((unquote(key), unquote(value.trim_end_matches(','))));
+            }
+        }
+    }
+
+    if current.is_some() {
+        return Err("unterminated entry in global class cache".to_string());
+    }
+
+    Ok(classes)
+}
+
+fn make_class(entries: &[(String, String)]) -> Result<GlobalClass, String> {
+    let get = |key: &str| -> Result<String, String> {
+        entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .ok_or_else(|| format!("global class cache entry without `{key}`: {entries:?}"))
+    };
+
+    Ok(GlobalClass {
+        name: get("class")?,
+        base: get("base")?,
+        path: get("path")?,
+        language: get("language")?,
+    })
+}
+
+/// Turns `"text"` or `&"text"` (StringName literal) into `text`.
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let value = value.strip_prefix('&').unwrap_or(value);
+
+    value.trim_matches('"').to_string()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cache() {
+        let content = r#"list=[{
+"base": &"Node2D",
+"class": &"Player",
+"icon": "",
+"is_abstract": false,
+"is_tool": false,
+"language": &"GDScript",
+"path": "res://actors/player.gd"
+}, {
+"base": &"Player",
+"class": &"Boss",
+"icon": "res://icon.svg",
+"language": &"GDScript",
+"path": "res://actors/boss.gd"
+}]
+"#;
+
+        let classes = parse_class_cache(content).unwrap();
+        assert_eq!(
+            classes,
+            vec![
+                GlobalClass {
+                    name: "Player".to_string(),
+                    base: "Node2D".to_string(),
+                    path: "res://actors/player.gd".to_string(),
+                    language: "GDScript".to_string(),
+                },
+                GlobalClass {
+                    name: "Boss".to_string(),
+                    base: "Player".to_string(),
+                    path: "res://actors/boss.gd".to_string(),
+                    language: "GDScript".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(parse_class_cache("list=[]\n").unwrap(), vec![]);
+        assert!(parse_class_cache("list=[{\n\"class\": &\"A\"\n}]").is_err());
+    }
+}
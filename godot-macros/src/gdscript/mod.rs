@@ -0,0 +1,553 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Generates typed Rust wrappers for GDScript classes declared with `class_name`.
+
+mod class_cache;
+mod script_parser;
+mod type_mapping;
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::ParseResult;
+use crate::util::{KvParser, bail, ident, safe_ident, venial_parse_meta};
+use class_cache::GlobalClass;
+use script_parser::{ScriptApi, ScriptParam};
+use type_mapping::{NamedType, RustType};
+
+const CLASS_CACHE_PATH: &str = ".godot/global_script_class_cache.cfg";
+
+/// Methods generated for every wrapper. Script functions with these names are generated with a `script_` prefix.
+const RESERVED_METHODS: &[&str] = &[
+    "try_cast",
+    "cast",
+    "as_gd",
+    "into_gd",
+    "into_object",
+    "instantiate",
+];
+
+/// Engine classes that are generated in every codegen configuration.
+///
+/// The macro has no access to the Godot API. Other engine classes are only recognized if a global class derives from them.
+const CORE_ENGINE_CLASSES: &[&str] = &[
+    "Object",
+    "RefCounted",
+    "Resource",
+    "Node",
+    "CanvasItem",
+    "Node2D",
+    "Node3D",
+];
+
+pub fn gdscript_classes(input: TokenStream) -> ParseResult<TokenStream> {
+    // Reuse the attribute parser for `key = value` syntax.
+    let item = venial_parse_meta(&input, ident("gdscript_classes"), &quote! { fn dummy() {} })?;
+    let venial::Item::Function(dummy) = item else {
+        unreachable!("venial_parse_meta() on function")
+    };
+
+    let mut parser = KvParser::parse_required(&dummy.attributes, "gdscript_classes", &input)?;
+    let Some(project_lit) = parser.handle_literal("project", "string")? else {
+        return bail!(
+            &input,
+            "gdscript_classes!: missing `project = \"path/to/godot/project\"`"
+        );
+    };
+
+    let selected = match parser.handle_array("classes")? {
+        Some(mut list) => {
+            let mut selected = Vec::new();
+            while let Some(class) = list.next_ident()? {
+                selected.push(class);
+            }
+            list.finish()?;
+            Some(selected)
+        }
+        None => None,
+    };
+    parser.finish()?;
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let project_dir = Path::new(&manifest_dir).join(project_lit.to_string().trim_matches('"'));
+
+    let cache_path = project_dir.join(CLASS_CACHE_PATH);
+    let cache_content = match std::fs::read_to_string(&cache_path) {
+        Ok(content) => content,
+        Err(e) => {
+            return bail!(
+                &project_lit,
+                "gdscript_classes!: cannot read {}: {e}\n\
+                 note: the file is created when the project is opened in the Godot editor (or imported with `--headless --import`)",
+                cache_path.display()
+            );
+        }
+    };
+
+    let classes = match class_cache::parse_class_cache(&cache_content) {
+        Ok(classes) => classes,
+        Err(e) => return bail!(&project_lit, "gdscript_classes!: {e}"),
+    };
+
+    let gdscript_classes = classes
+        .iter()
+        .filter(|class| class.language == "GDScript")
+        .collect::<Vec<_>>();
+
+    let generated = match &selected {
+        Some(selected) => {
+            let mut generated = Vec::new();
+            for name in selected {
+                let Some(class) = gdscript_classes.iter().find(|c| name == &c.name) else {
+                    return bail!(
+                        name,
+                        "gdscript_classes!: no GDScript class with `class_name {name}` in {}",
+                        cache_path.display()
+                    );
+                };
+                generated.push(*class);
+            }
+            generated
+        }
+        None => gdscript_classes,
+    };
+
+    let ctx = TypeContext::new(&classes, &generated);
+
+    let mut tracked_files = vec![cache_path];
+    let mut wrappers = Vec::new();
+    for class in &generated {
+        let script_path = script_file(&project_dir, &class.path);
+        let source = match std::fs::read_to_string(&script_path) {
+            Ok(source) => source,
+            Err(e) => {
+                return bail!(
+                    &project_lit,
+                    "gdscript_classes!: cannot read script of class `{}` at {}: {e}",
+                    class.name,
+                    script_path.display()
+                );
+            }
+        };
+
+        let api = script_parser::parse_script(&source);
+        wrappers.push(make_wrapper(class, &api, &ctx));
+        tracked_files.push(script_path);
+    }
+
+    // Rebuild when the class cache or any script changes.
+    let tracked_files = tracked_files
+        .iter()
+        .map(|path| Literal::string(&path.to_string_lossy()));
+
+    Ok(quote! {
+        #( const _: &[u8] = include_bytes!(#tracked_files); )*
+        #( #wrappers )*
+    })
+}
+
+/// Maps `res://` paths to the file system.
+fn script_file(project_dir: &Path, res_path: &str) -> PathBuf {
+    project_dir.join(res_path.strip_prefix("res://").unwrap_or(res_path))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type resolution
+
+/// Knows which global classes get a wrapper, and the native base of every global class.
+struct TypeContext {
+    generated: HashSet<String>,
+
+    /// Direct base of every global class.
+    bases: HashMap<String, String>,
+    native_bases: HashMap<String, String>,
+
+    /// Engine classes known to exist: the native bases of global classes, and [`CORE_ENGINE_CLASSES`].
+    engine_classes: HashSet<String>,
+}
+
+impl TypeContext {
+    fn new(all: &[GlobalClass], generated: &[&GlobalClass]) -> Self {
+        let bases = all
+            .iter()
+            .map(|class| (class.name.clone(), class.base.clone()))
+            .collect::<HashMap<_, _>>();
+
+        let native_bases = all
+            .iter()
+            .map(|class| {
+                // Follow script bases until reaching an engine class; bounded in case of a corrupt cache with cycles.
+                let mut base = &class.base;
+                for _ in 0..bases.len() {
+                    match bases.get(base) {
+                        Some(next) => base = next,
+                        None => break,
+                    }
+                }
+
+                (class.name.clone(), base.clone())
+            })
+            .collect::<HashMap<_, _>>();
+
+        let engine_classes = native_bases
+            .values()
+            .cloned()
+            .chain(CORE_ENGINE_CLASSES.iter().map(|class| class.to_string()))
+            .collect();
+
+        Self {
+            generated: generated.iter().map(|class| class.name.clone()).collect(),
+            bases,
+            native_bases,
+            engine_classes,
+        }
+    }
+
+    /// Decides what a type name in the script of `class` refers to. Script-local enums and inner classes shadow global names.
+    fn classify(&self, name: &str, class: &str, api: &ScriptApi) -> NamedType {
+        // Local types can be qualified with the class name, e.g. `Player.State`.
+        let local_name = name
+            .strip_prefix(class)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .unwrap_or(name);
+
+        if api.inner_classes.iter().any(|inner| inner == local_name) {
+            NamedType::InnerClass
+        } else if api.enums.iter().any(|enum_| enum_ == local_name) {
+            NamedType::ScriptEnum
+        } else if self.generated.contains(name) {
+            NamedType::ScriptClass(name.to_string())
+        } else if let Some(native_base) = self.native_bases.get(name) {
+            // Global classes without wrapper are represented by their native base.
+            NamedType::EngineClass(native_base.clone())
+        } else if self.engine_classes.contains(name) {
+            NamedType::EngineClass(name.to_string())
+        } else {
+            NamedType::Unknown
+        }
+    }
+
+    fn native_base(&self, class: &str) -> &str {
+        &self.native_bases[class]
+    }
+
+    /// Generated script classes that `class` inherits from, directly or indirectly.
+    fn script_ancestors(&self, class: &str) -> Vec<&str> {
+        let mut ancestors = Vec::new();
+        let mut current = class;
+        while let Some(base) = self.bases.get(current) {
+            // Guard against cycles in a corrupt cache.
+            if base == class || ancestors.contains(&base.as_str()) {
+                break;
+            }
+
+            ancestors.push(base.as_str());
+            current = base;
+        }
+
+        ancestors.retain(|name| self.generated.contains(*name));
+        ancestors
+    }
+}
+
+/// Maps the types used in the script of one class.
+struct ScriptTypes<'a> {
+    ctx: &'a TypeContext,
+    class: &'a str,
+    api: &'a ScriptApi,
+}
+
+impl ScriptTypes<'_> {
+    fn map(&self, gd_type: Option<&str>) -> RustType {
+        type_mapping::map_type(gd_type, &|name| {
+            self.ctx.classify(name, self.class, self.api)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Code generation
+
+fn make_wrapper(class: &GlobalClass, api: &ScriptApi, ctx: &TypeContext) -> TokenStream {
+    let wrapper = ident(&class.name);
+    let class_name = &class.name;
+    let script_path = &class.path;
+    let native_base = ident(ctx.native_base(&class.name));
+    let base_ty = quote! { ::godot::obj::Gd<::godot::classes::#native_base> };
+
+    let struct_doc = format!(
+        "Typed wrapper for the GDScript class `{class_name}` (`{script_path}`), generated by `gdscript_classes!`."
+    );
+
+    let types = ScriptTypes {
+        ctx,
+        class: &class.name,
+        api,
+    };
+
+    let instantiate = make_instantiate(api.init_params.as_deref().unwrap_or_default(), &types);
+
+    let functions = api.functions.iter().map(|func| {
+        let method = if RESERVED_METHODS.contains(&func.name.as_str()) {
+            format_ident!("script_{}", func.name)
+        } else {
+            safe_ident(&func.name)
+        };
+
+        let doc = &func.doc;
+        let (params, args) = make_params(&func.params, &types);
+        let return_ty = types.map(func.return_type.as_deref());
+        let rust_return_ty = return_ty.to_rust_type();
+        let method_name = &func.name;
+
+        let call = if func.is_static {
+            quote! {
+                ::godot::private::call_script_method(Self::SCRIPT_PATH, #method_name, &[ #(#args),* ])
+            }
+        } else {
+            quote! {
+                self.object
+                    .upcast_mut::<::godot::classes::Object>()
+                    .call(#method_name, &[ #(#args),* ])
+            }
+        };
+
+        let receiver = if func.is_static {
+            quote! {}
+        } else {
+            quote! { &mut self, }
+        };
+
+        let result = return_ty.make_from_variant(&quote! { result });
+
+        quote! {
+            #( #[doc = #doc] )*
+            pub fn #method(#receiver #(#params),*) -> #rust_return_ty {
+                let result = #call;
+                #result
+            }
+        }
+    });
+
+    let function_names = api
+        .functions
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let properties = api.properties.iter().map(|property| {
+        let name = &property.name;
+        let doc = &property.doc;
+        let ty = types.map(property.ty.as_deref());
+        let rust_ty = ty.to_rust_type();
+        let from_variant = ty.make_from_variant(&quote! { value });
+        let to_variant = ty.make_to_variant(&quote! { value });
+
+        // Explicit functions take precedence over generated accessors.
+        let getter_name = format!("get_{name}");
+        let setter_name = format!("set_{name}");
+
+        let getter = (!function_names.contains(getter_name.as_str())).then(|| {
+            let getter = ident(&getter_name);
+            quote! {
+                #( #[doc = #doc] )*
+                pub fn #getter(&self) -> #rust_ty {
+                    let value = self.object.upcast_ref::<::godot::classes::Object>().get(#name);
+                    #from_variant
+                }
+            }
+        });
+
+        let setter = (!function_names.contains(setter_name.as_str())).then(|| {
+            let setter = ident(&setter_name);
+            quote! {
+                #( #[doc = #doc] )*
+                pub fn #setter(&mut self, value: #rust_ty) {
+                    let value = #to_variant;
+                    self.object.upcast_mut::<::godot::classes::Object>().set(#name, &value);
+                }
+            }
+        });
+
+        quote! { #getter #setter }
+    });
+
+    let signals = api.signals.iter().map(|signal| {
+        let name = &signal.name;
+        let doc = &signal.doc;
+        let getter = format_ident!("signal_{}", signal.name);
+        let emitter = format_ident!("emit_{}", signal.name);
+        let (params, args) = make_params(&signal.params, &types);
+
+        quote! {
+            #( #[doc = #doc] )*
+            pub fn #getter(&self) -> ::godot::builtin::Signal {
+                ::godot::builtin::Signal::from_object_signal(&self.object, #name)
+            }
+
+            #( #[doc = #doc] )*
+            pub fn #emitter(&mut self, #(#params),*) -> ::godot::global::Error {
+                self.object
+                    .upcast_mut::<::godot::classes::Object>()
+                    .emit_signal(#name, &[ #(#args),* ])
+            }
+        }
+    });
+
+    let ancestor_conversions = ctx
+        .script_ancestors(&class.name)
+        .into_iter()
+        .map(|ancestor| {
+            let ancestor = ident(ancestor);
+            quote! {
+                impl From<#wrapper> for #ancestor {
+                    fn from(derived: #wrapper) -> Self {
+                        #ancestor::cast(derived.into_object())
+                    }
+                }
+            }
+        });
+
+    quote! {
+        #[doc = #struct_doc]
+        #[derive(Clone, Debug)]
+        pub struct #wrapper {
+            object: #base_ty,
+        }
+
+        impl #wrapper {
+            /// Name of the GDScript class, as declared with `class_name`.
+            pub const CLASS_NAME: &'static str = #class_name;
+
+            /// Path of the script resource.
+            pub const SCRIPT_PATH: &'static str = #script_path;
+
+            /// Casts `object` to this class, if the object's script is this class or derived from it. Returns the object otherwise.
+            pub fn try_cast<T>(object: ::godot::obj::Gd<T>) -> Result<Self, ::godot::obj::Gd<T>>
+            where
+                T: ::godot::obj::Inherits<::godot::classes::Object>,
+            {
+                if ::godot::private::object_has_script_path(object.upcast_ref(), Self::SCRIPT_PATH) {
+                    let object = object.upcast::<::godot::classes::Object>().cast::<::godot::classes::#native_base>();
+                    Ok(Self { object })
+                } else {
+                    Err(object)
+                }
+            }
+
+            /// ⚠️ Casts `object` to this class.
+            ///
+            /// # Panics
+            /// If the object does not have this script, or a script derived from it.
+            pub fn cast<T>(object: ::godot::obj::Gd<T>) -> Self
+            where
+                T: ::godot::obj::Inherits<::godot::classes::Object>,
+            {
+                Self::try_cast(object).unwrap_or_else(|object| {
+                    panic!("object {object:?} is not an instance of GDScript class `{}`", Self::CLASS_NAME)
+                })
+            }
+
+            /// Returns the underlying object.
+            pub fn as_gd(&self) -> &#base_ty {
+                &self.object
+            }
+
+            /// Returns the underlying object.
+            pub fn into_gd(self) -> #base_ty {
+                self.object
+            }
+
+            /// Returns the underlying object, upcast to `Object`.
+            pub fn into_object(self) -> ::godot::obj::Gd<::godot::classes::Object> {
+                self.object.upcast()
+            }
+
+            #instantiate
+            #( #functions )*
+            #( #properties )*
+            #( #signals )*
+        }
+
+        impl ::std::ops::Deref for #wrapper {
+            type Target = #base_ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.object
+            }
+        }
+
+        impl ::std::ops::DerefMut for #wrapper {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.object
+            }
+        }
+
+        #( #ancestor_conversions )*
+    }
+}
+
+fn make_instantiate(init_params: &[ScriptParam], types: &ScriptTypes) -> TokenStream {
+    let (params, args) = make_params(init_params, types);
+
+    quote! {
+        /// Creates a new instance of this class, calling `_init()` with the given arguments.
+        ///
+        /// Objects not inheriting `RefCounted` must be freed manually, or added to the scene tree.
+        pub fn instantiate(#(#params),*) -> Self {
+            let object = ::godot::private::call_script_method(Self::SCRIPT_PATH, "new", &[ #(#args),* ]);
+            Self::cast(object.to::<::godot::obj::Gd<::godot::classes::Object>>())
+        }
+    }
+}
+
+/// Returns parameter declarations and the expressions converting them to `Variant`.
+fn make_params(
+    params: &[ScriptParam],
+    types: &ScriptTypes,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    params
+        .iter()
+        .map(|param| {
+            let name = safe_ident(&param.name);
+            let ty = types.map(param.ty.as_deref());
+            let rust_ty = ty.to_rust_type();
+
+            let declaration = quote! { #name: #rust_ty };
+            let conversion = ty.make_to_variant(&quote! { #name });
+            (declaration, conversion)
+        })
+        .unzip()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_function_names() {
+        let class = GlobalClass {
+            name: "Spell".to_string(),
+            base: "Node".to_string(),
+            path: "res://spell.gd".to_string(),
+            language: "GDScript".to_string(),
+        };
+
+        let api = script_parser::parse_script("func cast(target: Node) -> void:\n\tpass\n");
+        let ctx = TypeContext::new(std::slice::from_ref(&class), &[&class]);
+        let wrapper = make_wrapper(&class, &api, &ctx).to_string();
+
+        assert!(wrapper.contains("pub fn script_cast"), "{wrapper}");
+        assert!(wrapper.contains("pub fn cast < T >"), "{wrapper}");
+    }
+}
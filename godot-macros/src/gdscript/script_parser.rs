@@ -0,0 +1,584 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Extracts the public API (functions, properties, signals) from GDScript source code.
+//!
+//! This is not a full GDScript parser. It only looks at top-level declarations, i.e. lines without indentation, and understands their
+//! signatures. Bodies and constants are skipped; of enums and inner classes, only the names are recorded. Members starting with `_` are
+//! considered private.
+
+/// Public API declared by a single `.gd` file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScriptApi {
+    pub functions: Vec<ScriptFunction>,
+    pub properties: Vec<ScriptProperty>,
+    pub signals: Vec<ScriptSignal>,
+
+    /// Names of enums declared in the script. Anonymous enums are not included.
+    pub enums: Vec<String>,
+
+    /// Names of inner classes declared in the script.
+    pub inner_classes: Vec<String>,
+
+    /// Parameters of `_init()`, if declared.
+    pub init_params: Option<Vec<ScriptParam>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptFunction {
+    pub name: String,
+    pub params: Vec<ScriptParam>,
+
+    /// Declared return type; `None` if untyped.
+    pub return_type: Option<String>,
+    pub is_static: bool,
+    pub doc: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptParam {
+    pub name: String,
+
+    /// Declared type; `None` if untyped or inferred (`:=`).
+    pub ty: Option<String>,
+    pub has_default: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptProperty {
+    pub name: String,
+    pub ty: Option<String>,
+    pub doc: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptSignal {
+    pub name: String,
+    pub params: Vec<ScriptParam>,
+    pub doc: Vec<String>,
+}
+
+/// Parses the top-level declarations of a GDScript file.
+pub fn parse_script(source: &str) -> ScriptApi {
+    let mut api = ScriptApi::default();
+    let mut doc = Vec::new();
+
+    for statement in top_level_statements(source) {
+        if let Some(doc_line) = statement.strip_prefix("##") {
+            doc.push(doc_line.trim().to_string());
+            continue;
+        }
+
+        // Annotations on their own line, such as `@export`, apply to the next declaration, and so does the doc comment.
+        let declaration = strip_annotations(&statement);
+        if declaration.is_empty() {
+            continue;
+        }
+
+        let doc = std::mem::take(&mut doc);
+
+        if let Some(rest) = declaration.strip_prefix("static func ") {
+            if let Some(func) = parse_function(rest, true, doc) {
+                api.functions.push(func);
+            }
+        } else if let Some(rest) = declaration.strip_prefix("func ") {
+            let Some(func) = parse_function(rest, false, doc) else {
+                continue;
+            };
+
+            if func.name == "_init" {
+                api.init_params = Some(func.params);
+            } else if !func.name.starts_with('_') {
+                api.functions.push(func);
+            }
+        } else if let Some(rest) = declaration.strip_prefix("var ") {
+            // `static var` is deliberately not handled; it cannot be accessed through an instance.
+            if let Some(property) = parse_property(rest, doc) {
+                api.properties.push(property);
+            }
+        } else if let Some(rest) = declaration.strip_prefix("signal ") {
+            if let Some(signal) = parse_signal(rest, doc) {
+                api.signals.push(signal);
+            }
+        } else if let Some(rest) = declaration.strip_prefix("enum ") {
+            if let Some(name) = leading_identifier(rest) {
+                api.enums.push(name.to_string());
+            }
+        } else if let Some(rest) = declaration.strip_prefix("class ") {
+            if let Some(name) = leading_identifier(rest) {
+                api.inner_classes.push(name.to_string());
+            }
+        }
+    }
+
+    api
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Declarations
+
+fn parse_function(rest: &str, is_static: bool, doc: Vec<String>) -> Option<ScriptFunction> {
+    let (name, after_name) = rest.split_once('(')?;
+    let name = name.trim();
+    if is_static && name.starts_with('_') {
+        return None;
+    }
+
+    let close = find_closing_paren(after_name)?;
+    let params = parse_params(&after_name[..close]);
+
+    // After `)`: optional `-> Type`, then `:`. The return type itself never contains `:`.
+    let after_params = after_name[close + 1..].trim();
+    let return_type = after_params
+        .strip_prefix("->")
+        .and_then(|ret| ret.split(':').next())
+        .map(|ret| ret.trim().to_string());
+
+    Some(ScriptFunction {
+        name: name.to_string(),
+        params,
+        return_type,
+        is_static,
+        doc,
+    })
+}
+
+fn parse_property(rest: &str, doc: Vec<String>) -> Option<ScriptProperty> {
+    let name = leading_identifier(rest)?;
+    if name.starts_with('_') {
+        return None;
+    }
+
+    let name_end = name.len();
+
+    // `var x: Type = value`, `var x: Type:` (setget block), `var x := value` or `var x = value`.
+    let ty = rest[name_end..]
+        .trim_start()
+        .strip_prefix(':')
+        .filter(|after_colon| !after_colon.starts_with('='))
+        .map(|after_colon| {
+            let end = find_top_level(after_colon, &['=', ':']).unwrap_or(after_colon.len());
+            after_colon[..end].trim().to_string()
+        })
+        .filter(|ty| !ty.is_empty());
+
+    Some(ScriptProperty {
+        name: name.to_string(),
+        ty,
+        doc,
+    })
+}
+
+fn parse_signal(rest: &str, doc: Vec<String>) -> Option<ScriptSignal> {
+    let (name, params) = match rest.split_once('(') {
+        Some((name, after_name)) => {
+            let close = find_closing_paren(after_name)?;
+            (name, parse_params(&after_name[..close]))
+        }
+        None => (rest, Vec::new()),
+    };
+
+    let name = name.trim();
+    if name.is_empty() || name.starts_with('_') {
+        return None;
+    }
+
+    Some(ScriptSignal {
+        name: name.to_string(),
+        params,
+        doc,
+    })
+}
+
+/// Parses a comma-separated parameter list, e.g. `a: int, b := 2.0, c: Array[int] = []`.
+fn parse_params(list: &str) -> Vec<ScriptParam> {
+    split_top_level(list, ',')
+        .into_iter()
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let default_pos = find_top_level(param, &['=']);
+            let has_default = default_pos.is_some();
+            let declaration = &param[..default_pos.unwrap_or(param.len())];
+
+            let (name, ty) = match declaration.split_once(':') {
+                Some((name, ty)) => (name.trim(), Some(ty.trim())),
+                None => (declaration.trim(), None),
+            };
+
+            ScriptParam {
+                name: name.to_string(),
+                ty: ty.filter(|ty| !ty.is_empty()).map(str::to_string),
+                has_default,
+            }
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Lexical helpers
+
+/// Splits the source into top-level statements, with comments removed and continuation lines joined.
+///
+/// Doc comments (`##`) are kept as separate statements, so they can be attached to the following declaration.
+fn top_level_statements(source: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+
+    for line in source.lines() {
+        let continues = !current.is_empty();
+
+        // Indented lines belong to bodies or setget blocks, unless they continue a multi-line signature.
+        let is_indented = line.starts_with([' ', '\t']);
+        if is_indented && !continues {
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if !continues && trimmed.starts_with("##") {
+            statements.push(trimmed.to_string());
+            continue;
+        }
+
+        let code = strip_comment(trimmed);
+        if code.is_empty() {
+            continue;
+        }
+
+        if continues {
+            current.push(' ');
+        }
+
+        current.push_str(code.trim_end_matches('\\').trim_end());
+        depth += bracket_delta(code);
+
+        if depth <= 0 && !code.ends_with('\\') {
+            statements.push(std::mem::take(&mut current));
+            depth = 0;
+        }
+    }
+
+    if !current.is_empty() {
+        statements.push(current);
+    }
+
+    statements
+}
+
+/// Removes a trailing `# comment`, ignoring `#` inside string literals.
+fn strip_comment(line: &str) -> &str {
+    match code_chars(line).find(|&(_, c)| c == '#') {
+        Some((comment_start, _)) => line[..comment_start].trim_end(),
+        None => line,
+    }
+}
+
+/// Iterates over the characters of `text` outside of string literals, together with their byte indices.
+///
+/// Quotes and `\` escapes inside strings are skipped as well. A `#` is yielded, so callers can detect comments.
+fn code_chars(text: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut chars = text.char_indices();
+    let mut quote = None;
+
+    std::iter::from_fn(move || {
+        while let Some((i, c)) = chars.next() {
+            match quote {
+                Some(_) if c == '\\' => {
+                    chars.next();
+                }
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if c == '"' || c == '\'' => quote = Some(c),
+                None => return Some((i, c)),
+            }
+        }
+
+        None
+    })
+}
+
+/// Returns the identifier at the start of `text`, if any.
+fn leading_identifier(text: &str) -> Option<&str> {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+
+    Some(&text[..end]).filter(|name| !name.is_empty())
+}
+
+/// Removes leading annotations like `@export`, `@onready` or `@export_range(0, 10)`.
+fn strip_annotations(statement: &str) -> &str {
+    let mut rest = statement.trim_start();
+
+    while let Some(after_at) = rest.strip_prefix('@') {
+        let name_end = after_at
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(after_at.len());
+
+        let mut after_annotation = &after_at[name_end..];
+        if let Some(args) = after_annotation.strip_prefix('(') {
+            let close = find_closing_paren(args).unwrap_or(args.len().saturating_sub(1));
+            after_annotation = &args[(close + 1).min(args.len())..];
+        }
+
+        rest = after_annotation.trim_start();
+    }
+
+    rest
+}
+
+/// Counts opening minus closing brackets, ignoring those in string literals and comments.
+fn bracket_delta(code: &str) -> i32 {
+    code_chars(code)
+        .take_while(|&(_, c)| c != '#')
+        .map(|(_, c)| match c {
+            '(' | '[' | '{' => 1,
+            ')' | ']' | '}' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Given the text after an opening `(`, returns the index of the matching `)`. Brackets in string literals are ignored.
+fn find_closing_paren(after_open: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in code_chars(after_open) {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Finds the first of `needles` outside of brackets and string literals.
+fn find_top_level(text: &str, needles: &[char]) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in code_chars(text) {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            c if depth == 0 && needles.contains(&c) => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(pos) = find_top_level(rest, &[separator]) {
+        parts.push(&rest[..pos]);
+        rest = &rest[pos + 1..];
+    }
+
+    parts.push(rest);
+    parts
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param(name: &str, ty: Option<&str>, has_default: bool) -> ScriptParam {
+        ScriptParam {
+            name: name.to_string(),
+            ty: ty.map(str::to_string),
+            has_default,
+        }
+    }
+
+    #[test]
+    fn parse_declarations() {
+        let source = r#"
+class_name Player
+extends CharacterBody2D
+
+## Emitted when health reaches zero.
+signal died(cause: String)
+signal respawned
+
+@export var health: int = 100
+@export_range(0, 10) var armor: float
+var inventory: Array[String] = []
+var speed := 3.0
+var untyped = null
+var _secret: int
+var clamped: int:
+	set(value):
+		clamped = clampi(value, 0, 10)
+
+enum State { IDLE, RUNNING }
+enum {
+	ANONYMOUS,
+}
+const MAX_SPEED = 10
+
+func _init(start_health: int = 100) -> void:
+	health = start_health
+
+func _ready():
+	pass
+
+## Applies damage.
+## Returns whether the player died.
+func take_damage(amount: int, source: Node = null) -> bool:
+	health -= amount # comment with (unbalanced
+	return health <= 0
+
+func move_to(
+	target: Vector2,
+	options: Dictionary = {},
+) -> void:
+	pass
+
+static func create(name: String) -> Player:
+	return null
+
+func untyped_fn(a, b := 2):
+	return a
+
+class Inner:
+	func hidden() -> void:
+		pass
+"#;
+
+        let api = parse_script(source);
+
+        assert_eq!(
+            api.signals,
+            vec![
+                ScriptSignal {
+                    name: "died".to_string(),
+                    params: vec![param("cause", Some("String"), false)],
+                    doc: vec!["Emitted when health reaches zero.".to_string()],
+                },
+                ScriptSignal {
+                    name: "respawned".to_string(),
+                    params: vec![],
+                    doc: vec![],
+                },
+            ]
+        );
+
+        let properties = api
+            .properties
+            .iter()
+            .map(|p| (p.name.as_str(), p.ty.as_deref()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            properties,
+            vec![
+                ("health", Some("int")),
+                ("armor", Some("float")),
+                ("inventory", Some("Array[String]")),
+                ("speed", None),
+                ("untyped", None),
+                ("clamped", Some("int")),
+            ]
+        );
+
+        assert_eq!(api.enums, vec!["State"]);
+        assert_eq!(api.inner_classes, vec!["Inner"]);
+
+        assert_eq!(
+            api.init_params,
+            Some(vec![param("start_health", Some("int"), true)])
+        );
+
+        let functions = api
+            .functions
+            .iter()
+            .map(|f| (f.name.as_str(), f.return_type.as_deref(), f.is_static))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            functions,
+            vec![
+                ("take_damage", Some("bool"), false),
+                ("move_to", Some("void"), false),
+                ("create", Some("Player"), true),
+                ("untyped_fn", None, false),
+            ]
+        );
+
+        let take_damage = &api.functions[0];
+        assert_eq!(
+            take_damage.params,
+            vec![
+                param("amount", Some("int"), false),
+                param("source", Some("Node"), true),
+            ]
+        );
+        assert_eq!(
+            take_damage.doc,
+            vec!["Applies damage.", "Returns whether the player died."]
+        );
+
+        assert_eq!(
+            api.functions[1].params,
+            vec![
+                param("target", Some("Vector2"), false),
+                param("options", Some("Dictionary"), true),
+            ]
+        );
+
+        assert_eq!(
+            api.functions[3].params,
+            vec![param("a", None, false), param("b", None, true)]
+        );
+    }
+
+    #[test]
+    fn bracket_delta_skips_strings_and_comments() {
+        assert_eq!(bracket_delta("func f(a = \"(\", b = ')'"), 1);
+        assert_eq!(bracket_delta(r#"f("\")(", '\'[') # ) ] }"#), 0);
+        assert_eq!(bracket_delta("var x = [ # ]"), 1);
+
+        let source = r#"
+func greet(
+	text: String = "(\"unbalanced",
+	suffix := ")",
+) -> void:
+	pass
+
+func after() -> void:
+	pass
+"#;
+
+        let api = parse_script(source);
+        let functions = api
+            .functions
+            .iter()
+            .map(|f| (f.name.as_str(), f.params.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(functions, vec![("greet", 2), ("after", 0)]);
+    }
+
+    #[test]
+    fn parse_typed_collections() {
+        let params =
+            parse_params("a: Dictionary[String, int], b: Array[Vector2] = [Vector2(1, 2)]");
+        assert_eq!(
+            params,
+            vec![
+                param("a", Some("Dictionary[String, int]"), false),
+                param("b", Some("Array[Vector2]"), true),
+            ]
+        );
+    }
+}
@@ -0,0 +1,237 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Maps GDScript type names to Rust types.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::ident;
+
+/// Rust representation of a GDScript type.
+#[derive(Clone, Debug)]
+pub enum RustType {
+    /// `void` return type.
+    Unit,
+
+    /// Type implementing `ToGodot + FromGodot`, e.g. `i64`, `GString` or `Option<Gd<Node>>`.
+    Convertible(TokenStream),
+
+    /// Another global script class, represented by its generated wrapper as `Option<Wrapper>`.
+    ScriptClass(proc_macro2::Ident),
+}
+
+impl RustType {
+    /// The type as it appears in parameter and return positions.
+    pub fn to_rust_type(&self) -> TokenStream {
+        match self {
+            Self::Unit => quote! { () },
+            Self::Convertible(ty) => ty.clone(),
+            Self::ScriptClass(wrapper) => quote! { Option<#wrapper> },
+        }
+    }
+
+    /// Expression converting `value` (of this type) to `Variant`.
+    pub fn make_to_variant(&self, value: &TokenStream) -> TokenStream {
+        match self {
+            Self::Unit => quote! { ::godot::builtin::Variant::nil() },
+            Self::Convertible(_) => quote! { ::godot::meta::ToGodot::to_variant(&#value) },
+            Self::ScriptClass(wrapper) => quote! {
+                ::godot::meta::ToGodot::to_variant(&#value.map(#wrapper::into_object))
+            },
+        }
+    }
+
+    /// Expression converting `variant` (a `Variant`) to this type.
+    pub fn make_from_variant(&self, variant: &TokenStream) -> TokenStream {
+        match self {
+            Self::Unit => quote! { { let _ = #variant; } },
+            Self::Convertible(ty) => quote! { #variant.to::<#ty>() },
+            Self::ScriptClass(wrapper) => quote! {
+                #variant
+                    .to::<Option<::godot::obj::Gd<::godot::classes::Object>>>()
+                    .map(#wrapper::cast)
+            },
+        }
+    }
+}
+
+/// What a type name refers to, if it is not a builtin type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NamedType {
+    /// Global script class with a generated wrapper.
+    ScriptClass(String),
+
+    /// Engine class, available in `godot::classes`. Global script classes without wrapper are represented by their engine base.
+    EngineClass(String),
+
+    /// Enum declared in the script itself. Values are integers.
+    ScriptEnum,
+
+    /// Inner class declared in the script itself (`class Name:`).
+    InnerClass,
+
+    /// Any other name, e.g. a class of another GDExtension or an engine enum.
+    Unknown,
+}
+
+/// Maps a declared GDScript type to Rust. Untyped declarations (`None`), inner classes and unknown names map to `Variant`.
+///
+/// `classify` decides what a (non-builtin) type name refers to.
+pub fn map_type(gd_type: Option<&str>, classify: &dyn Fn(&str) -> NamedType) -> RustType {
+    let variant = RustType::Convertible(quote! { ::godot::builtin::Variant });
+
+    let Some(gd_type) = gd_type else {
+        return variant;
+    };
+
+    if gd_type == "void" {
+        RustType::Unit
+    } else if let Some(element) = gd_type
+        .strip_prefix("Array[")
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        let array = match map_array_element(element.trim(), classify) {
+            Some(element) => quote! { ::godot::builtin::Array<#element> },
+            None => quote! { ::godot::builtin::VarArray },
+        };
+
+        RustType::Convertible(array)
+    } else if gd_type.starts_with("Dictionary[") {
+        // Typed dictionaries are represented as untyped, to stay compatible with all Godot versions.
+        RustType::Convertible(quote! { ::godot::builtin::VarDictionary })
+    } else if let Some(builtin) = map_builtin(gd_type) {
+        RustType::Convertible(builtin)
+    } else {
+        match classify(gd_type) {
+            NamedType::ScriptClass(class) => RustType::ScriptClass(ident(&class)),
+            NamedType::EngineClass(class) => {
+                let class = ident(&class);
+                RustType::Convertible(quote! { Option<::godot::obj::Gd<::godot::classes::#class>> })
+            }
+            NamedType::ScriptEnum => RustType::Convertible(quote! { i64 }),
+            NamedType::InnerClass | NamedType::Unknown => variant,
+        }
+    }
+}
+
+/// Element type of a typed array, or `None` if the array is represented as `VarArray`.
+///
+/// Object elements are non-nullable `Gd<T>`. Arrays of script classes are stored with their native base type, so they are untyped in Rust.
+fn map_array_element(element: &str, classify: &dyn Fn(&str) -> NamedType) -> Option<TokenStream> {
+    if element == "Variant" {
+        return None;
+    }
+
+    if let Some(builtin) = map_builtin(element) {
+        return Some(builtin);
+    }
+
+    match classify(element) {
+        NamedType::EngineClass(class) => {
+            let class = ident(&class);
+            Some(quote! { ::godot::obj::Gd<::godot::classes::#class> })
+        }
+        NamedType::ScriptEnum => Some(quote! { i64 }),
+        NamedType::ScriptClass(_) | NamedType::InnerClass | NamedType::Unknown => None,
+    }
+}
+
+fn map_builtin(gd_type: &str) -> Option<TokenStream> {
+    let builtin = match gd_type {
+        "bool" => quote! { bool },
+        "int" => quote! { i64 },
+        "float" => quote! { f64 },
+        "String" => quote! { ::godot::builtin::GString },
+        "Variant" => quote! { ::godot::builtin::Variant },
+        "Array" => quote! { ::godot::builtin::VarArray },
+        "Dictionary" => quote! { ::godot::builtin::VarDictionary },
+        "AABB" => quote! { ::godot::builtin::Aabb },
+        "RID" => quote! { ::godot::builtin::Rid },
+
+        "StringName" | "NodePath" | "Vector2" | "Vector2i" | "Vector3" | "Vector3i" | "Vector4"
+        | "Vector4i" | "Rect2" | "Rect2i" | "Transform2D" | "Transform3D" | "Plane"
+        | "Quaternion" | "Basis" | "Projection" | "Color" | "Callable" | "Signal"
+        | "PackedByteArray" | "PackedInt32Array" | "PackedInt64Array" | "PackedFloat32Array"
+        | "PackedFloat64Array" | "PackedStringArray" | "PackedVector2Array"
+        | "PackedVector3Array" | "PackedVector4Array" | "PackedColorArray" => {
+            let ty = ident(gd_type);
+            quote! { ::godot::builtin::#ty }
+        }
+
+        _ => return None,
+    };
+
+    Some(builtin)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(name: &str) -> NamedType {
+        match name {
+            "Player" => NamedType::ScriptClass(name.to_string()),
+            "Node" => NamedType::EngineClass(name.to_string()),
+            "State" => NamedType::ScriptEnum,
+            "Inner" => NamedType::InnerClass,
+            _ => NamedType::Unknown,
+        }
+    }
+
+    fn map(gd_type: &str) -> String {
+        map_type(Some(gd_type), &classify)
+            .to_rust_type()
+            .to_string()
+    }
+
+    fn tokens(tokens: TokenStream) -> String {
+        tokens.to_string()
+    }
+
+    #[test]
+    fn map_variant() {
+        assert_eq!(map("Variant"), tokens(quote! { ::godot::builtin::Variant }));
+        assert_eq!(
+            map("Array[Variant]"),
+            tokens(quote! { ::godot::builtin::VarArray })
+        );
+    }
+
+    #[test]
+    fn map_script_enum() {
+        assert_eq!(map("State"), tokens(quote! { i64 }));
+        assert_eq!(
+            map("Array[State]"),
+            tokens(quote! { ::godot::builtin::Array<i64> })
+        );
+    }
+
+    #[test]
+    fn map_unknown_and_inner_class() {
+        let variant = tokens(quote! { ::godot::builtin::Variant });
+
+        assert_eq!(map("Inner"), variant);
+        assert_eq!(map("OtherExtensionClass"), variant);
+        assert_eq!(map("Node.ProcessMode"), variant);
+        assert_eq!(
+            map("Array[Inner]"),
+            tokens(quote! { ::godot::builtin::VarArray })
+        );
+    }
+
+    #[test]
+    fn map_classes() {
+        assert_eq!(
+            map("Node"),
+            tokens(quote! { Option<::godot::obj::Gd<::godot::classes::Node>> })
+        );
+        assert_eq!(map("Player"), tokens(quote! { Option<Player> }));
+    }
+}
@@ -15,6 +15,7 @@ mod class;
 mod derive;
 mod docs;
 mod gdextension;
+mod gdscript;
 mod itest;
 mod util;
 
@@ -307,10 +308,32 @@ use crate::util::{KvParser, bail, ident};
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export_node_path("Node2D", "Node3D")
+///     #[export(node_path = (Node2D, Node3D))]
+///     target: NodePath,
+///
+///     // @export_custom(PROPERTY_HINT_ENUM_SUGGESTION, "Low,High", PROPERTY_USAGE_DEFAULT | PROPERTY_USAGE_READ_ONLY)
+///     #[export(custom(hint = ENUM_SUGGESTION, hint_string = "Low,High", usage = [DEFAULT, READ_ONLY]))]
+///     quality: GString,
+///
+///     // @export_range(0, 100) var percentages: Array[int]
+///     #[export(elements, range = (0.0, 100.0))]
+///     percentages: Array<i64>,
 /// }
 ///
 /// ```
 ///
+/// Hints on typed arrays apply to the whole array by default. To apply a hint to each element instead, like GDScript does for
+/// annotations on typed arrays, add the `elements` key. Packed arrays support this since Godot 4.3; earlier versions fail to compile.
+/// Arrays of resources and nodes get their element type hint automatically.
+///
+/// Since Godot 4.4, `#[export(file)]` stores `uid://` references. Use `#[export(file_path)]` or `#[export(file_path = "*.png")]`
+/// (Godot 4.5+, `@export_file_path`) to store the raw path instead.
+///
+/// To show a property with a custom Rust editor in the Inspector, use `#[export(editor = MyEditor)]`; see
+/// [Custom property editors](#custom-property-editors).
+///
 /// Most values in syntax such as `key = value` can be arbitrary expressions. For example, you can use constants, function calls or
 /// other Rust expressions that are valid in that context.
 ///
@@ -332,13 +355,14 @@ use crate::util::{KvParser, bail, ident};
 /// It is possible to group your exported properties inside the Inspector with the `#[export_group(name = "...", prefix =  "...")]` attribute.
 /// Every exported property after this attribute will be added to the group. Start a new group or use `#[export_group(name = "")]` (with an empty name) to break out.
 ///
-/// Groups cannot be nested but subgroups can be declared with an `#[export_subgroup]` attribute.
+/// Groups cannot be nested but subgroups can be declared with an `#[export_subgroup]` attribute. Subgroups can in turn be nested
+/// with the slash separator `/`, e.g. `#[export_subgroup(name = "Air/Jump", prefix = "jump_")]`; each of them can have its own prefix.
+/// Nested subgroups are only supported inside a group, otherwise compilation fails.
 ///
-/// GDExtension groups and subgroups follow the same rules as the gdscript ones.
+/// Above groups, `#[export_category(name = "...")]` adds a category header to the Inspector, like `@export_category`. A category ends
+/// the previous group and subgroup.
 ///
-/// <div class="warning">
-/// Nesting subgroups with the slash separator `/` <strong>outside</strong> the group is not supported and might crash the editor.
-/// </div>
+/// GDExtension categories, groups and subgroups follow the same rules as the gdscript ones.
 ///
 /// See also in Godot docs:
 /// [Grouping Exports](https://docs.godotengine.org/en/stable/tutorials/scripting/gdscript/gdscript_exports.html#grouping-exports)
@@ -382,6 +406,20 @@ use crate::util::{KvParser, bail, ident};
 ///     // @export var ungrouped_field: int
 ///     #[export]
 ///     ungrouped_field: i64,
+///
+///     // @export_category("Stats")
+///     // @export_group("Movement", "move_")
+///     // @export var move_speed: float
+///     #[export_category(name = "Stats")]
+///     #[export_group(name = "Movement", prefix = "move_")]
+///     #[export]
+///     move_speed: f32,
+///
+///     // @export_subgroup("Air/Jump", "move_jump_")
+///     // @export var move_jump_height: float
+///     #[export_subgroup(name = "Air/Jump", prefix = "move_jump_")]
+///     #[export]
+///     move_jump_height: f32,
 /// }
 ///```
 ///
@@ -580,6 +618,44 @@ use crate::util::{KvParser, bail, ident};
 ///
 /// In such a case, await one frame until extension is properly hot-reloaded (See: [`godot::task::spawn()`](../task/fn.spawn.html)).
 ///
+/// ### Custom property editors
+///
+/// Instead of implementing `IEditorInspectorPlugin` and `IEditorProperty` by hand, an exported field can name a Rust editor with
+/// `#[export(editor = MyEditor)]`. The key can be combined with the other `#[export]` keys.
+///
+/// `MyEditor` is a `Control` class implementing [`PropertyEditor`](../tools/trait.PropertyEditor.html). It receives the property value as
+/// a typed `Value`, and commits changes through a [`PropertySetter`](../tools/struct.PropertySetter.html), which records them in the
+/// editor's undo/redo history.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Label;
+/// # use godot::tools::PropertyEditor;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=Label)]
+/// struct HealthEditor {
+///     base: Base<Label>,
+/// }
+///
+/// impl PropertyEditor for HealthEditor {
+///     type Value = i32;
+///
+///     fn update_value(&mut self, value: i32) {
+///         self.base_mut().set_text(&format!("{value} HP"));
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     #[export(editor = HealthEditor, range = (0.0, 100.0))]
+///     health: i32,
+/// }
+/// ```
+///
+/// For each class with such fields, an internal editor plugin is registered, which shows the editors in the Inspector. This requires the
+/// full engine API, i.e. the default features of the `godot` crate.
+///
 /// ## Class renaming
 ///
 /// You may want to have structs with the same name. With Rust, this is allowed using `mod`. However, in GDScript
@@ -745,6 +821,7 @@ use crate::util::{KvParser, bail, ident};
         hint,
         var,
         export,
+        export_category,
         export_group,
         export_tool_button,
         export_subgroup,
@@ -937,6 +1014,77 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// - Default expressions are evaluated on each function call (not cached). **This may change**, see
 ///   [PR #1396](https://github.com/godot-rust/gdext/pull/1396).
 ///
+/// ## Returning `Result`
+///
+/// Functions can return `Result<T, E>`. Since GDScript has no such type, the result is converted by an error mapping strategy,
+/// i.e. a type implementing [`ErrorMapping`](../register/trait.ErrorMapping.html). The strategy is chosen with `#[func(on_error = ...)]`
+/// for a single function, or with `#[godot_api(on_error = ...)]` for all functions in the impl block. By default, errors are treated
+/// like panics ([`ErrorToPanic`](../register/struct.ErrorToPanic.html)).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::register::{ErrorToCode, ErrorToNull};
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct SaveGame {}
+///
+/// #[godot_api(on_error = ErrorToNull)]
+/// impl SaveGame {
+///     // Returns the level name, or null (and prints the error) on failure.
+///     #[func]
+///     fn level_name(&self) -> Result<GString, String> {
+///         Err("no level loaded".to_string())
+///     }
+///
+///     // Returns Error.OK or the error code.
+///     #[func(on_error = ErrorToCode)]
+///     fn save(&self) -> Result<(), godot::global::Error> {
+///         Err(godot::global::Error::ERR_FILE_CANT_WRITE)
+///     }
+/// }
+/// ```
+///
+/// The registered method uses the strategy's output type as its return type (here `Variant` and `Error`), so GDScript and
+/// the editor documentation show what is actually returned. `Result` is recognized by name; for other type aliases, specify
+/// `#[func(on_error = ...)]` explicitly.
+///
+/// ## Async functions
+///
+/// `#[func]` can be declared as `async fn`. When called from Godot, the future is spawned on the [async runtime](../task/fn.spawn.html),
+/// and the function immediately returns a `Signal`. The signal is emitted once, with the future's output as its argument -- so GDScript
+/// can simply `await` the call.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Level {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Level {
+///     #[signal]
+///     fn loaded();
+///
+///     #[func(gd_self)]
+///     async fn load_level(this: Gd<Self>, path: GString) -> i64 {
+///         this.signals().loaded().to_future().await;
+///         path.len() as i64
+///     }
+/// }
+/// ```
+/// ```gdscript
+/// var result = await level.load_level("res://level_1.tscn")
+/// ```
+///
+/// Since the future outlives the call, async functions cannot take `&self` or `&mut self`. Use `#[func(gd_self)]` or an associated function
+/// instead. Returning `Result` is supported; the error mapping is applied to the output of the future.
+///
+/// If the task panics or is canceled (e.g. as part of a canceled parent task), the error is printed and the signal is emitted with `null`,
+/// so awaiting callers do not hang. Panics are tracked by the runtime like in any other task.
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -1051,10 +1199,36 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ### Calling RPCs
+///
+/// For classes with at least one `#[rpc]` method, the primary `#[godot_api]` block generates an `rpcs()` method. It returns a collection with
+/// one accessor per RPC, which provides type-checked `rpc()` and `rpc_id()` methods. Both return `Err` with an [`RpcError`] if the node is
+/// not in the scene tree, or if Godot reports an error.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=Node)]
+/// # struct MyStruct {
+/// #     base: Base<Node>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[rpc(any_peer, reliable)]
+///     fn chat(&mut self, sender: GString, message: GString) { /* ... */ }
+///
+///     fn send_chat(&self, peer_id: i64) {
+///         // Equivalent to `rpc_id(peer_id, "chat", vslice!["me", "hello"])`, but type-checked at compile time.
+///         self.rpcs().chat().rpc_id(peer_id, "me", "hello").expect("RPC failed");
+///     }
+/// }
+/// ```
+///
 // Note: for some reason, the intra-doc links don't work here, despite dev-dependency on godot.
 /// [`RpcMode`]: ../classes/multiplayer_api/struct.RpcMode.html
 /// [`TransferMode`]: ../classes/multiplayer_peer/struct.TransferMode.html
 /// [`RpcConfig`]: ../register/struct.RpcConfig.html
+/// [`RpcError`]: ../register/struct.RpcError.html
 ///
 /// # Lifecycle functions with custom receivers
 ///
@@ -1269,7 +1443,7 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// # Choosing a Via type
 ///
 /// To specify the `Via` type that your type should be converted to, you must use the `godot` attribute.
-/// There are currently two modes supported.
+/// There are currently three modes supported: `transparent`, `via = <type>` and collection-based `via = Dictionary` or `via = Array`.
 ///
 /// ## `transparent`
 ///
@@ -1389,6 +1563,87 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## `via = Dictionary`
+///
+/// Structs with named fields can be converted to a [`VarDictionary`](../builtin/type.VarDictionary.html) with one key per field. Each field
+/// type must itself implement `ToGodot` and `FromGodot`, so such structs can be nested.
+///
+/// Fields accept the following `#[godot(...)]` keys:
+/// - `rename = "key"`: use `"key"` as the dictionary key instead of the field name.
+/// - `default` or `default = expr`: if the key is absent, initialize the field with `Default::default()` or `expr`.
+///   Without this key, absent keys cause a conversion error.
+/// - `skip`: never write the field to the dictionary and always initialize it like a `default` field. Can be combined with `default = expr`.
+///
+/// Dictionary keys that don't correspond to any field are ignored. When conversion fails, the returned
+/// [`ConvertError`](../meta/error/struct.ConvertError.html) reports the path to the failing key, e.g. `stats.health`.
+///
+/// ### Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// struct SaveData {
+///     player_name: GString,
+///     #[godot(rename = "pos")]
+///     position: Vector2,
+///     #[godot(default)]
+///     unlocked_levels: Array<i64>,
+///     #[godot(skip)]
+///     dirty: bool,
+/// }
+///
+/// let data = SaveData {
+///     player_name: "Ferris".into(),
+///     position: Vector2::new(1.0, 2.0),
+///     unlocked_levels: array![1, 2],
+///     dirty: true,
+/// };
+///
+/// let dict: VarDictionary = data.to_godot();
+/// assert_eq!(dict.at("pos"), Vector2::new(1.0, 2.0).to_variant());
+/// assert!(!dict.contains_key("dirty"));
+/// ```
+///
+/// ## Enums carrying data: `via = Dictionary` and `via = Array`
+///
+/// Enums whose enumerators carry data (tuple or named fields, mixed with unit enumerators) are converted to a tagged representation.
+/// The tag is the enumerator name, unless overridden with `#[godot(rename = "tag")]` on the enumerator.
+///
+/// - `#[godot(via = Dictionary)]` stores the tag under the key `"type"` (configurable with `#[godot(via = Dictionary, tag = "key")]`),
+///   and each field under its own key. Tuple fields use their index as key, i.e. `"0"`, `"1"`, etc.
+/// - `#[godot(via = Array)]` stores the tag as the first element, followed by the fields in declaration order.
+///
+/// Fields support the same `rename`, `default` and `skip` keys as structs. For arrays, `default` applies if the array is too short.
+///
+/// ### Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// enum Command {
+///     Move(Vector2),
+///     Say { text: GString },
+///     Stop,
+/// }
+///
+/// let dict: VarDictionary = Command::Say { text: "hi".into() }.to_godot();
+/// assert_eq!(dict, vdict! { "type": "Say", "text": "hi" });
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Array)]
+/// enum Event {
+///     Damage(i64, GString),
+///     Heal { amount: i64 },
+/// }
+///
+/// let array: VarArray = Event::Damage(10, "fire".into()).to_godot();
+/// assert_eq!(array, varray!["Damage", 10, "fire"]);
+/// ```
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -1440,6 +1695,74 @@ pub fn gdextension(meta: TokenStream, input: TokenStream) -> TokenStream {
     )
 }
 
+/// Generates typed Rust wrappers for GDScript classes declared with `class_name`.
+///
+/// Reads the project's `.godot/global_script_class_cache.cfg` and the `.gd` files of all GDScript global classes, and emits one wrapper
+/// struct per class. The cache file is created by the Godot editor; for CI, run `godot --headless --import` once before building.
+/// The crate is rebuilt whenever the cache or one of the scripts changes.
+///
+/// # Syntax
+/// ```text
+/// gdscript_classes!(project = "../godot");
+/// gdscript_classes!(project = "../godot", classes = [Player, Enemy]);
+/// ```
+/// - **`project`**: path to the directory containing `project.godot`, relative to the crate's `Cargo.toml`.
+/// - **`classes`** (optional): restricts generation to the listed classes. Other global classes are then represented by their engine base class.
+///
+/// # Generated API
+/// For a script like
+/// ```gdscript
+/// class_name Player extends Node2D
+///
+/// signal died(cause: String)
+/// @export var health: int = 100
+///
+/// func take_damage(amount: int) -> bool:
+///     ...
+/// static func create(name: String) -> Player:
+///     ...
+/// ```
+/// the macro generates a `Player` struct wrapping a `Gd<Node2D>`, with:
+/// - `Player::try_cast(gd)` and `Player::cast(gd)` to convert from any `Gd<T>`, checking that the script (or a derived script) is attached.
+/// - `Player::instantiate(...)`, taking the parameters of `_init()`.
+/// - `fn take_damage(&mut self, amount: i64) -> bool` and `fn create(name: GString) -> Option<Player>` for functions, including static ones.
+///   Functions named like one of the generated methods (e.g. `func cast()`) get a `script_` prefix (`script_cast()`).
+/// - `get_health()`/`set_health()` for properties.
+/// - `signal_died()` returning a [`Signal`](../builtin/struct.Signal.html), and `emit_died(cause: GString)`.
+/// - `Deref`/`DerefMut` to the `Gd<Node2D>`, and `From` conversions into wrappers of base script classes.
+///
+/// GDScript types are mapped to their Rust equivalents (`int` to `i64`, `String` to `GString`, `Array[int]` to `Array<i64>`, engine classes
+/// to `Option<Gd<T>>`, global script classes to `Option<Wrapper>`, enums declared in the script to `i64`). Untyped declarations, inner
+/// classes, engine enums and other unknown types become `Variant`. Since the macro has no access to the Godot API, the only recognized
+/// engine classes are the base classes of global classes, and `Object`, `RefCounted`, `Resource`, `Node`, `CanvasItem`, `Node2D` and
+/// `Node3D`.
+/// Members starting with `_` are considered private and skipped. Parameters with default values must be passed explicitly.
+///
+/// Calls are dispatched dynamically through [`Object::call()`](../classes/struct.Object.html#method.call), so a wrapper that is out of
+/// date with its script fails at runtime, like a stringly-typed call would. Regenerating after script changes turns such mismatches into
+/// compile errors in the Rust code.
+///
+/// # Example
+/// ```no_run
+/// # #[cfg(any())] // Requires an imported Godot project.
+/// mod gd {
+///     godot::tools::gdscript_classes!(project = "../godot");
+/// }
+///
+/// # #[cfg(any())]
+/// fn on_body_entered(body: Gd<Node2D>) {
+///     if let Ok(mut player) = gd::Player::try_cast(body) {
+///         if player.take_damage(10) {
+///             player.emit_died("spikes".into());
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn gdscript_classes(input: TokenStream) -> TokenStream {
+    translate_functional(input, gdscript::gdscript_classes)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -1481,7 +1804,6 @@ where
 }
 
 /// For `#[proc_macro]` function-style macros.
-#[expect(dead_code)]
 fn translate_functional<F>(input: TokenStream, transform: F) -> TokenStream
 where
     F: FnOnce(TokenStream2) -> ParseResult<TokenStream2>,
@@ -99,6 +99,33 @@
 //! </div>
 //!
 //!
+//! ## Engine class subset
+//!
+//! Generating bindings for the entire Godot API takes a large share of clean build times. If your project only uses some engine classes,
+//! you can list them in a text file and point the `GODOT4_CODEGEN_CLASSES` environment variable to it. The easiest way is an `[env]`
+//! entry in `.cargo/config.toml`:
+//!
+//! ```toml
+//! [env]
+//! GODOT4_CODEGEN_CLASSES = { value = "godot-classes.txt", relative = true }
+//! ```
+//!
+//! The file contains class names as they appear in Godot, separated by whitespace or commas. `#` starts a comment:
+//!
+//! ```text
+//! # Classes used by the game.
+//! Sprite2D, AnimationPlayer
+//! Timer
+//! ```
+//!
+//! Besides the listed classes, godot-rust generates their base classes and all classes that appear in their method and signal signatures
+//! (parameters, return types and enums), so that the listed classes are fully usable. Those additional classes are not expanded further:
+//! their methods are available only if they don't refer to classes outside the subset. Classes needed by godot-rust itself are always
+//! generated. Unknown class names cause a build error.
+//!
+//! Cargo metadata of your crate is not visible to godot-rust's build scripts, which is why an environment variable is used.
+//!
+//!
 //! ## Cargo features
 //!
 //! The following features can be enabled for this crate. All of them are off by default.
@@ -192,7 +219,8 @@
 //!
 //! * **`serde`**
 //!
-//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
+//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for built-in types, including `Variant`, dictionaries
+//!   and packed arrays. Variants holding objects, callables, signals or RIDs cannot be serialized.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
 
@@ -241,7 +269,13 @@ pub use godot_core::possibly_docs as docs;
 #[doc(hidden)]
 pub use godot_core::sys;
 #[doc(inline)]
-pub use godot_core::{builtin, classes, global, meta, obj, task, tools};
+pub use godot_core::{builtin, classes, global, meta, obj, task};
+
+/// Higher-level additions to the Godot engine API.
+pub mod tools {
+    pub use godot_core::tools::*;
+    pub use godot_macros::gdscript_classes;
+}
 
 /// Entry point and global init/shutdown of the library.
 pub mod init {
@@ -252,17 +286,27 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
-    #[cfg(feature = "__codegen-full")]
-    pub use godot_core::registry::RpcConfig;
+    pub use godot_core::builder::ClassBuilder;
+    pub use godot_core::registry::func_result::{
+        ErrorMapping, ErrorToCode, ErrorToDictionary, ErrorToNull, ErrorToPanic,
+    };
     pub use godot_core::registry::property;
     pub use godot_core::registry::signal::re_export::*;
+    #[cfg(feature = "__codegen-full")]
+    pub use godot_core::registry::{RpcConfig, RpcError, TypedRpc};
     pub use godot_macros::{Export, GodotClass, GodotConvert, Var, godot_api, godot_dyn};
 
+    /// Builders for registering class symbols at runtime, see [`ClassBuilder`].
+    pub mod builder {
+        pub use godot_core::builder::{MethodBuilder, PropertyBuilder, SignalBuilder};
+    }
+
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
     pub mod private {
         #[cfg(feature = "__codegen-full")]
         pub use godot_core::registry::class::auto_register_rpcs;
+        pub use godot_core::registry::func_result::{FuncResult, MappedReturn, map_func_result};
         pub use godot_core::registry::godot_register_wrappers::*;
         pub use godot_core::registry::{constant, method};
     }
@@ -0,0 +1,29 @@
+# Copyright (c) godot-rust; Bromeon and contributors.
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+# Fixture for the Rust wrapper generated by `gdscript_classes!`, see gdscript_classes_test.rs.
+class_name ScriptWrapperFixture
+extends RefCounted
+
+signal scored(points: int)
+
+var points: int = 0
+var label: String = "none"
+var last_scored: int = -1
+
+func _init(start: int) -> void:
+	points = start
+	scored.connect(func(p: int) -> void: last_scored = p)
+
+func add(amount: int) -> int:
+	points += amount
+	return points
+
+# Collides with the generated `cast()` method; the wrapper exposes it as `script_cast()`.
+func cast(spell: String) -> String:
+	return "cast " + spell
+
+static func double(value: int) -> int:
+	return value * 2
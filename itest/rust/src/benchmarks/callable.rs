@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::Variant;
+use godot::builtin::{Array, Variant};
 use godot::prelude::{Callable, RustCallable, varray};
 
 use crate::framework::{BenchResult, bench, bench_measure};
@@ -40,6 +40,21 @@ fn callable_to_string_custom() -> BenchResult {
     bench_measure(1000, || callable.to_string())
 }
 
+#[bench(manual)]
+fn callable_array_filter() -> BenchResult {
+    let array: Array<i64> = (0..1000).collect();
+    let is_even = Callable::from_fn("is_even", |args| args[0].to::<i64>() % 2 == 0);
+
+    bench_measure(25, || array.functional_ops().filter(&is_even))
+}
+
+#[bench(manual)]
+fn callable_array_filter_by() -> BenchResult {
+    let array: Array<i64> = (0..1000).collect();
+
+    bench_measure(25, || array.functional_ops().filter_by(|i| i % 2 == 0))
+}
+
 // Helpers for benchmarks above
 
 #[derive(PartialEq, Hash)]
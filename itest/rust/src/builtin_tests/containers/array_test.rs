@@ -854,6 +854,90 @@ fn array_fops_rfind_custom() {
     assert_eq!(array.functional_ops().rfind_custom(&is_even, None), None);
 }
 
+#[itest]
+fn array_fops_typed() {
+    let array = array![1, 2, 3, 4, 5, 6];
+    let ops = array.functional_ops();
+
+    assert_eq!(ops.filter_by(|i| i % 2 == 0), array![2, 4, 6]);
+    assert_eq!(ops.fold_by(0, |acc, i| acc + i), 21);
+    assert!(ops.any_by(|i| *i > 5));
+    assert!(!ops.all_by(|&i| i > 5));
+
+    let strings: Array<GString> = ops.map_by(|i| GString::from(&format!("#{i}")));
+    assert_eq!(strings.at(2), GString::from("#3"));
+    assert_eq!(strings.len(), 6);
+
+    assert_eq!(ops.find_by(|i| i % 2 == 0, None), Some(1));
+    assert_eq!(ops.find_by(|i| i % 2 == 0, Some(2)), Some(3));
+    assert_eq!(ops.find_by(|&i| i > 10, None), None);
+    assert_eq!(ops.rfind_by(|i| i % 2 == 0, None), Some(5));
+    assert_eq!(ops.rfind_by(|i| i % 2 == 0, Some(4)), Some(3));
+    assert_eq!(ops.rfind_by(|&i| i > 10, None), None);
+
+    let empty = Array::<i64>::new();
+    assert!(empty.functional_ops().map_by(|i| i * 2).is_empty());
+    assert!(empty.functional_ops().all_by(|_| false));
+}
+
+#[itest]
+fn array_extend_typed() {
+    let mut array = array![1, 2];
+    array.extend(vec![3, 4, 5]);
+    array.extend((6..10).filter(|i| i % 2 == 0)); // Unknown size.
+    assert_eq!(array, array![1, 2, 3, 4, 5, 6, 8]);
+
+    let mut strings = Array::<GString>::new();
+    strings.extend(["a", "b"].map(GString::from));
+    assert_eq!(strings, array!["a", "b"]);
+}
+
+/// Iterator which reports a lower bound of 4, but panics or ends after 2 elements.
+struct UnreliableIter {
+    count: i64,
+    panics: bool,
+}
+
+impl Iterator for UnreliableIter {
+    type Item = GString;
+
+    fn next(&mut self) -> Option<GString> {
+        if self.count == 2 {
+            assert!(!self.panics, "iterator panics partway");
+            return None;
+        }
+
+        self.count += 1;
+        Some(GString::from(&self.count.to_string()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (4, None)
+    }
+}
+
+#[itest]
+fn array_extend_typed_unreliable_iter() {
+    let mut strings: Array<GString> = array!["0"];
+    expect_panic("iterator panics while extending", || {
+        strings.extend(UnreliableIter {
+            count: 0,
+            panics: true,
+        });
+    });
+
+    // Unwritten slots are removed again; remaining elements must convert to `GString`.
+    assert_eq!(strings.len(), 3);
+    let elements: Vec<GString> = strings.iter_shared().collect();
+    assert_eq!(elements, ["0", "1", "2"].map(GString::from));
+
+    strings.extend(UnreliableIter {
+        count: 0,
+        panics: false,
+    });
+    assert_eq!(strings, array!["0", "1", "2", "1", "2"]);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions for creating callables.
 
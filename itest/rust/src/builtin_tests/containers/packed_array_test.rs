@@ -442,6 +442,89 @@ fn packed_array_extend_array() {
     test!(u8, i32, GString, Color);
 }
 
+#[itest]
+fn packed_array_edit_push() {
+    fn test<T: Generator>() {
+        // Enough elements to flush the internal buffer several times.
+        let n = 2000;
+
+        let mut array = T::packed_n(2);
+        array.edit(|editor| {
+            assert_eq!(editor.len(), 2);
+            for i in 2..n {
+                editor.push(T::r#gen(i));
+            }
+            assert_eq!(editor.len(), n);
+        });
+
+        assert_eq!(array.to_vec(), T::vec_n(n));
+    }
+
+    test!(u8, i32, GString, Color);
+}
+
+#[itest]
+fn packed_array_edit_bulk() {
+    fn test<T: Generator>() {
+        let mut array = PackedArray::<T>::new();
+        let len = array.edit(|editor| {
+            assert!(editor.is_empty());
+
+            editor.push(T::r#gen(0));
+            editor.extend_from_slice(&[T::r#gen(1), T::r#gen(2)]);
+            editor.extend_with(2, |new| {
+                assert_eq!(new, &[T::default(), T::default()]);
+                new[0] = T::r#gen(3);
+                new[1] = T::r#gen(4);
+            });
+            editor.push(T::r#gen(5));
+            editor.extend((6..8).map(T::r#gen));
+
+            editor.as_mut_slice()[0] = T::r#gen(7);
+            editor.len()
+        });
+
+        let mut expected = T::vec_n(8);
+        expected[0] = T::r#gen(7);
+        assert_eq!(len, 8);
+        assert_eq!(array.to_vec(), expected);
+
+        array.edit(|editor| {
+            editor.push(T::r#gen(0));
+            editor.truncate(3);
+            editor.truncate(5); // No effect.
+            assert_eq!(editor.len(), 3);
+        });
+        assert_eq!(array.to_vec(), expected[..3]);
+    }
+
+    test!(u8, i32, GString, Color);
+}
+
+#[itest]
+fn packed_array_edit_cow() {
+    let original = PackedInt32Array::from([1, 2, 3]);
+    let mut copy = original.clone();
+
+    copy.edit(|editor| {
+        editor.push(4);
+        editor.as_mut_slice()[0] = 10;
+    });
+
+    assert_eq!(original.as_slice(), &[1, 2, 3]);
+    assert_eq!(copy.as_slice(), &[10, 2, 3, 4]);
+}
+
+#[itest]
+fn packed_array_from_boxed_slice() {
+    let boxed: Box<[GString]> = vec![GString::from("a"), GString::from("b")].into_boxed_slice();
+    let array = PackedStringArray::from(boxed);
+    assert_eq!(array.as_slice(), &[GString::from("a"), GString::from("b")]);
+
+    let empty: Box<[Vector3]> = Box::new([]);
+    assert!(PackedArray::from(empty).is_empty());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Slice and view tests
 
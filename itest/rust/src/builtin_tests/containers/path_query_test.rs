@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    Array, GString, VarArray, VarDictionary, Variant, Vector2, array, varray, vdict,
+};
+use godot::meta::ToGodot;
+
+use crate::framework::itest;
+
+fn make_config() -> VarDictionary {
+    vdict! {
+        "graphics": vdict! {
+            "shadows": varray![
+                vdict! { "size": 512 },
+                vdict! { "size": 1024 },
+                vdict! { "size": 2048, "offset": Vector2::new(1.0, 2.0) },
+            ],
+            "vsync": true,
+        },
+        "players": vdict! { 1: "host", 2: "guest" },
+    }
+}
+
+#[itest]
+fn path_get_nested() {
+    let config = make_config();
+
+    assert_eq!(
+        config.get_path::<i64>("graphics/shadows/2/size").ok(),
+        Some(2048)
+    );
+    assert_eq!(config.get_path::<bool>("graphics/vsync").ok(), Some(true));
+    assert_eq!(
+        config.get_path::<Vector2>("graphics/shadows/2/offset").ok(),
+        Some(Vector2::new(1.0, 2.0))
+    );
+
+    // Subnames are further segments.
+    assert_eq!(
+        config.get_path::<i64>("graphics/shadows:0:size").ok(),
+        Some(512)
+    );
+
+    // Numeric segments fall back to integer keys.
+    assert_eq!(
+        config.get_path::<GString>("players/2").ok(),
+        Some("guest".into())
+    );
+
+    let variant = config.to_variant();
+    assert_eq!(
+        variant.get_path::<i64>("graphics/shadows/1/size").ok(),
+        Some(1024)
+    );
+    assert_eq!(
+        variant.get_path::<VarDictionary>("").ok(),
+        Some(config.clone())
+    );
+
+    let shadows = config.get_path::<VarArray>("graphics/shadows").unwrap();
+    assert_eq!(shadows.get_path::<i64>("0/size").ok(), Some(512));
+}
+
+#[itest]
+fn path_get_errors() {
+    let config = make_config();
+
+    let err = config.get_path::<i64>("graphics/missing/size").unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.missing"));
+
+    let err = config
+        .get_path::<i64>("graphics/shadows/7/size")
+        .unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.shadows.7"));
+
+    let err = config
+        .get_path::<i64>("graphics/shadows/first/size")
+        .unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.shadows.first"));
+
+    let err = config.get_path::<i64>("graphics/vsync/inner").unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.vsync.inner"));
+
+    // Conversion of the final value.
+    let err = config.get_path::<i64>("graphics/vsync").unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.vsync"));
+    assert!(err.to_string().starts_with("at `graphics.vsync`"), "{err}");
+}
+
+#[itest]
+fn path_set_creates_containers() {
+    let mut config = make_config();
+
+    config.set_path("graphics/shadows/0/size", 256).unwrap();
+    assert_eq!(
+        config.get_path::<i64>("graphics/shadows/0/size").ok(),
+        Some(256)
+    );
+
+    config.set_path("audio/music/volume", 0.5).unwrap();
+    assert_eq!(config.get_path::<f64>("audio/music/volume").ok(), Some(0.5));
+    assert!(config.get_path::<VarDictionary>("audio").is_ok());
+
+    // Index equal to length appends.
+    config.set_path("graphics/shadows/3/size", 4096).unwrap();
+    assert_eq!(
+        config
+            .get_path::<VarArray>("graphics/shadows")
+            .unwrap()
+            .len(),
+        4
+    );
+    assert_eq!(
+        config.get_path::<i64>("graphics/shadows/3/size").ok(),
+        Some(4096)
+    );
+
+    // Existing integer key is reused.
+    config.set_path("players/1", "admin").unwrap();
+    assert_eq!(
+        config.at("players").get_path::<GString>("1").ok(),
+        Some("admin".into())
+    );
+
+    let mut variant = Variant::nil();
+    variant.set_path("a/b", 3).unwrap();
+    assert_eq!(variant, vdict! { "a": vdict! { "b": 3 } }.to_variant());
+
+    variant.set_path("", "replaced").unwrap();
+    assert_eq!(variant, "replaced".to_variant());
+}
+
+#[itest]
+fn path_set_errors() {
+    let mut config = make_config();
+
+    let err = config.set_path("graphics/shadows/9/size", 1).unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.shadows.9"));
+
+    let err = config.set_path("graphics/vsync/inner", 1).unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("graphics.vsync.inner"));
+
+    let err = config.set_path("", 1).unwrap_err();
+    assert_eq!(err.field_path(), None);
+
+    let mut typed: Array<i64> = array![1, 2, 3];
+    let err = typed.set_path("1", 5).unwrap_err();
+    assert_eq!(err.field_path().as_deref(), Some("1"));
+    assert_eq!(typed, array![1, 2, 3]);
+
+    let mut read_only = varray![1, 2].into_read_only();
+    assert!(read_only.set_path("0", 5).is_err());
+}
@@ -8,7 +8,7 @@
 use godot::builtin::{Callable, Signal};
 use godot::classes::Object;
 use godot::obj::{Base, Gd, NewAlloc};
-use godot::register::{ConnectHandle, GodotClass, godot_api};
+use godot::register::{ConnectHandle, GodotClass, SignalScope, godot_api};
 
 use crate::framework::{expect_panic, expect_panic_or_nothing, itest};
 
@@ -161,6 +161,125 @@ fn test_handle_after_freeing_receiver() {
 
 // Helper functions:
 
+#[itest]
+fn guard_disconnects_on_drop() {
+    let obj = SignalDisc::new_alloc();
+    let mut closure_obj = obj.clone();
+
+    let guard = obj
+        .signals()
+        .my_signal()
+        .builder()
+        .connect_scoped(move || closure_obj.bind_mut().increment_self());
+
+    obj.signals().my_signal().emit();
+    assert_eq!(obj.bind().counter, 1);
+    assert!(guard.is_connected());
+
+    drop(guard);
+    assert!(!has_connections(&obj));
+
+    obj.signals().my_signal().emit();
+    assert_eq!(obj.bind().counter, 1);
+
+    obj.free();
+}
+
+#[itest]
+fn guard_release_keeps_connection() {
+    let obj = SignalDisc::new_alloc();
+
+    let guard = obj
+        .signals()
+        .my_signal()
+        .connect_self(SignalDisc::increment_self)
+        .into_guard();
+
+    let handle = guard.release();
+    assert!(has_connections(&obj));
+
+    obj.signals().my_signal().emit();
+    assert_eq!(obj.bind().counter, 1);
+
+    handle.disconnect();
+    obj.free();
+}
+
+#[itest]
+fn guard_after_freeing_broadcaster() {
+    let obj = SignalDisc::new_alloc();
+    let guard = obj.signals().my_signal().builder().connect_scoped(|| {});
+
+    obj.free();
+    assert!(!guard.is_connected());
+
+    // Must not panic, since the connection is already gone.
+    drop(guard);
+}
+
+#[itest]
+fn signal_scope_disconnects_all() {
+    let broadcaster = SignalDisc::new_alloc();
+    let receiver = SignalDisc::new_alloc();
+
+    let mut scope = SignalScope::new();
+    scope.add(
+        broadcaster
+            .signals()
+            .my_signal()
+            .connect_self(SignalDisc::increment_self),
+    );
+    scope.extend([
+        broadcaster
+            .signals()
+            .my_signal()
+            .connect_other(&receiver, SignalDisc::increment_self),
+        receiver
+            .signals()
+            .my_signal()
+            .connect_self(SignalDisc::increment_self),
+    ]);
+    assert_eq!(scope.len(), 3);
+
+    broadcaster.signals().my_signal().emit();
+    assert_eq!(broadcaster.bind().counter, 1);
+    assert_eq!(receiver.bind().counter, 1);
+
+    scope.disconnect_all();
+    assert!(scope.is_empty());
+    assert!(!has_connections(&broadcaster));
+    assert!(!has_connections(&receiver));
+
+    // Scope can be reused, and disconnects on drop.
+    scope.add(
+        receiver
+            .signals()
+            .my_signal()
+            .connect_self(SignalDisc::increment_self),
+    );
+    drop(scope);
+    assert!(!has_connections(&receiver));
+
+    broadcaster.free();
+    receiver.free();
+}
+
+#[itest]
+fn signal_scope_skips_freed_objects() {
+    let broadcaster = SignalDisc::new_alloc();
+    let other = SignalDisc::new_alloc();
+
+    let mut scope = SignalScope::default();
+    scope.add(broadcaster.signals().my_signal().connect(|| {}));
+    scope.add(other.signals().my_signal().connect(|| {}));
+
+    broadcaster.free();
+    scope.disconnect_all();
+    assert!(!has_connections(&other));
+
+    other.free();
+}
+
 fn test_disconnect(
     connect_to_self: bool,
     handle_function: impl FnOnce(&Gd<SignalDisc>, &Gd<SignalDisc>) -> ConnectHandle,
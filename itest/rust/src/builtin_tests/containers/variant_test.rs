@@ -15,6 +15,8 @@ use godot::builtin::{
     VariantOperator, VariantType, Vector2, Vector2i, Vector3, Vector3i, array, varray, vdict,
     vslice,
 };
+#[cfg(since_api = "4.4")]
+use godot::builtin::{VariantEnum, VariantView};
 use godot::classes::{Node, Node2D, Resource};
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
@@ -724,6 +726,104 @@ fn variant_hash() {
     assert_ne!(vdict! { 0: vdict! { 0: 0 } }, vdict! { 0: vdict! { 0: 1 } });
 }
 
+#[cfg(since_api = "4.4")]
+#[itest]
+fn variant_view() {
+    assert_eq!(Variant::nil().view(), VariantView::Nil);
+    assert_eq!(true.to_variant().view(), VariantView::Bool(true));
+    assert_eq!(42.to_variant().view(), VariantView::Int(42));
+    assert_eq!(2.5.to_variant().view(), VariantView::Float(2.5));
+    assert_eq!(
+        Vector2i::new(1, 2).to_variant().view(),
+        VariantView::Vector2i(Vector2i::new(1, 2))
+    );
+
+    let packed = PackedInt32Array::from([1, 2, 3]);
+    let variant = packed.to_variant();
+    assert_eq!(variant.view(), VariantView::PackedInt32Array(&packed));
+
+    let array = varray![1, "two"];
+    let variant = array.to_variant();
+    let VariantView::Array(viewed) = variant.view() else {
+        panic!("expected array, got {:?}", variant.view());
+    };
+    assert_eq!(viewed.len(), 2);
+    assert_eq!(viewed.at(1), "two".to_variant());
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn variant_view_borrows_string() {
+    let variant = "borrowed".to_variant();
+
+    let VariantView::Str(s) = variant.view() else {
+        panic!("expected string, got {:?}", variant.view());
+    };
+    assert_eq!(*s, gstr("borrowed"));
+
+    // The string is stored inline in the variant, so the reference must point into the variant itself.
+    let variant_start = std::ptr::addr_of!(variant) as usize;
+    let variant_end = variant_start + std::mem::size_of::<Variant>();
+    let string_addr = std::ptr::from_ref(s) as usize;
+    assert!(
+        (variant_start..variant_end).contains(&string_addr),
+        "string reference does not point into the variant"
+    );
+
+    let VariantView::StringName(name) = sname("name").to_variant().view() else {
+        panic!("expected string name");
+    };
+    assert_eq!(name, &sname("name"));
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn variant_view_object() {
+    let node = Node::new_alloc();
+    let variant = node.to_variant();
+
+    let VariantView::Object(Some(obj)) = variant.view() else {
+        panic!("expected live object, got {:?}", variant.view());
+    };
+    assert_eq!(obj.instance_id(), node.instance_id());
+
+    node.free();
+    assert_eq!(variant.view(), VariantView::Object(None));
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn variant_enum_roundtrip() {
+    let values = [
+        Variant::nil(),
+        false.to_variant(),
+        (-7).to_variant(),
+        gstr("text").to_variant(),
+        sname("name").to_variant(),
+        NodePath::from("a/b").to_variant(),
+        Color::from_rgb(0.5, 0.25, 1.0).to_variant(),
+        varray![1, 2].to_variant(),
+        vdict! { "key": 3 }.to_variant(),
+        PackedStringArray::from(&[gstr("x"), gstr("y")]).to_variant(),
+    ];
+
+    for variant in values {
+        let owned = VariantEnum::from(&variant);
+        assert_eq!(owned, variant.view().into_owned());
+        assert_eq!(Variant::from(owned), variant);
+        assert_eq!(variant.view().to_variant(), variant);
+    }
+
+    assert_eq!(
+        Variant::from(VariantEnum::Str(gstr("hello"))),
+        "hello".to_variant()
+    );
+    assert_eq!(
+        VariantEnum::from(&Transform2D::IDENTITY.to_variant()),
+        VariantEnum::Transform2D(Transform2D::IDENTITY)
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn convert_relaxed_to<T, U>(from: T, expected_to: U)
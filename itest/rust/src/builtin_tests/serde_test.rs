@@ -5,7 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i, array};
+use godot::builtin::{
+    AnyDictionary, Array, Color, ColorHsv, Dictionary, GString, NodePath, PackedByteArray,
+    PackedStringArray, PackedVector2Array, StringName, VarDictionary, Variant, Vector2, Vector2i,
+    array, dict, varray, vdict,
+};
+use godot::classes::RefCounted;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
 use serde::{Deserialize, Serialize};
 
 use crate::framework::itest;
@@ -93,3 +100,100 @@ fn color_hsv_serde() {
     let expected_json = r#"{"h":0.0,"s":0.0,"v":0.0,"a":1.0}"#;
     serde_roundtrip(&color, expected_json);
 }
+
+#[itest]
+fn serde_variant_primitives() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&true.to_variant(), r#"{"Bool":true}"#);
+    serde_roundtrip(&42.to_variant(), r#"{"Int":42}"#);
+    serde_roundtrip(&1.5.to_variant(), r#"{"Float":1.5}"#);
+    serde_roundtrip(&"hi".to_variant(), r#"{"String":"hi"}"#);
+    serde_roundtrip(
+        &StringName::from("hi").to_variant(),
+        r#"{"StringName":"hi"}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_builtin() {
+    let value = Vector2::new(1.5, -2.0).to_variant();
+    serde_roundtrip(&value, r#"{"Vector2":{"x":1.5,"y":-2.0}}"#);
+
+    let value = Vector2i::new(3, 4).to_variant();
+    serde_roundtrip(&value, r#"{"Vector2i":{"x":3,"y":4}}"#);
+}
+
+#[itest]
+fn serde_variant_nested_collections() {
+    let value = varray![1, "two", vdict! { "three": 3 }].to_variant();
+    let expected_json =
+        r#"{"Array":[{"Int":1},{"String":"two"},{"Dictionary":[[{"String":"three"},{"Int":3}]]}]}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_variant_unsupported_type() {
+    let object = RefCounted::new_gd().to_variant();
+
+    let err = serde_json::to_string(&object).expect_err("Object must not be serializable");
+    assert!(err.to_string().contains("cannot be serialized"), "{err}");
+}
+
+#[itest]
+fn serde_variant_unknown_tag() {
+    let err = serde_json::from_str::<Variant>(r#"{"Unknown":7}"#).expect_err("unknown tag");
+    assert!(err.to_string().contains("unknown variant"), "{err}");
+}
+
+#[itest]
+fn serde_dictionary_typed() {
+    let value: Dictionary<GString, i64> = dict! { "a": 1, "b": 2 };
+    let expected_json = r#"{"a":1,"b":2}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_dictionary_typed_int_keys() {
+    let value: Dictionary<i64, GString> = dict! { 1: "a", (-2): "b" };
+    let expected_json = r#"[[1,"a"],[-2,"b"]]"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_dictionary_untyped() {
+    let value: VarDictionary = vdict! { "a": 1, (Vector2i::new(1, 2)): "b" };
+    let expected_json =
+        r#"[[{"String":"a"},{"Int":1}],[{"Vector2i":{"x":1,"y":2}},{"String":"b"}]]"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_any_dictionary() {
+    let typed: Dictionary<GString, i64> = dict! { "a": 1 };
+    let value = typed.upcast_any_dictionary();
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"[[{"String":"a"},{"Int":1}]]"#);
+
+    let back: AnyDictionary = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.get("a"), Some(1.to_variant()));
+}
+
+#[itest]
+fn serde_packed_arrays() {
+    let bytes = PackedByteArray::from(&[1, 2, 255]);
+    serde_roundtrip(&bytes, "[1,2,255]");
+
+    let strings = PackedStringArray::from(&["a".into(), "b".into()]);
+    serde_roundtrip(&strings, r#"["a","b"]"#);
+
+    let vectors = PackedVector2Array::from(&[Vector2::new(0.5, 1.0)]);
+    serde_roundtrip(&vectors, r#"[{"x":0.5,"y":1.0}]"#);
+
+    let variant = bytes.to_variant();
+    serde_roundtrip(&variant, r#"{"PackedByteArray":[1,2,255]}"#);
+}
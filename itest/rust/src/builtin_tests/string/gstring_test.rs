@@ -7,7 +7,7 @@
 
 use std::collections::HashSet;
 
-use godot::builtin::{Encoding, GString, PackedStringArray};
+use godot::builtin::{Encoding, GString, GStringBuilder, GStringUtf8, PackedStringArray};
 
 use super::string_test_macros::{APPLE_CHARS, APPLE_STR};
 use crate::framework::{expect_panic_or_nothing, itest};
@@ -262,6 +262,89 @@ fn gstring_pad() {
     assert_eq!(s.pad_zeros(2), "123.456");
 }
 
+#[itest]
+fn gstring_builder() {
+    use std::fmt::Write;
+
+    let mut builder = GStringBuilder::new();
+    assert!(builder.is_empty());
+
+    write!(builder, "{}-{:03}", "level", 7).unwrap();
+    builder.push(' ');
+    builder.push_str(APPLE_STR);
+    builder.push_gstring(&GString::from("!"));
+    builder.push_str("");
+
+    let expected = format!("level-007 {APPLE_STR}!");
+    assert_eq!(builder.len(), expected.chars().count());
+
+    let built = builder.finish();
+    assert_eq!(built, expected.as_str());
+    assert_eq!(built.len(), expected.chars().count());
+    assert_eq!(built.to_string(), expected);
+
+    // Godot functions must see the correct length, i.e. terminator in place and no excess capacity.
+    assert_eq!(built.as_inner().length() as usize, expected.chars().count());
+    assert!(built.ends_with("!"));
+}
+
+#[itest]
+fn gstring_builder_growth() {
+    use std::fmt::Write;
+
+    let mut builder = GStringBuilder::with_capacity(4);
+    let mut expected = String::new();
+    for i in 0..500 {
+        write!(builder, "{i},").unwrap();
+        write!(expected, "{i},").unwrap();
+    }
+
+    assert_eq!(builder.finish(), expected.as_str());
+
+    let mut builder = GStringBuilder::from_gstring(GString::from("abc"));
+    builder.push_str("def");
+    builder.clear();
+    builder.push_str("xyz");
+    assert_eq!(builder.finish(), "xyz");
+
+    assert_eq!(GStringBuilder::new().finish(), GString::new());
+    assert_eq!(GStringBuilder::with_capacity(16).finish(), GString::new());
+}
+
+#[itest]
+fn gstring_builder_does_not_modify_source() {
+    let original = GString::from("shared");
+    let mut builder = GStringBuilder::from_gstring(original.clone());
+    builder.push_str(" and extended");
+
+    assert_eq!(builder.finish(), "shared and extended");
+    assert_eq!(original, "shared");
+}
+
+#[itest]
+fn gstring_utf8() {
+    let string = GStringUtf8::from(GString::from(APPLE_STR));
+    assert_eq!(string.as_str(), APPLE_STR);
+    assert_eq!(string.as_gstring(), &GString::from(APPLE_STR));
+    assert_eq!(string.to_string(), APPLE_STR);
+
+    // Computed once, then reused.
+    let first = string.as_str().as_ptr();
+    assert_eq!(string.as_str().as_ptr(), first);
+
+    let mut map = std::collections::HashMap::new();
+    map.insert(GStringUtf8::from("key"), 1);
+    map.insert(GStringUtf8::from(GString::from("other")), 2);
+    assert_eq!(map.get("key"), Some(&1));
+    assert_eq!(map.get("other"), Some(&2));
+
+    assert_eq!(
+        GStringUtf8::from("same"),
+        GStringUtf8::from(GString::from("same"))
+    );
+    assert_eq!(GString::from(GStringUtf8::from("back")), "back");
+}
+
 // Byte and C-string conversions.
 crate::generate_string_bytes_and_cstr_tests!(
     builtin: GString,
@@ -7,8 +7,10 @@
 
 use std::collections::HashSet;
 
-use godot::builtin::{GString, NodePath};
+use godot::builtin::{GString, NodePath, node_path};
+use godot::classes::Node;
 use godot::meta::wrapped;
+use godot::obj::NewAlloc;
 
 use crate::framework::{expect_panic_or_nothing, itest};
 
@@ -129,6 +131,38 @@ fn node_path_get_subname() {
     })
 }
 
+#[itest]
+fn node_path_macro() {
+    fn get() -> &'static NodePath {
+        node_path!("../Player/Sprite2D:modulate:r")
+    }
+
+    let path = get();
+    assert_eq!(path, &NodePath::from("../Player/Sprite2D:modulate:r"));
+    assert_eq!(path.get_name_count(), 3);
+    assert_eq!(path.get_subname_count(), 2);
+
+    // Same static instance on every evaluation.
+    assert!(std::ptr::eq(path, get()));
+
+    assert_eq!(node_path!("/root").to_string(), "/root");
+    assert_eq!(node_path!(":position").get_subname(0), "position");
+}
+
+#[itest]
+fn node_path_macro_as_arg() {
+    let mut parent = Node::new_alloc();
+    let mut child = Node::new_alloc();
+    child.set_name("Child");
+    parent.add_child(&child);
+
+    let found = parent.get_node_or_null(node_path!("Child"));
+    assert_eq!(found, Some(child));
+    assert!(parent.has_node(node_path!("Child")));
+
+    parent.free();
+}
+
 crate::generate_string_standard_fmt_tests!(
     builtin: NodePath,
     tests: [
@@ -7,7 +7,10 @@
 
 use std::collections::HashSet;
 
-use godot::builtin::{Encoding, GString, NodePath, StringName, static_sname};
+use godot::builtin::{Encoding, GString, NodePath, StringName, sname, static_sname};
+use godot::classes::Object;
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
 
 #[cfg(since_api = "4.5")]
 use super::string_test_macros::{APPLE_CHARS, APPLE_STR};
@@ -167,6 +170,25 @@ fn string_name_static_sname() {
     assert_eq!(a, b);
 }
 
+#[itest]
+fn string_name_sname_macro() {
+    fn get() -> &'static StringName {
+        sname!("cached_name")
+    }
+
+    let a = get();
+    assert_eq!(a, &StringName::from("cached_name"));
+
+    // Same static instance on every evaluation.
+    assert!(std::ptr::eq(a, get()));
+
+    // Usable as AsArg<StringName>.
+    let mut obj = Object::new_alloc();
+    obj.set_meta(sname!("score"), &7.to_variant());
+    assert_eq!(obj.get_meta(sname!("score")), 7.to_variant());
+    obj.free();
+}
+
 #[itest]
 fn string_name_with_null() {
     // Godot always ignores bytes after a null byte.
@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Signal, vslice};
+use godot::prelude::*;
+use godot::register::ErrorToDictionary;
+use godot::task::{self, TaskHandle};
+use godot::tools::collect_class_api;
+
+use crate::framework::{itest, suppress_godot_print, suppress_panic_log};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AsyncFuncObj {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl AsyncFuncObj {
+    #[signal]
+    fn proceed(value: i64);
+
+    #[func(gd_self)]
+    async fn wait_and_double(this: Gd<Self>) -> i64 {
+        let (value,) = this.signals().proceed().to_future().await;
+        value * 2
+    }
+
+    #[func]
+    async fn immediate(text: GString) -> GString {
+        text
+    }
+
+    #[func(on_error = ErrorToDictionary)]
+    async fn parse(text: GString) -> Result<i64, String> {
+        text.to_string()
+            .parse()
+            .map_err(|_| format!("invalid: {text}"))
+    }
+
+    #[func]
+    async fn panicking() -> i64 {
+        panic!("async #[func] panicked on purpose")
+    }
+}
+
+/// Calls an async `#[func]` dynamically, like GDScript does.
+fn call_async(obj: &mut Gd<AsyncFuncObj>, method: &str, args: &[Variant]) -> Signal {
+    obj.call(method, args).to::<Signal>()
+}
+
+#[itest(async)]
+fn async_func_immediate_output() -> TaskHandle {
+    let signal = call_async(&mut AsyncFuncObj::new_gd(), "immediate", vslice!["hello"]);
+
+    // Emission is deferred, so the output is not missed even though the future completed during the call.
+    task::spawn(async move {
+        let (output,): (GString,) = signal.to_future().await;
+        assert_eq!(output, "hello");
+    })
+}
+
+#[itest(async)]
+fn async_func_awaits_signal() -> TaskHandle {
+    let mut obj = AsyncFuncObj::new_gd();
+    let signal = call_async(&mut obj, "wait_and_double", &[]);
+
+    let handle = task::spawn(async move {
+        let (output,): (i64,) = signal.to_future().await;
+        assert_eq!(output, 42);
+    });
+
+    obj.signals().proceed().emit(21);
+    handle
+}
+
+#[itest(async)]
+fn async_func_result_mapping() -> TaskHandle {
+    let mut obj = AsyncFuncObj::new_gd();
+    let ok_signal = call_async(&mut obj, "parse", vslice!["7"]);
+    let err_signal = call_async(&mut obj, "parse", vslice!["seven"]);
+
+    // Both signals are emitted in the same frame, so connect to both before awaiting.
+    let ok_future = ok_signal.to_future::<(VarDictionary,)>();
+    let err_future = err_signal.to_future::<(VarDictionary,)>();
+
+    task::spawn(async move {
+        let (ok,) = ok_future.await;
+        assert_eq!(ok, vdict! { "ok": 7, "err": Variant::nil() });
+
+        let (err,) = err_future.await;
+        assert_eq!(
+            err,
+            vdict! { "ok": Variant::nil(), "err": "invalid: seven" }
+        );
+    })
+}
+
+#[itest(async)]
+fn async_func_panic_emits_null() -> TaskHandle {
+    let mut obj = AsyncFuncObj::new_gd();
+
+    // The future is polled for the first time during the call, which is where it panics.
+    let mut signal = None;
+    suppress_panic_log(|| {
+        suppress_godot_print(|| signal = Some(call_async(&mut obj, "panicking", &[])));
+    });
+    let signal = signal.unwrap();
+
+    task::spawn(async move {
+        let (output,): (Variant,) = signal.to_future().await;
+        assert!(output.is_nil());
+    })
+}
+
+#[itest]
+fn async_func_registered_return_type() {
+    let api = collect_class_api();
+    let class = api.iter().find(|c| c.name == "AsyncFuncObj").unwrap();
+
+    for method in ["wait_and_double", "immediate", "parse", "panicking"] {
+        let method = class.methods.iter().find(|m| m.name == method).unwrap();
+        assert_eq!(method.return_value.type_name, "Signal");
+    }
+}
@@ -5,15 +5,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::RefCell;
 use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use godot::builtin::{Array, Callable, Signal, array, vslice};
-use godot::classes::{Object, RefCounted};
+use godot::classes::{Node, Object, RefCounted};
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{GodotClass, godot_api};
 use godot::task::{self, SignalFuture, TaskHandle, create_test_signal_future_resolver};
 
-use crate::framework::{TestContext, expect_async_panic, itest};
+use crate::framework::{TestContext, expect_async_panic, itest, next_frame, quick_thread};
 
 #[derive(GodotClass)]
 #[class(init)]
@@ -254,3 +258,268 @@ fn async_typed_signal_with_array() -> TaskHandle {
 
     task_handle
 }
+
+#[itest]
+fn spawn_send_resolves_inline() {
+    let object = AsyncRefCounted::new_gd();
+    let future = object.signals().custom_signal().to_future();
+    let result = Arc::new(AtomicU32::new(0));
+
+    let result_copy = result.clone();
+    let handle = task::spawn_send(async move {
+        let (value,) = future.await;
+        result_copy.store(value, Ordering::Relaxed);
+    });
+
+    assert!(handle.is_pending());
+
+    // Task is polled during the emission, not deferred.
+    object.signals().custom_signal().emit(12);
+
+    assert!(!handle.is_pending());
+    assert_eq!(result.load(Ordering::Relaxed), 12);
+}
+
+#[itest]
+fn spawn_send_cancel() {
+    let object = AsyncRefCounted::new_gd();
+    let future = object.signals().custom_signal().to_future();
+
+    let handle = task::spawn_send(async move {
+        future.await;
+        unreachable!();
+    });
+
+    let is_connected = || {
+        !object
+            .get_signal_connection_list("custom_signal")
+            .is_empty()
+    };
+    assert!(is_connected());
+
+    handle.cancel();
+
+    // Dropping the future disconnects it from the signal.
+    assert!(!is_connected());
+    object.signals().custom_signal().emit(12);
+}
+
+#[itest(async)]
+fn spawn_send_cancel_on_worker_thread() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let future = object.signals().custom_signal().to_future();
+
+    let handle = task::spawn_send(async move {
+        future.await;
+        unreachable!();
+    });
+
+    // The future is dropped on the worker thread, which defers disconnecting to the main thread.
+    quick_thread(move || handle.cancel());
+    assert!(
+        !object
+            .get_signal_connection_list("custom_signal")
+            .is_empty()
+    );
+
+    next_frame(move || {
+        assert!(
+            object
+                .get_signal_connection_list("custom_signal")
+                .is_empty()
+        );
+    })
+}
+
+#[itest]
+fn spawn_send_drops_unreachable_task() {
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(dropped.clone());
+
+    // The future never stores its waker, so nothing can wake the task up.
+    let handle = task::spawn_send(async move {
+        let _flag = flag;
+        std::future::pending::<()>().await;
+    });
+
+    assert!(handle.is_pending());
+    assert!(!dropped.load(Ordering::Relaxed));
+
+    drop(handle);
+    assert!(dropped.load(Ordering::Relaxed));
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest]
+fn spawn_send_on_worker_thread() {
+    use crate::framework::ThreadCrosser;
+
+    let object = AsyncRefCounted::new_gd();
+    let future = object.signals().custom_signal().to_future();
+
+    let handle = std::thread::spawn(move || {
+        task::spawn_send(async move {
+            let (value,) = future.await;
+            assert_eq!(value, 34);
+        })
+    })
+    .join()
+    .expect("failed to join thread");
+
+    assert!(handle.is_pending());
+
+    // Emit on yet another thread, which then polls the task.
+    let crosser = ThreadCrosser::new(object.clone());
+    std::thread::spawn(move || {
+        let object = unsafe { crosser.extract() };
+        object.signals().custom_signal().emit(34);
+    })
+    .join()
+    .expect("failed to join thread");
+
+    assert!(!handle.is_pending());
+    assert!(!task::has_send_task_panicked(handle));
+}
+
+#[itest(async)]
+fn async_join_handle_output() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let copy = object.clone();
+
+    let child = task::spawn(async move {
+        let (value,) = copy.signals().custom_signal().to_future().await;
+        value * 2
+    });
+
+    let parent = task::spawn(async move {
+        assert_eq!(child.await, Ok(42));
+    });
+
+    object.signals().custom_signal().emit(21);
+
+    parent
+}
+
+#[itest(async)]
+fn async_join_all_select() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let copy = object.clone();
+
+    let task_handle = task::spawn(async move {
+        let signal = copy.signals().custom_signal();
+
+        let values = task::join_all([signal.to_future(), signal.to_future()]).await;
+        assert_eq!(values, vec![(5,), (5,)]);
+
+        // Losing future is disconnected when select() resolves.
+        let other = AsyncRefCounted::new_gd();
+        let first = signal.to_future();
+        let second = other.signals().custom_signal().to_future();
+        signal.emit(6);
+
+        let ((value,), index) = task::select([first, second]).await;
+        assert_eq!((value, index), (6, 0));
+        assert!(other.get_signal_connection_list("custom_signal").is_empty());
+    });
+
+    object.signals().custom_signal().emit(5);
+
+    task_handle
+}
+
+#[itest]
+fn cancel_async_task_with_children(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree();
+    let signal = Signal::from_object_signal(&tree, "process_frame");
+
+    let child_slot = Rc::new(RefCell::new(None));
+    let child_slot_copy = child_slot.clone();
+
+    let parent = task::spawn(async move {
+        let child_signal = signal.clone();
+        let child = task::spawn(async move {
+            let _: () = child_signal.to_future().await;
+            unreachable!();
+        });
+        *child_slot_copy.borrow_mut() = Some(child);
+
+        let _: () = signal.to_future().await;
+        unreachable!();
+    });
+
+    let child = child_slot.borrow_mut().take().expect("child spawned");
+    assert!(child.is_pending());
+
+    parent.cancel();
+    assert!(!child.is_pending());
+}
+
+#[itest]
+fn spawn_bound_cancel_on_free(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree();
+    let signal = Signal::from_object_signal(&tree, "process_frame");
+
+    let owner = Node::new_alloc();
+    let handle = task::spawn_bound(&owner, async move {
+        let _: () = signal.to_future().await;
+        unreachable!();
+    });
+
+    assert!(handle.is_pending());
+
+    owner.free();
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn spawn_bound_cancel_on_tree_exit(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree();
+    let signal = Signal::from_object_signal(&tree, "process_frame");
+
+    let owner = Node::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&owner);
+
+    let handle = task::spawn_bound(&owner, async move {
+        let _: () = signal.to_future().await;
+        unreachable!();
+    });
+
+    assert!(handle.is_pending());
+
+    parent.remove_child(&owner);
+    assert!(!handle.is_pending());
+
+    // Not connected anymore.
+    assert!(owner.get_signal_connection_list("tree_exiting").is_empty());
+    owner.free();
+}
+
+#[itest(async)]
+fn spawn_bound_completes() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let copy = object.clone();
+    let owner = Node::new_alloc();
+
+    let bound = task::spawn_bound(&owner, async move {
+        let (value,) = copy.signals().custom_signal().to_future().await;
+        value
+    });
+
+    let task_handle = task::spawn(async move {
+        assert_eq!(bound.await, Ok(3));
+        owner.free();
+    });
+
+    object.signals().custom_signal().emit(3);
+
+    task_handle
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::time::Duration;
+
+use godot::builtin::Signal;
+use godot::classes::{Engine, Object, Time};
+use godot::obj::{NewAlloc, Singleton};
+use godot::task::{self, TaskHandle};
+
+use crate::framework::itest;
+
+#[itest(async)]
+fn task_sleep() -> TaskHandle {
+    let start_usec = Time::singleton().get_ticks_usec();
+
+    task::spawn(async move {
+        task::sleep(Duration::from_millis(30)).await;
+
+        let elapsed_usec = Time::singleton().get_ticks_usec() - start_usec;
+        assert!(elapsed_usec >= 30_000, "slept only {elapsed_usec}us");
+    })
+}
+
+#[itest(async)]
+fn task_sleep_zero() -> TaskHandle {
+    let start_frame = Engine::singleton().get_process_frames();
+
+    task::spawn(async move {
+        task::sleep(Duration::ZERO).await;
+
+        // Resolves immediately, without waiting for a frame.
+        assert_eq!(Engine::singleton().get_process_frames(), start_frame);
+    })
+}
+
+#[itest(async)]
+fn task_sleep_drop_unregisters() -> TaskHandle {
+    task::spawn(async move {
+        let timer_count = task::pending_timer_count();
+
+        // Inner sleep registers a timer, then is dropped when the zero timeout elapses.
+        let result = task::timeout(task::sleep(Duration::from_secs(60)), Duration::ZERO).await;
+        assert!(result.is_err());
+
+        assert_eq!(task::pending_timer_count(), timer_count);
+    })
+}
+
+#[itest(async)]
+fn task_next_frame() -> TaskHandle {
+    task::spawn(async move {
+        let start_frame = Engine::singleton().get_process_frames();
+        task::next_frame().await;
+        assert!(Engine::singleton().get_process_frames() > start_frame);
+
+        let start_frame = Engine::singleton().get_physics_frames();
+        task::next_physics_frame().await;
+        assert!(Engine::singleton().get_physics_frames() > start_frame);
+    })
+}
+
+#[itest(async)]
+fn task_timeout() -> TaskHandle {
+    let obj = Object::new_alloc();
+    let signal = Signal::from_object_signal(&obj, "script_changed");
+
+    task::spawn(async move {
+        // Signal is never emitted.
+        let result = task::timeout(signal.to_future::<()>(), Duration::from_millis(10)).await;
+        assert!(result.is_err());
+
+        let result = task::timeout(task::next_frame(), Duration::from_secs(10)).await;
+        assert!(result.is_ok());
+
+        obj.free();
+    })
+}
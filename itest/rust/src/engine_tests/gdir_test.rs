@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{ErrorKind, Read, Write};
+
+use godot::builtin::GString;
+use godot::classes::file_access::ModeFlags;
+use godot::tools::{GDir, GFile};
+
+use crate::framework::itest;
+
+const TEST_DIR: &str = "user://gdir_tests";
+
+fn write_file(path: &str, content: &str) {
+    let mut file = GFile::open(path, ModeFlags::WRITE).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+fn read_file(path: &str) -> String {
+    let mut file = GFile::open(path, ModeFlags::READ).unwrap();
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    content
+}
+
+fn sorted_names(path: &str) -> Vec<(String, bool)> {
+    let mut names: Vec<_> = GDir::read_dir(path)
+        .expect("read_dir")
+        .map(|entry| {
+            let entry = entry.unwrap();
+            (entry.file_name().to_string(), entry.is_dir())
+        })
+        .collect();
+
+    names.sort();
+    names
+}
+
+#[itest]
+fn gdir_create_list_remove() {
+    let nested = format!("{TEST_DIR}/levels/forest");
+    GDir::create_dir_all(&nested).expect("create_dir_all");
+    GDir::create_dir_all(&nested).expect("create_dir_all is idempotent");
+    assert!(GDir::exists(&nested));
+
+    write_file(&format!("{TEST_DIR}/levels/intro.txt"), "intro");
+    write_file(&format!("{nested}/map.txt"), "map");
+
+    let levels = format!("{TEST_DIR}/levels");
+    assert_eq!(
+        sorted_names(&levels),
+        [
+            ("forest".to_string(), true),
+            ("intro.txt".to_string(), false)
+        ]
+    );
+
+    let entry = GDir::read_dir(&levels)
+        .unwrap()
+        .map(Result::unwrap)
+        .find(|entry| entry.is_file())
+        .unwrap();
+    assert_eq!(entry.path(), GString::from(&format!("{levels}/intro.txt")));
+
+    let err = GDir::remove(&levels).expect_err("non-empty directory");
+    assert_ne!(err.kind(), ErrorKind::NotFound);
+
+    GDir::remove(&format!("{nested}/map.txt")).unwrap();
+    GDir::remove(&nested).unwrap();
+    GDir::remove(&format!("{levels}/intro.txt")).unwrap();
+    GDir::remove(&levels).unwrap();
+    GDir::remove(TEST_DIR).unwrap();
+
+    assert!(!GDir::exists(TEST_DIR));
+}
+
+#[itest]
+fn gdir_copy_rename() {
+    GDir::create_dir_all(TEST_DIR).unwrap();
+
+    let original = format!("{TEST_DIR}/original.txt");
+    let copied = format!("{TEST_DIR}/copied.txt");
+    let renamed = format!("{TEST_DIR}/renamed.txt");
+
+    write_file(&original, "content");
+    GDir::copy(&original, &copied).expect("copy");
+    GDir::rename(&original, &renamed).expect("rename");
+
+    assert_eq!(read_file(&copied), "content");
+    assert_eq!(read_file(&renamed), "content");
+    assert_eq!(
+        sorted_names(TEST_DIR),
+        [
+            ("copied.txt".to_string(), false),
+            ("renamed.txt".to_string(), false)
+        ]
+    );
+
+    GDir::remove(&copied).unwrap();
+    GDir::remove(&renamed).unwrap();
+    GDir::remove(TEST_DIR).unwrap();
+}
+
+#[itest]
+fn gdir_errors() {
+    let missing = format!("{TEST_DIR}/does_not_exist");
+
+    let err = GDir::open(&missing).err().expect("open missing directory");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    let err = GDir::read_dir(&missing)
+        .err()
+        .expect("list missing directory");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    let err = GDir::remove(&missing).expect_err("remove missing path");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
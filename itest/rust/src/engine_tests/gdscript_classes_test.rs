@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::gdscript_classes;
+
+use crate::framework::itest;
+
+// Wrapper for itest/godot/ScriptWrapperFixture.gd.
+gdscript_classes!(project = "../godot", classes = [ScriptWrapperFixture]);
+
+#[itest]
+fn gdscript_wrapper_instantiate_and_call() {
+    let mut fixture = ScriptWrapperFixture::instantiate(10);
+    assert_eq!(fixture.get_points(), 10);
+
+    assert_eq!(fixture.add(5), 15);
+    assert_eq!(fixture.get_points(), 15);
+
+    // Static function.
+    assert_eq!(ScriptWrapperFixture::double(21), 42);
+}
+
+#[itest]
+fn gdscript_wrapper_properties() {
+    let mut fixture = ScriptWrapperFixture::instantiate(0);
+    assert_eq!(fixture.get_label(), "none");
+
+    fixture.set_label("custom".into());
+    assert_eq!(fixture.get_label(), "custom");
+
+    // Wrapper derefs to the native base.
+    assert_eq!(fixture.get("label"), "custom".to_variant());
+}
+
+#[itest]
+fn gdscript_wrapper_renamed_method() {
+    let mut fixture = ScriptWrapperFixture::instantiate(0);
+
+    // Script function `cast()` collides with the generated `cast()`.
+    assert_eq!(fixture.script_cast("fire".into()), "cast fire");
+}
+
+#[itest]
+fn gdscript_wrapper_cast() {
+    let fixture = ScriptWrapperFixture::instantiate(3);
+
+    let object = fixture.into_object();
+    let fixture = ScriptWrapperFixture::try_cast(object).expect("object has fixture script");
+    assert_eq!(fixture.get_points(), 3);
+
+    let plain = RefCounted::new_gd();
+    let plain = ScriptWrapperFixture::try_cast(plain).expect_err("object has no script");
+    assert_eq!(plain.get_script(), None);
+}
+
+#[itest]
+fn gdscript_wrapper_signal() {
+    let mut fixture = ScriptWrapperFixture::instantiate(0);
+    assert_eq!(fixture.get_last_scored(), -1);
+
+    // The script connects `scored` in `_init()`.
+    assert_eq!(fixture.signal_scored().connections().len(), 1);
+
+    let error = fixture.emit_scored(7);
+    assert_eq!(error, godot::global::Error::OK);
+    assert_eq!(fixture.get_last_scored(), 7);
+}
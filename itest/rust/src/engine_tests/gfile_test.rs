@@ -161,3 +161,32 @@ fn bufread_trait_works() {
     drop(file);
     remove_test_file();
 }
+
+#[itest]
+fn bufread_interleaved_with_seek_and_write() {
+    let mut file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE_READ).unwrap();
+
+    file.write_gstring("alpha\nbeta\ngamma\n")
+        .expect("couldn't write to file");
+    file.rewind().unwrap();
+
+    let mut line = String::new();
+    file.read_line(&mut line).unwrap();
+    assert_eq!(line, "alpha\n");
+    assert_eq!(file.position(), 6, "cursor must match consumed bytes");
+
+    // Seek back into the buffered region.
+    file.seek(SeekFrom::Start(2)).unwrap();
+    line.clear();
+    file.read_line(&mut line).unwrap();
+    assert_eq!(line, "pha\n");
+
+    // Overwrite part of the buffered region; subsequent reads must see the new bytes.
+    file.write_all(b"BETA").unwrap();
+    file.seek(SeekFrom::Start(6)).unwrap();
+    let lines: Vec<String> = file.lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["BETA", "gamma"]);
+
+    drop(file);
+    remove_test_file();
+}
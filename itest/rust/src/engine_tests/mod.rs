@@ -5,12 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod async_func_test;
 mod async_test;
+#[cfg(since_api = "4.5")]
+mod async_timer_test;
 mod autoload_test;
 mod classdb_test;
 mod codegen_enums_test;
 mod codegen_test;
 mod engine_enum_test;
+mod gdir_test;
+mod gdscript_classes_test;
 mod gfile_test;
 mod match_class_test;
 mod native_st_niche_audio_test;
@@ -267,7 +267,6 @@ pub fn expect_async_panic<T: std::future::Future>(
 ///
 /// Useful for assertions that run expect a `call_deferred()` or similar operation, and still want to check the result.
 #[must_use]
-#[allow(dead_code)] // not yet used.
 pub fn next_frame<F>(code: F) -> godot::task::TaskHandle
 where
     F: FnOnce() + 'static,
@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::meta::error::BorrowKind;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base = RefCounted)]
+struct BorrowedObj {
+    base: Base<RefCounted>,
+    value: i32,
+    observed_kinds: Vec<BorrowKind>,
+}
+
+#[godot_api]
+impl BorrowedObj {
+    #[func]
+    fn observe_borrows(&mut self) {
+        let gd = self.to_gd();
+
+        // Releases the exclusive borrow of this call, so the instance can be inspected re-entrantly.
+        let base = self.base_mut();
+        let kinds = gd.live_borrows().iter().map(|b| b.kind()).collect();
+        drop(base);
+
+        self.observed_kinds = kinds;
+    }
+}
+
+#[itest]
+fn try_bind_succeeds_when_unbound() {
+    let mut obj = BorrowedObj::new_gd();
+
+    obj.try_bind_mut().expect("no other borrows").value = 7;
+
+    let a = obj.try_bind().expect("no exclusive borrow");
+    let b = obj.try_bind().expect("shared borrows can coexist");
+    assert_eq!(a.value, 7);
+    assert_eq!(b.value, 7);
+}
+
+#[itest]
+fn try_bind_fails_while_bound_mut() {
+    let obj = BorrowedObj::new_gd();
+    let mut other = obj.clone();
+
+    let guard = other.bind_mut();
+    let err = obj.try_bind().expect_err("exclusively bound");
+    assert_eq!(err.requested_kind(), BorrowKind::Shared);
+    assert!(err.to_string().contains("Gd<T>::bind() failed"));
+    drop(guard);
+
+    assert!(obj.try_bind().is_ok());
+}
+
+#[itest]
+fn try_bind_mut_fails_while_bound() {
+    let obj = BorrowedObj::new_gd();
+    let mut other = obj.clone();
+
+    let guard = obj.bind();
+    let err = other.try_bind_mut().expect_err("shared bound");
+    assert_eq!(err.requested_kind(), BorrowKind::Exclusive);
+    assert!(err.to_string().contains("Gd<T>::bind_mut() failed"));
+
+    if cfg!(safeguards_strict) {
+        let kinds: Vec<_> = err.live_borrows().iter().map(|b| b.kind()).collect();
+        assert_eq!(kinds, [BorrowKind::Shared]);
+    } else {
+        assert!(err.live_borrows().is_empty());
+    }
+    drop(guard);
+
+    assert!(other.try_bind_mut().is_ok());
+}
+
+#[cfg(safeguards_strict)]
+#[itest]
+fn live_borrows_tracks_all_guards() {
+    let obj = BorrowedObj::new_gd();
+    assert!(obj.live_borrows().is_empty());
+
+    let a = obj.bind();
+    let b = obj.bind();
+    let borrows = obj.live_borrows();
+    assert_eq!(borrows.len(), 2);
+    assert!(
+        borrows
+            .iter()
+            .all(|borrow| borrow.kind() == BorrowKind::Shared)
+    );
+    assert!(
+        borrows
+            .iter()
+            .all(|borrow| borrow.thread_id() == std::thread::current().id())
+    );
+    assert!(obj.live_borrows_report().starts_with("2 live borrow(s)"));
+
+    drop(a);
+    assert_eq!(obj.live_borrows().len(), 1);
+
+    drop(b);
+    assert!(obj.live_borrows().is_empty());
+}
+
+#[cfg(safeguards_strict)]
+#[itest]
+fn live_borrows_tracks_inaccessible() {
+    let mut obj = BorrowedObj::new_gd();
+    obj.call("observe_borrows", &[]);
+
+    assert_eq!(
+        obj.bind().observed_kinds,
+        [BorrowKind::Exclusive, BorrowKind::Inaccessible]
+    );
+    assert!(obj.live_borrows().is_empty());
+}
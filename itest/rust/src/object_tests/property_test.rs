@@ -5,9 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 use godot::builtin::{
-    Color, GString, PackedInt32Array, VarDictionary, Variant, VariantType, vdict, vslice,
+    Array, Color, GString, NodePath, PackedInt32Array, VarDictionary, Variant, VariantType, vdict,
+    vslice,
 };
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource};
+use godot::classes::{INode, IRefCounted, Node, Node2D, Node3D, Object, RefCounted, Resource};
 use godot::global::{PropertyHint, PropertyUsageFlags};
 use godot::init::GdextBuild;
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
@@ -521,6 +522,167 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP);
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportParity {
+    #[export_category(name = "Stats")]
+    #[export]
+    health: i32,
+
+    #[export_group(name = "Movement", prefix = "move_")]
+    #[export_subgroup(name = "Air/Jump", prefix = "move_jump_")]
+    #[export]
+    move_jump_height: f32,
+
+    #[export_group(name = "")]
+    #[export(node_path = (Node2D, Node3D))]
+    target: NodePath,
+
+    #[export(custom(hint = ENUM_SUGGESTION, hint_string = "Low,High", usage = [DEFAULT, READ_ONLY]))]
+    quality: GString,
+
+    #[export(elements, range = (0.0, 100.0))]
+    percentages: Array<i64>,
+}
+
+#[itest]
+fn export_parity() {
+    let class = ExportParity::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property {name} not found"))
+    };
+
+    let property = find_property("Stats");
+    check_property(&property, "usage", PropertyUsageFlags::CATEGORY);
+
+    let property = find_property("Air/Jump");
+    check_property(&property, "usage", PropertyUsageFlags::SUBGROUP);
+    check_property(&property, "hint_string", "move_jump_");
+
+    let property = find_property("target");
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES);
+    check_property(&property, "hint_string", "Node2D,Node3D");
+
+    let property = find_property("quality");
+    check_property(&property, "hint", PropertyHint::ENUM_SUGGESTION);
+    check_property(&property, "hint_string", "Low,High");
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::DEFAULT | PropertyUsageFlags::READ_ONLY,
+    );
+
+    let property = find_property("percentages");
+    check_property(&property, "hint", PropertyHint::TYPE_STRING);
+    check_property(&property, "hint_string", "2/1:0,100");
+
+    class.free();
+}
+
+#[cfg(since_api = "4.3")]
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportPackedElements {
+    #[export(elements, enum = (Low, High))]
+    levels: godot::builtin::PackedStringArray,
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn export_packed_array_elements() {
+    let class = ExportPackedElements::new_gd();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "levels".to_variant())
+        .unwrap();
+
+    check_property(&property, "hint", PropertyHint::TYPE_STRING);
+    check_property(&property, "hint_string", "4/2:Low,High");
+}
+
+#[cfg(since_api = "4.5")]
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportFilePath {
+    #[export(file_path = "*.png")]
+    image: GString,
+}
+
+#[cfg(since_api = "4.5")]
+#[itest]
+fn export_file_path() {
+    let class = ExportFilePath::new_gd();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "image".to_variant())
+        .unwrap();
+
+    check_property(&property, "hint", PropertyHint::FILE_PATH);
+    check_property(&property, "hint_string", "*.png");
+}
+
+// The Inspector integration only runs in the editor; here we check that the macro compiles and the property is registered as usual.
+#[cfg(feature = "codegen-full")]
+#[derive(GodotClass)]
+#[class(tool, init, base=Label)]
+struct HealthEditor {
+    base: Base<godot::classes::Label>,
+}
+
+#[cfg(feature = "codegen-full")]
+impl godot::tools::PropertyEditor for HealthEditor {
+    type Value = i32;
+
+    fn update_value(&mut self, value: i32) {
+        self.base_mut().set_text(&format!("{value} HP"));
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportEditor {
+    #[export(editor = HealthEditor, range = (0.0, 100.0))]
+    health: i32,
+
+    #[var(rename = renamed_health)]
+    #[export(editor = HealthEditor)]
+    other_health: i32,
+}
+
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn export_editor() {
+    let mut class = ExportEditor::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property {name} not found"))
+    };
+
+    let property = find_property("health");
+    check_property(&property, "hint", PropertyHint::RANGE);
+    check_property(&property, "hint_string", "0,100");
+    check_property(&property, "usage", PropertyUsageFlags::DEFAULT);
+
+    find_property("renamed_health");
+
+    class.set("health", &42.to_variant());
+    assert_eq!(class.bind().health, 42);
+
+    class.free();
+}
+
 fn check_property(property: &VarDictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -0,0 +1,106 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::{ClassApi, class_api_to_json, collect_class_api};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ApiExportObj {
+    #[var]
+    health: i64,
+
+    #[var]
+    targets: Array<Gd<Node>>,
+}
+
+#[godot_api]
+impl ApiExportObj {
+    #[constant]
+    const MAX_HEALTH: i64 = 100;
+
+    #[signal]
+    fn damaged(amount: i64, source: Gd<Node>);
+
+    #[func]
+    fn heal(&mut self, amount: i64, #[opt(default = 1)] times: i64) -> i64 {
+        self.health += amount * times;
+        self.health
+    }
+
+    #[func]
+    fn create() -> Gd<Self> {
+        Self::new_alloc()
+    }
+}
+
+fn find_api() -> ClassApi {
+    collect_class_api()
+        .into_iter()
+        .find(|class| class.name == "ApiExportObj")
+        .expect("ApiExportObj is registered")
+}
+
+#[itest]
+fn class_api_collect() {
+    let api = find_api();
+    assert_eq!(api.base, "Node");
+
+    let heal = api.methods.iter().find(|m| m.name == "heal").unwrap();
+    let params: Vec<_> = heal
+        .params
+        .iter()
+        .map(|p| (p.name.as_str(), p.type_name.as_str()))
+        .collect();
+    assert_eq!(params, [("amount", "int"), ("times", "int")]);
+    assert_eq!(heal.default_args, ["1"]);
+    assert_eq!(heal.return_value.type_name, "int");
+    assert!(!heal.is_static);
+
+    let create = api.methods.iter().find(|m| m.name == "create").unwrap();
+    assert!(create.is_static);
+    assert_eq!(create.return_value.type_name, "ApiExportObj");
+
+    let properties: Vec<_> = api
+        .properties
+        .iter()
+        .map(|p| (p.name.as_str(), p.type_name.as_str()))
+        .collect();
+    assert_eq!(properties, [("health", "int"), ("targets", "Array[Node]")]);
+
+    let damaged = api.signals.iter().find(|s| s.name == "damaged").unwrap();
+    assert_eq!(damaged.params[1].type_name, "Node");
+
+    assert_eq!(api.constants[0].name, "MAX_HEALTH");
+    assert_eq!(api.constants[0].value, 100);
+}
+
+#[itest]
+fn class_api_gdscript_stub() {
+    let stub = find_api().to_gdscript_stub();
+
+    assert!(stub.contains("class_name ApiExportObj\nextends Node\n"));
+    assert!(stub.contains("signal damaged(amount: int, source: Node)"));
+    assert!(stub.contains("const MAX_HEALTH = 100"));
+    assert!(stub.contains("var targets: Array[Node]"));
+    assert!(stub.contains("func heal(amount: int, times: int = 1) -> int:\n\treturn int()"));
+    assert!(stub.contains("static func create() -> ApiExportObj:\n\treturn null"));
+}
+
+#[itest]
+fn class_api_json() {
+    let json = class_api_to_json(&[find_api()]);
+
+    assert!(json.starts_with(
+        "{\n  \"classes\": [\n    {\n      \"name\": \"ApiExportObj\",\n      \"base\": \"Node\","
+    ));
+    assert!(json.contains(r#"{"name": "health", "type": "int"}"#));
+    assert!(json.contains(r#"{"name": "MAX_HEALTH", "value": 100}"#));
+    assert!(json.ends_with("]\n}\n"));
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::vslice;
+use godot::classes::ClassDb;
+use godot::obj::Singleton;
+use godot::prelude::*;
+use godot::register::ClassBuilder;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct BuilderObj {
+    value: i64,
+}
+
+#[godot_api]
+impl BuilderObj {
+    #[func]
+    fn func_from_macro(&self) -> i64 {
+        self.value * 10
+    }
+}
+
+#[godot_api]
+impl IRefCounted for BuilderObj {
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        // Names as they could come from a data file.
+        let getter = String::from("get_value");
+
+        builder
+            .method(&getter, |this: &mut Self, (): ()| this.value)
+            .done();
+
+        builder
+            .method("set_value", |this: &mut Self, (value,): (i64,)| {
+                this.value = value
+            })
+            .param_names(&["value"])
+            .done();
+
+        builder
+            .method("add", |this: &mut Self, (a, b): (i64, i64)| {
+                this.value + a + b
+            })
+            .param_names(&["a", "b"])
+            .default_args(vec![7.to_variant()])
+            .done();
+
+        builder
+            .static_method("concat", |(a, b): (GString, GString)| format!("{a}{b}"))
+            .done();
+
+        builder
+            .varargs_method("count_args", |_this: &mut Self, args: &[&Variant]| {
+                (args.len() as i64).to_variant()
+            })
+            .done();
+
+        builder.group("Stats", "");
+        builder
+            .property::<i64>("value")
+            .getter("get_value")
+            .setter("set_value")
+            .export::<i64>()
+            .done();
+
+        builder
+            .property::<i64>("macro_value")
+            .getter("func_from_macro")
+            .done();
+
+        builder
+            .signal("value_changed")
+            .param::<i64>("new_value")
+            .done();
+
+        builder.constant("MAX_VALUE", 100);
+        builder.enum_constants("Mode", &[("MODE_A", 0), ("MODE_B", 1)]);
+    }
+}
+
+#[itest]
+fn class_builder_methods() {
+    let mut obj = BuilderObj::new_gd();
+
+    obj.call("set_value", vslice![5]);
+    assert_eq!(obj.bind().value, 5);
+    assert_eq!(obj.call("get_value", &[]), 5.to_variant());
+
+    assert_eq!(obj.call("add", vslice![1, 2]), 8.to_variant());
+    assert_eq!(obj.call("add", vslice![1]), 13.to_variant());
+
+    assert_eq!(
+        obj.call("count_args", vslice![1, "two", 3.0]),
+        3.to_variant()
+    );
+    assert_eq!(obj.call("count_args", &[]), 0.to_variant());
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn class_builder_static_method() {
+    let concat = ClassDb::singleton().class_call_static(
+        &BuilderObj::class_id().to_string_name(),
+        "concat",
+        vslice!["ab", "cd"],
+    );
+    assert_eq!(concat, "abcd".to_variant());
+}
+
+#[itest]
+fn class_builder_properties() {
+    let mut obj = BuilderObj::new_gd();
+
+    obj.set("value", &42.to_variant());
+    assert_eq!(obj.bind().value, 42);
+    assert_eq!(obj.get("value"), 42.to_variant());
+
+    // Property backed by #[func] getter.
+    assert_eq!(obj.get("macro_value"), 420.to_variant());
+}
+
+#[itest]
+fn class_builder_signals_constants() {
+    let class_name = BuilderObj::class_id().to_string_name();
+    let db = ClassDb::singleton();
+
+    assert!(db.class_has_signal(&class_name, "value_changed"));
+    assert_eq!(db.class_get_integer_constant(&class_name, "MAX_VALUE"), 100);
+    assert_eq!(db.class_get_integer_constant(&class_name, "MODE_B"), 1);
+    assert!(db.class_has_enum(&class_name, "Mode"));
+}
@@ -7,8 +7,8 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::{GString, Vector2};
-use godot::meta::ToGodot;
+use godot::builtin::{GString, VarArray, VarDictionary, Vector2, varray, vdict};
+use godot::meta::{FromGodot, ToGodot};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -148,3 +148,206 @@ test_inty!(i64, test_enum_i64, EnumI64);
 test_inty!(u8, test_enum_u8, EnumU8);
 test_inty!(u16, test_enum_u16, EnumU16);
 test_inty!(u32, test_enum_u32, EnumU32);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Structs converted via Dictionary
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+struct DictStats {
+    health: i64,
+    #[godot(rename = "max_hp")]
+    max_health: i64,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+struct DictPlayer {
+    name: GString,
+    position: Vector2,
+    stats: DictStats,
+    #[godot(default)]
+    level: i32,
+    #[godot(default = 1.5)]
+    speed: f32,
+    #[godot(skip)]
+    cached_score: u32,
+}
+
+fn make_player() -> DictPlayer {
+    DictPlayer {
+        name: "Ferris".into(),
+        position: Vector2::new(1.0, 2.0),
+        stats: DictStats {
+            health: 80,
+            max_health: 100,
+        },
+        level: 3,
+        speed: 2.0,
+        cached_score: 0,
+    }
+}
+
+#[itest]
+fn dictionary_struct_roundtrip() {
+    roundtrip(make_player());
+
+    let dict = make_player().to_godot();
+    assert_eq!(dict.len(), 5, "skipped field must not be written");
+    assert_eq!(dict.at("name"), "Ferris".to_variant());
+    assert_eq!(
+        dict.at("stats"),
+        vdict! { "health": 80, "max_hp": 100 }.to_variant()
+    );
+}
+
+#[itest]
+fn dictionary_struct_defaults() {
+    let dict = vdict! {
+        "name": "Ferris",
+        "position": Vector2::new(1.0, 2.0),
+        "stats": vdict! { "health": 80, "max_hp": 100 },
+        "unknown_key": "ignored",
+    };
+
+    let player = DictPlayer::try_from_godot(dict).expect("missing default fields");
+    assert_eq!(player.level, 0);
+    assert_eq!(player.speed, 1.5);
+    assert_eq!(player.cached_score, 0);
+}
+
+#[itest]
+fn dictionary_struct_errors() {
+    let missing = vdict! { "health": 80 };
+    let err = DictStats::try_from_godot(missing).expect_err("missing key must fail");
+    assert_eq!(err.field_path().as_deref(), Some("max_hp"));
+
+    // Errors in nested structs report the full path.
+    let nested = vdict! {
+        "name": "Ferris",
+        "position": Vector2::ZERO,
+        "stats": vdict! { "health": "full", "max_hp": 100 },
+    };
+    let err = DictPlayer::try_from_godot(nested).expect_err("wrong type must fail");
+    assert_eq!(err.field_path().as_deref(), Some("stats.health"));
+    assert!(err.to_string().starts_with("at `stats.health`: "), "{err}");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Enums carrying data
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+enum DictCommand {
+    Move(Vector2),
+    Say {
+        text: GString,
+        #[godot(default)]
+        loud: bool,
+    },
+    #[godot(rename = "halt")]
+    Stop,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Dictionary, tag = "kind")]
+enum DictCustomTag {
+    Jump { height: f32 },
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = Array)]
+enum ArrayEvent {
+    Damage(i64, GString),
+    Heal {
+        amount: i64,
+        #[godot(skip)]
+        applied: bool,
+        #[godot(default = 1)]
+        stacks: i32,
+    },
+    Reset,
+}
+
+#[itest]
+fn data_enum_dictionary_roundtrip() {
+    roundtrip(DictCommand::Move(Vector2::new(1.0, 2.0)));
+    roundtrip(DictCommand::Say {
+        text: "hello".into(),
+        loud: true,
+    });
+    roundtrip(DictCommand::Stop);
+    roundtrip(DictCustomTag::Jump { height: 2.5 });
+
+    let dict: VarDictionary = DictCommand::Move(Vector2::new(1.0, 2.0)).to_godot();
+    assert_eq!(dict, vdict! { "type": "Move", "0": Vector2::new(1.0, 2.0) });
+
+    let dict: VarDictionary = DictCommand::Stop.to_godot();
+    assert_eq!(dict, vdict! { "type": "halt" });
+
+    let dict: VarDictionary = DictCustomTag::Jump { height: 2.5 }.to_godot();
+    assert_eq!(dict, vdict! { "kind": "Jump", "height": 2.5 });
+
+    let say = DictCommand::try_from_godot(vdict! { "type": "Say", "text": "hi" });
+    assert_eq!(
+        say.unwrap(),
+        DictCommand::Say {
+            text: "hi".into(),
+            loud: false
+        }
+    );
+}
+
+#[itest]
+fn data_enum_array_roundtrip() {
+    roundtrip(ArrayEvent::Damage(10, "fire".into()));
+    roundtrip(ArrayEvent::Reset);
+
+    let array: VarArray = ArrayEvent::Damage(10, "fire".into()).to_godot();
+    assert_eq!(array, varray!["Damage", 10, "fire"]);
+
+    let heal = ArrayEvent::Heal {
+        amount: 5,
+        applied: true,
+        stacks: 3,
+    };
+    let array: VarArray = heal.to_godot();
+    assert_eq!(
+        array,
+        varray!["Heal", 5, 3],
+        "skipped field must not be written"
+    );
+
+    let back = ArrayEvent::try_from_godot(varray!["Heal", 5]).unwrap();
+    assert_eq!(
+        back,
+        ArrayEvent::Heal {
+            amount: 5,
+            applied: false,
+            stacks: 1
+        }
+    );
+}
+
+#[itest]
+fn data_enum_errors() {
+    let err = DictCommand::try_from_godot(vdict! { "type": "Fly" }).expect_err("unknown tag");
+    assert!(
+        err.to_string()
+            .starts_with("invalid DictCommand variant tag, expected one of: Move, Say, halt"),
+        "{err}"
+    );
+
+    let err = DictCommand::try_from_godot(vdict! { "0": 1 }).expect_err("missing tag");
+    assert_eq!(err.field_path().as_deref(), Some("type"));
+
+    let err = DictCommand::try_from_godot(vdict! { "type": "Move", "0": "north" })
+        .expect_err("wrong payload type");
+    assert_eq!(err.field_path().as_deref(), Some("0"));
+
+    let err = ArrayEvent::try_from_godot(varray!["Damage", 10]).expect_err("array too short");
+    assert_eq!(err.field_path().as_deref(), Some("2"));
+
+    let err = ArrayEvent::try_from_godot(VarArray::new()).expect_err("empty array");
+    assert_eq!(err.field_path().as_deref(), Some("0"));
+}
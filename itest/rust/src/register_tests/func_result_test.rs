@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::vslice;
+use godot::global::Error;
+use godot::prelude::*;
+use godot::register::{ErrorMapping, ErrorToCode, ErrorToDictionary, ErrorToNull};
+use godot::tools::collect_class_api;
+
+use crate::framework::{expect_panic, itest, suppress_godot_print};
+
+/// Custom strategy: errors become `-1`.
+struct ErrorToMinusOne;
+
+impl<E> ErrorMapping<i64, E> for ErrorToMinusOne {
+    type Output = i64;
+
+    fn map_result(result: Result<i64, E>) -> i64 {
+        result.unwrap_or(-1)
+    }
+}
+
+type ParseResult = Result<i64, String>;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncResultObj;
+
+#[godot_api(on_error = ErrorToNull)]
+impl FuncResultObj {
+    #[func]
+    fn parse_or_null(&self, text: GString) -> Result<i64, String> {
+        parse(text)
+    }
+
+    #[func(on_error = ErrorToDictionary)]
+    fn parse_to_dict(text: GString) -> Result<i64, String> {
+        parse(text)
+    }
+
+    #[func(on_error = ErrorToCode)]
+    fn check_positive(&self, value: i64) -> Result<(), Error> {
+        if value > 0 {
+            Ok(())
+        } else {
+            Err(Error::ERR_INVALID_PARAMETER)
+        }
+    }
+
+    // Alias not recognized as `Result`, thus explicit strategy.
+    #[func(on_error = ErrorToMinusOne)]
+    fn parse_or_minus_one(&self, text: GString) -> ParseResult {
+        parse(text)
+    }
+
+    #[func]
+    fn plain(&self) -> i64 {
+        7
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncResultPanicObj;
+
+#[godot_api]
+impl FuncResultPanicObj {
+    #[func]
+    fn parse_or_fail(&self, text: GString) -> Result<i64, String> {
+        parse(text)
+    }
+}
+
+fn parse(text: GString) -> Result<i64, String> {
+    text.to_string()
+        .parse()
+        .map_err(|_| format!("not a number: {text}"))
+}
+
+#[itest]
+fn func_result_error_to_null() {
+    let mut obj = FuncResultObj::new_gd();
+
+    let ok = obj.call("parse_or_null", vslice!["12"]);
+    assert_eq!(ok, 12.to_variant());
+
+    let mut err = Variant::nil();
+    suppress_godot_print(|| err = obj.call("parse_or_null", vslice!["abc"]));
+    assert!(err.is_nil());
+}
+
+#[itest]
+fn func_result_error_to_dictionary() {
+    let ok = FuncResultObj::new_gd().call("parse_to_dict", vslice!["5"]);
+    assert_eq!(ok, vdict! { "ok": 5, "err": Variant::nil() }.to_variant());
+
+    let err = FuncResultObj::new_gd().call("parse_to_dict", vslice!["x"]);
+    assert_eq!(
+        err,
+        vdict! { "ok": Variant::nil(), "err": "not a number: x" }.to_variant()
+    );
+}
+
+#[itest]
+fn func_result_error_to_code() {
+    let mut obj = FuncResultObj::new_gd();
+
+    let ok = obj.call("check_positive", vslice![1]);
+    assert_eq!(ok.to::<Error>(), Error::OK);
+
+    let err = obj.call("check_positive", vslice![-1]);
+    assert_eq!(err.to::<Error>(), Error::ERR_INVALID_PARAMETER);
+}
+
+#[itest]
+fn func_result_custom_mapping() {
+    let mut obj = FuncResultObj::new_gd();
+
+    assert_eq!(obj.call("parse_or_minus_one", vslice!["3"]), 3.to_variant());
+    assert_eq!(
+        obj.call("parse_or_minus_one", vslice!["?"]),
+        (-1).to_variant()
+    );
+}
+
+#[itest]
+fn func_result_error_to_panic() {
+    let mut obj = FuncResultPanicObj::new_gd();
+    assert_eq!(obj.call("parse_or_fail", vslice!["4"]), 4.to_variant());
+
+    expect_panic("Err(..) with default strategy fails the call", || {
+        obj.call("parse_or_fail", vslice!["four"]);
+    });
+}
+
+#[itest]
+fn func_result_registered_return_types() {
+    let api = collect_class_api();
+    let return_type = |class: &str, method: &str| {
+        let class = api.iter().find(|c| c.name == class).unwrap();
+        let method = class.methods.iter().find(|m| m.name == method).unwrap();
+        (
+            method.return_value.type_name.clone(),
+            method.return_value.variant_type,
+        )
+    };
+
+    let (type_name, _) = return_type("FuncResultObj", "parse_or_null");
+    assert_eq!(type_name, "Variant");
+
+    let (_, variant_type) = return_type("FuncResultObj", "parse_to_dict");
+    assert_eq!(variant_type, VariantType::DICTIONARY);
+
+    let (_, variant_type) = return_type("FuncResultObj", "check_positive");
+    assert_eq!(variant_type, VariantType::INT);
+
+    let (type_name, _) = return_type("FuncResultObj", "plain");
+    assert_eq!(type_name, "int");
+
+    let (type_name, _) = return_type("FuncResultPanicObj", "parse_or_fail");
+    assert_eq!(type_name, "int");
+}
@@ -5,9 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod class_api_test;
+mod class_builder_test;
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
+mod func_result_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod multiple_impl_blocks_secondary;
@@ -16,6 +16,7 @@ use godot::test::itest;
 #[derive(GodotClass)]
 #[class(init, base = Node2D)]
 pub struct RpcTest {
+    score: i32,
     base: Base<Node2D>,
 }
 
@@ -79,6 +80,12 @@ impl RpcTest {
 
     #[rpc(config = provide_cfg())]
     pub fn arg_config_fn(&mut self) {}
+
+    #[rpc(any_peer, call_local, reliable)]
+    #[func(rename = add_score_renamed)]
+    pub fn add_score(&mut self, amount: i32) {
+        self.score += amount;
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -91,14 +98,82 @@ fn node_enters_tree() {
     let node = RpcTest::new_alloc();
 
     // Registering is done in `UserClass::__before_ready()`, and it requires a multiplayer API to exist.
+    let mut root = scene_tree_with_multiplayer().get_root().unwrap();
+    root.add_child(&node);
+    root.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn typed_rpc_method_name() {
+    let node = RpcTest::new_alloc();
+
+    assert_eq!(
+        node.bind().rpcs().default_args().method_name(),
+        "default_args"
+    );
+    assert_eq!(
+        node.bind().rpcs().add_score().method_name(),
+        "add_score_renamed"
+    );
+
+    node.free();
+}
+
+#[itest]
+fn typed_rpc_outside_tree() {
+    let node = RpcTest::new_alloc();
+
+    // Not in the tree, so there's no multiplayer API.
+    let err = node
+        .bind()
+        .rpcs()
+        .add_score()
+        .rpc(5)
+        .expect_err("node not in tree");
+    assert!(err.is_outside_tree());
+    assert_eq!(err.godot_error(), None);
+    assert!(err.to_string().contains("add_score_renamed"), "{err}");
+
+    let err = node
+        .bind()
+        .rpcs()
+        .add_score()
+        .rpc_id(1, 5)
+        .expect_err("node not in tree");
+    assert!(err.is_outside_tree());
+
+    node.free();
+}
+
+#[itest]
+fn typed_rpc_call_local() {
+    let node = RpcTest::new_alloc();
+
+    let mut root = scene_tree_with_multiplayer().get_root().unwrap();
+    root.add_child(&node);
+
+    // Obtain the RPC first, since `call_local` invokes the method right away, which requires `bind_mut()`.
+    let add_score = node.bind().rpcs().add_score();
+
+    // Default multiplayer API uses an offline peer with ID 1; call_local runs the method on this peer.
+    add_score.rpc(5).expect("rpc() with offline peer");
+    assert_eq!(node.bind().score, 5);
+
+    add_score
+        .rpc_id(1, 2)
+        .expect("rpc_id() to self with offline peer");
+    assert_eq!(node.bind().score, 7);
+
+    root.remove_child(&node);
+    node.free();
+}
+
+fn scene_tree_with_multiplayer() -> Gd<SceneTree> {
     let mut scene_tree = Engine::singleton()
         .get_main_loop()
         .unwrap()
         .cast::<SceneTree>();
     scene_tree.set_multiplayer(MultiplayerApi::create_default_interface().as_ref());
-
-    let mut root = scene_tree.get_root().unwrap();
-    root.add_child(&node);
-    root.remove_child(&node);
-    node.free();
+    scene_tree
 }